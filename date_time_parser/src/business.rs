@@ -0,0 +1,292 @@
+//! Resolve workplace terms like "payday", "end of quarter" or "fiscal year end" using a company's own calendar.
+
+use crate::holiday::HolidayProvider;
+use crate::patterns::Pattern;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// A payday, e.g. "payday" or "next pay day", with the next in the first group.
+static PAYDAY: Pattern = Pattern::new(r"(?i)\b(next\s)?pay\s?day\b");
+
+/// The end of the fiscal year, e.g. "fiscal year end" or "FYE".
+static FISCAL_YEAR_END: Pattern = Pattern::new(
+    r"(?i)\b(end\sof\s(the\s)?(fiscal|financial)\syear|(fiscal|financial)\syear[-\s]end|fye)\b",
+);
+
+/// The end of a quarter, e.g. "end of next quarter" or "EOQ", with the captures `next` and `next2`.
+static QUARTER_END: Pattern = Pattern::new(
+    r"(?i)\b(end\sof\s((the|this)\s)?(?P<next>next\s)?quarter|(?P<next2>next\s)?quarter[-\s]end|eoq)\b",
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The rule that places a company's paydays. A payday falling on a weekend or day off is paid on the business day before.
+pub enum Payday {
+    /// The last business day of every month.
+    LastBusinessDay,
+    /// The same day of every month, e.g. `DayOfMonth(25)`. Short months pay on their last day.
+    DayOfMonth(u32),
+    /// The given day of every month and the last day of the month, e.g. `SemiMonthly(15)`.
+    SemiMonthly(u32),
+    /// Every other week, counting from a known payday.
+    Biweekly(NaiveDate),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A company's calendar, selected on the [`DateParser`](../date_time_parser/date_parse/struct.DateParser.html)
+/// with [`with_company_calendar`](../date_time_parser/date_parse/struct.DateParser.html#method.with_company_calendar).
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use date_time_parser::{CompanyCalendar, DateParser, Payday};
+///
+/// let company = CompanyCalendar {
+///     payday: Payday::DayOfMonth(15),
+///     fiscal_year_start: 10,
+/// };
+/// let parser = DateParser::new().with_company_calendar(company);
+/// let now = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+///
+/// assert_eq!(parser.find("payday", now), NaiveDate::from_ymd_opt(2025, 2, 14));
+/// assert_eq!(parser.find("end of quarter", now), NaiveDate::from_ymd_opt(2025, 3, 31));
+/// assert_eq!(parser.find("fiscal year end", now), NaiveDate::from_ymd_opt(2025, 9, 30));
+/// ```
+pub struct CompanyCalendar {
+    /// When employees are paid. Defaults to the last business day of the month.
+    pub payday: Payday,
+    /// The month the fiscal year starts in, from 1 to 12. Defaults to January. Quarter and fiscal year ends are not
+    /// found for a month outside that range.
+    pub fiscal_year_start: u32,
+}
+
+impl Default for CompanyCalendar {
+    fn default() -> CompanyCalendar {
+        CompanyCalendar {
+            payday: Payday::LastBusinessDay,
+            fiscal_year_start: 1,
+        }
+    }
+}
+
+impl CompanyCalendar {
+    /// Finds a business term in `text` and returns its next date on or after `now`, skipping the days off of `holidays`.
+    pub(crate) fn find(
+        &self,
+        text: &str,
+        now: NaiveDate,
+        holidays: &dyn HolidayProvider,
+    ) -> Option<NaiveDate> {
+        if let Some(caps) = PAYDAY.captures(text) {
+            let payday = self.next_payday(now, holidays)?;
+            if caps.get(1).is_some() && payday == now {
+                return self.next_payday(now.succ_opt()?, holidays);
+            }
+            return Some(payday);
+        }
+
+        if FISCAL_YEAR_END.is_match(text) {
+            return self.fiscal_year_end(now);
+        }
+
+        if let Some(caps) = QUARTER_END.captures(text) {
+            let n = if caps.name("next").or_else(|| caps.name("next2")).is_some() {
+                1
+            } else {
+                0
+            };
+            return self.quarter_end(now, n);
+        }
+
+        None
+    }
+
+    /// Returns the first payday on or after `now`.
+    fn next_payday(&self, now: NaiveDate, holidays: &dyn HolidayProvider) -> Option<NaiveDate> {
+        let mut month = NaiveDate::from_ymd_opt(now.year(), now.month(), 1)?;
+        for _ in 0..3 {
+            let last = last_day_of_month(month.year(), month.month())?;
+            let days: Vec<u32> = match self.payday {
+                Payday::LastBusinessDay => vec![last.day()],
+                Payday::DayOfMonth(d) => vec![d.min(last.day())],
+                Payday::SemiMonthly(d) => vec![d.min(last.day()), last.day()],
+                Payday::Biweekly(anchor) => {
+                    let periods = (now - anchor).num_days().div_euclid(14);
+                    return (periods..periods + 3)
+                        .filter_map(|k| anchor.checked_add_signed(Duration::days(14 * k)))
+                        .map(|d| previous_business_day(d, holidays))
+                        .find(|d| *d >= now);
+                }
+            };
+            let payday = days
+                .into_iter()
+                .filter_map(|d| NaiveDate::from_ymd_opt(month.year(), month.month(), d))
+                .map(|d| previous_business_day(d, holidays))
+                .find(|d| *d >= now);
+            if payday.is_some() {
+                return payday;
+            }
+            month = last.succ_opt()?;
+        }
+        None
+    }
+
+    /// Returns the month the fiscal year starts in, or `None` if it is not a month.
+    fn fiscal_start(&self) -> Option<u32> {
+        Some(self.fiscal_year_start).filter(|month| (1..=12).contains(month))
+    }
+
+    /// Returns the last day of the fiscal quarter `n` quarters after the one containing `now`.
+    fn quarter_end(&self, now: NaiveDate, n: u32) -> Option<NaiveDate> {
+        let start = self.fiscal_start()?;
+        let offset = (now.month() + 12 - start) % 12;
+        let months = start - 1 + (offset / 3 + n) * 3 + 2;
+        let year = now.year() + (now.month0() + 12 - offset) as i32 / 12 - 1;
+        last_day_of_month(year + months as i32 / 12, months % 12 + 1)
+    }
+
+    /// Returns the last day of the fiscal year containing `now`.
+    fn fiscal_year_end(&self, now: NaiveDate) -> Option<NaiveDate> {
+        let offset = (now.month() + 12 - self.fiscal_start()?) % 12;
+        self.quarter_end(now, 3 - offset / 3)
+    }
+}
+
+/// Returns the last day of `month` in `year`.
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let (y, m) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(y, m, 1)?.pred_opt()
+}
+
+/// Returns `date` if it is a business day, otherwise the closest business day before it.
+fn previous_business_day(mut date: NaiveDate, holidays: &dyn HolidayProvider) -> NaiveDate {
+    while matches!(date.weekday(), Weekday::Sat | Weekday::Sun) || holidays.is_day_off(date) {
+        date -= Duration::days(1);
+    }
+    date
+}
+
+#[cfg(test)]
+mod business_tests {
+    use super::{CompanyCalendar, Payday};
+    use crate::holiday::HolidayRegion;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn payday_tests() {
+        let last = company(Payday::LastBusinessDay, 1);
+        // May 31st 2025 is a Saturday
+        assert_find(&last, "payday", ymd(2025, 5, 12), ymd(2025, 5, 30));
+        assert_find(&last, "Next payday", ymd(2025, 5, 30), ymd(2025, 6, 30));
+        assert_find(&last, "payday", ymd(2025, 5, 30), ymd(2025, 5, 30));
+
+        let monthly = company(Payday::DayOfMonth(31), 1);
+        assert_find(&monthly, "payday", ymd(2025, 2, 3), ymd(2025, 2, 28));
+
+        let semi = company(Payday::SemiMonthly(15), 1);
+        assert_find(&semi, "payday", ymd(2025, 3, 3), ymd(2025, 3, 14));
+        assert_find(&semi, "payday", ymd(2025, 3, 17), ymd(2025, 3, 31));
+
+        // New Year's Day 2026 falls on a biweekly Thursday
+        let biweekly = company(Payday::Biweekly(ymd(2025, 1, 2)), 1);
+        assert_find(&biweekly, "payday", ymd(2025, 1, 3), ymd(2025, 1, 16));
+        assert_find(&biweekly, "payday", ymd(2025, 12, 20), ymd(2025, 12, 31));
+    }
+
+    #[test]
+    fn quarter_tests() {
+        let calendar = company(Payday::LastBusinessDay, 1);
+        assert_find(
+            &calendar,
+            "end of quarter",
+            ymd(2025, 2, 3),
+            ymd(2025, 3, 31),
+        );
+        assert_find(&calendar, "EOQ", ymd(2025, 12, 3), ymd(2025, 12, 31));
+        assert_find(
+            &calendar,
+            "end of next quarter",
+            ymd(2025, 12, 3),
+            ymd(2026, 3, 31),
+        );
+
+        let fiscal = company(Payday::LastBusinessDay, 10);
+        assert_find(&fiscal, "quarter end", ymd(2025, 10, 1), ymd(2025, 12, 31));
+        assert_find(
+            &fiscal,
+            "end of the quarter",
+            ymd(2025, 2, 3),
+            ymd(2025, 3, 31),
+        );
+        assert_find(
+            &fiscal,
+            "next quarter end",
+            ymd(2025, 8, 3),
+            ymd(2025, 12, 31),
+        );
+    }
+
+    #[test]
+    fn fiscal_year_tests() {
+        let calendar = company(Payday::LastBusinessDay, 1);
+        assert_find(
+            &calendar,
+            "fiscal year end",
+            ymd(2025, 2, 3),
+            ymd(2025, 12, 31),
+        );
+
+        let fiscal = company(Payday::LastBusinessDay, 7);
+        assert_find(
+            &fiscal,
+            "end of the fiscal year",
+            ymd(2025, 2, 3),
+            ymd(2025, 6, 30),
+        );
+        assert_find(&fiscal, "FYE", ymd(2025, 7, 1), ymd(2026, 6, 30));
+        assert_find(&fiscal, "FYE", ymd(2025, 6, 30), ymd(2025, 6, 30));
+    }
+
+    #[test]
+    fn fiscal_year_start_out_of_range_tests() {
+        for start in [0, 13, 25] {
+            let calendar = company(Payday::LastBusinessDay, start);
+            for text in ["end of quarter", "end of next quarter", "fiscal year end"] {
+                assert_eq!(
+                    calendar.find(text, ymd(2025, 2, 3), &HolidayRegion::UsFederal),
+                    None
+                );
+            }
+            assert_find(&calendar, "payday", ymd(2025, 5, 12), ymd(2025, 5, 30));
+        }
+    }
+
+    #[test]
+    fn no_term_tests() {
+        let calendar = CompanyCalendar::default();
+        assert_eq!(
+            calendar.find("Lunch at noon", ymd(2025, 2, 3), &HolidayRegion::UsFederal),
+            None
+        );
+    }
+
+    fn assert_find(calendar: &CompanyCalendar, text: &str, now: NaiveDate, expected: NaiveDate) {
+        assert_eq!(
+            calendar.find(text, now, &HolidayRegion::UsFederal),
+            Some(expected)
+        )
+    }
+
+    fn company(payday: Payday, fiscal_year_start: u32) -> CompanyCalendar {
+        CompanyCalendar {
+            payday,
+            fiscal_year_start,
+        }
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
@@ -0,0 +1,58 @@
+//! Plug alternative calendars (religious, fiscal, academic, ...) into the [`DateParser`](../date_time_parser/date_parse/struct.DateParser.html).
+
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A date written in an alternative calendar, e.g. 1 Ramadan 1445 => CalendarDate { year: Some(1445), month: 9, day: 1 }.
+pub struct CalendarDate {
+    /// The year in the calendar's own era, if given.
+    pub year: Option<i32>,
+    /// The month of the year, starting at 1.
+    pub month: u32,
+    /// The day of the month, starting at 1.
+    pub day: u32,
+}
+
+/// An interface for calendars other than the Gregorian one. Implement this trait and register it with
+/// [`DateParser::with_calendar`](../date_time_parser/date_parse/struct.DateParser.html#method.with_calendar)
+/// to have the date parser recognize dates written in that calendar.
+///
+/// # Example
+/// ```
+/// use chrono::{Datelike, NaiveDate};
+/// use date_time_parser::{CalendarDate, CalendarSystem, DateParser};
+///
+/// /// A fiscal calendar whose year starts on October 1st, e.g. "fiscal day 1" is October 1st.
+/// struct Fiscal;
+///
+/// impl CalendarSystem for Fiscal {
+///     fn name(&self) -> &'static str {
+///         "fiscal"
+///     }
+///
+///     fn recognize(&self, text: &str) -> Option<CalendarDate> {
+///         let day = text.strip_prefix("fiscal day ")?.parse().ok()?;
+///         Some(CalendarDate { year: None, month: 1, day })
+///     }
+///
+///     fn to_gregorian(&self, date: &CalendarDate, now: NaiveDate) -> Option<NaiveDate> {
+///         let start = NaiveDate::from_ymd_opt(now.year() - 1, 10, 1)?;
+///         start.checked_add_signed(chrono::Duration::days(date.day as i64 - 1))
+///     }
+/// }
+///
+/// let parser = DateParser::new().with_calendar(Fiscal);
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+/// assert_eq!(parser.find("fiscal day 1", now), NaiveDate::from_ymd_opt(2020, 10, 1));
+/// ```
+pub trait CalendarSystem: Send + Sync {
+    /// Returns a short name to describe the calendar.
+    fn name(&self) -> &'static str;
+
+    /// Takes unstructured text, and returns the date written in this calendar if a match is found.
+    fn recognize(&self, text: &str) -> Option<CalendarDate>;
+
+    /// Converts a date recognized by this calendar into a Gregorian [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html),
+    /// using `now` to fill in anything the text left out.
+    fn to_gregorian(&self, date: &CalendarDate, now: NaiveDate) -> Option<NaiveDate>;
+}
@@ -0,0 +1,74 @@
+//! Where "now" comes from when text is parsed without an explicit reference time.
+
+use chrono::{FixedOffset, Local, NaiveDateTime, Utc};
+
+/// A source of the current date and time.
+///
+/// Parsing with a [`FixedClock`](struct.FixedClock.html) gives the same result whenever it runs, which keeps tests
+/// from failing near midnight and lets servers parse every request against the time it arrived.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use date_time_parser::{Clock, DateParser, FixedClock};
+///
+/// let clock = FixedClock(NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap());
+///
+/// assert_eq!(DateParser::parse_relative("tomorrow", clock.now().date()), NaiveDate::from_ymd_opt(2021, 3, 2));
+/// ```
+pub trait Clock: Send + Sync {
+    /// Returns the current date and time.
+    fn now(&self) -> NaiveDateTime;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The system clock read in UTC, used by [`DateParser::parse`](../date_time_parser/date_parse/struct.DateParser.html#method.parse)
+/// and [`TimeParser::parse`](../date_time_parser/time_parse/struct.TimeParser.html#method.parse).
+pub struct UtcClock;
+
+impl Clock for UtcClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().naive_utc()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The system clock read in the local timezone.
+pub struct LocalClock;
+
+impl Clock for LocalClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The system clock read at a fixed offset from UTC, for parsing in a timezone other than the local one.
+///
+/// # Example
+/// ```
+/// use chrono::{FixedOffset, Utc};
+/// use date_time_parser::{Clock, OffsetClock};
+///
+/// let clock = OffsetClock("+09:00".parse::<FixedOffset>().unwrap());
+/// let hours_ahead = (clock.now() - Utc::now().naive_utc()).num_minutes() as f64 / 60.0;
+///
+/// assert_eq!(hours_ahead.round(), 9.0);
+/// ```
+pub struct OffsetClock(pub FixedOffset);
+
+impl Clock for OffsetClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().with_timezone(&self.0).naive_local()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A clock stopped at the given date and time.
+pub struct FixedClock(pub NaiveDateTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.0
+    }
+}
@@ -1,14 +1,864 @@
 //! Parse natural language text into the [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) format.
 
-use chrono::{Datelike, Duration, NaiveDate, Utc, Weekday};
-use regex::Regex;
-
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+use regex::Captures;
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::business::CompanyCalendar;
+use crate::calendar::CalendarSystem;
+use crate::clock::{Clock, UtcClock};
+#[cfg(feature = "hijri")]
+use crate::hijri::HijriCalendar;
+use crate::holiday::{
+    add_business_days, nth_weekday_of_month, HolidayDate, HolidayProvider, HolidayRegion,
+};
+use crate::locale::Locale;
+#[cfg(feature = "lunar")]
+use crate::lunar::LunarCalendar;
+use crate::number_words::{number_pattern, parse_number};
+use crate::patterns::{
+    blank_non_temporal, weekday_named, BuiltPattern, Pattern, DAY_MONTH, DAY_OF_MONTH,
+    ISO_DATE_TIME, ISO_WEEK, MONTH_DATE, NTH_WEEKDAY_OF_MONTH, NUMERIC_DATE,
+    NUMERIC_DATE_WITH_YEAR, ORDINAL_DATE, PERIOD, QUARTER, SEASON, SPAN, WEEKDAY, WEEKEND,
+};
+use crate::prefilter::may_be_temporal;
 use crate::recognizable::Recognizable;
+use crate::spans::{narrowest, DATE_CANDIDATES};
+use crate::time_parse::relative_offset;
+
+/// The weekend around a date, e.g. "weekend of June 5", with the capture `date`.
+static WEEKEND_OF: Pattern = Pattern::new(r"(?i)\bweekend\sof\s(?P<date>.+)");
+
+/// The week around a date, e.g. "week of June 7", with the capture `date`.
+static WEEK_OF: Pattern = Pattern::new(r"(?i)\bweek\sof\s(?P<date>.+)");
+
+/// Words marking a slash date as a date rather than a fraction, like "on" of "on 3/4" or a weekday, as in "Fri 3/4".
+static DATE_CUE: Pattern = Pattern::new(
+    r"(?i)\b(on|by|due|before|after|until|till|from|since|every)\s\d{1,2}/\d{1,2}\b|\b(mon|tue|wed|thu|fri|sat|sun)[a-z]*\.?,?\s\d{1,2}/",
+);
+
+/// A whole month, e.g. "all of June", with the capture `month`.
+static ALL_MONTH: Pattern = Pattern::new(r"(?i)\ball\s(of\s)?(?P<month>[a-z]{3,9})\b");
+
+/// A month name in a list of dates.
+const LIST_MONTH: &str = r"jan(uary)?|feb(ruary)?|mar(ch)?|apr(il)?|may|june?|july?|aug(ust)?|sep(t(ember)?)?|oct(ober)?|nov(ember)?|dec(ember)?";
+
+/// A weekday name in a list of dates.
+const LIST_WEEKDAY: &str =
+    r"mon(day)?|tue(s(day)?)?|wed(nesday)?|thu(r(s(day)?)?)?|fri(day)?|sat(urday)?|sun(day)?";
+
+/// What goes between the dates of a list, e.g. ", " or " and ".
+static LIST_SEPARATOR: Pattern = Pattern::new(r"(,?\s(and|&)\s|,\s?)");
+
+/// A list of days of the month, e.g. "June 5, 6, and 7" or "May 30 and June 2".
+static DATE_LIST: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b({m})\.?\s\d{{1,2}}(st|nd|rd|th)?({sep}(({m})\.?\s)?\d{{1,2}}(st|nd|rd|th)?)+\b",
+        m = LIST_MONTH,
+        sep = LIST_SEPARATOR.as_str()
+    )
+});
+
+/// A list of weekdays, e.g. "Mon and Wed".
+static WEEKDAY_LIST: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b({w})({sep}({w}))+\b",
+        w = LIST_WEEKDAY,
+        sep = LIST_SEPARATOR.as_str()
+    )
+});
+
+/// A run of weekdays, e.g. "Mon-Wed" or "Tuesday through Thursday", with the captures `start` and `end`.
+static WEEKDAY_RANGE: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b(?P<start>{w})\b\.?\s?(-|–|—|to\b|through\b|thru\b|till\b|until\b)\s?(?P<end>{w})\b",
+        w = LIST_WEEKDAY
+    )
+});
+
+/// A month name starting an item of a list of dates.
+static LIST_ITEM_MONTH: BuiltPattern = BuiltPattern::new(|| format!(r"(?i)^({})", LIST_MONTH));
+
+/// A day relative to today, e.g. "tomorrow" or "the day after tomorrow", with the capture `key`.
+static DAY_KEYWORD: Pattern = Pattern::new(
+    r"(?i)\b(?P<key>day\safter\stomorrow|day\sbefore\syesterday|night\sbefore\slast|today|tomorrow|yesterday|last\snight)\b",
+);
+
+/// A weekday in a week relative to this one, e.g. "next Saturday", with the captures `prep` and `day`.
+static RELATIVE_WEEKDAY: Pattern =
+    Pattern::new(r"(?i)(?P<prep>next|last|this\spast|this)\s(?P<day>\w+)");
+
+/// A number of days or weeks from today, e.g. "in 2 days", "in six days" or "in a week", with the captures `num` and
+/// `unit`.
+static IN_DAYS: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bin\s(?P<num>\d{{1,3}}|{})\s(?P<unit>day|week)s?\b",
+        number_pattern()
+    )
+});
+
+/// A number of days, weeks, months or years before now, e.g. "3 days ago" or "a week ago", with the captures `num` and
+/// `unit`.
+static AGO: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b(?P<num>\d{{1,3}}|{})\s(?P<unit>day|week|month|year)s?\sago\b",
+        number_pattern()
+    )
+});
+
+/// A number of days or weeks from another date, e.g. "a week from Friday", "two weeks from tomorrow" or "3 days before
+/// June 1", with the captures `num`, `unit`, `dir` and `anchor`.
+static OFFSET_FROM: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b(?P<num>\d{{1,3}}|{})\s(?P<unit>day|week)s?\s(?P<dir>from|after|before)\s(?P<anchor>.+)",
+        number_pattern()
+    )
+});
+
+/// A weekday a week after the next one, e.g. "the Monday after next", with the capture `day`.
+static AFTER_NEXT: BuiltPattern =
+    BuiltPattern::new(|| format!(r"(?i)\bthe\s(?P<day>{})\safter\snext\b", LIST_WEEKDAY));
+
+/// The next business day.
+static NEXT_BUSINESS_DAY: Pattern = Pattern::new(r"(?i)\bnext\s(business|working)\sday\b");
+
+/// A number of business days from today, e.g. "in 3 business days" or "2 working days from now", with the captures
+/// `in` and `from`.
+static IN_BUSINESS_DAYS: Pattern = Pattern::new(
+    r"(?i)\b(in\s(?P<in>\d{1,3})\s(business|working)\sdays?|(?P<from>\d{1,3})\s(business|working)\sdays?\sfrom\s(now|today))\b",
+);
+
+/// A month relative to this one, e.g. "next month", with the capture `prep`.
+static RELATIVE_MONTH: Pattern = Pattern::new(r"(?i)(?P<prep>next|last|this)\smonth");
+
+/// A number of months or years from this one, e.g. "in 2 months", "in a month" or "in three years", with the captures
+/// `num` and `unit`.
+static IN_MONTHS: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bin\s(?P<num>\d{{1,3}}|{})\s(?P<unit>month|year)s?\b",
+        number_pattern()
+    )
+});
+
+/// A numeric date written year first, e.g. "2021/6/5", with the captures `year`, `month` and `date`.
+static YEAR_FIRST_DATE: Pattern =
+    Pattern::new(r"\b(?P<year>\d{4}|\d{2})/(?P<month>\d{1,2})/(?P<date>\d{1,2})\b");
+
+/// A month name, with the capture `month`.
+static MONTH_NAME: Pattern = Pattern::new(
+    r"(?i)(?P<month>jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)",
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// Which way dates without a year or week are read from the current date.
+pub enum Direction {
+    /// Toward the next occurrence, e.g. "Friday" is the coming Friday. Used for plans and invitations.
+    #[default]
+    Future,
+    /// Toward the last occurrence, e.g. "Friday" is the Friday just gone and "June 5" is last year's after June 5.
+    /// Used for journals and meeting minutes.
+    Past,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// What "next" means before a weekday, as in "next Friday".
+pub enum NextWeekdayPolicy {
+    /// The weekday a week after the coming one, today included, e.g. on a Wednesday "next Friday" is nine days away, on
+    /// a Sunday "next Monday" eight and on a Friday "next Friday" seven. How "next" has always been read.
+    #[default]
+    WeekAfterComing,
+    /// The weekday in the week after this one, e.g. on a Wednesday "next Friday" is nine days away and on a Saturday six.
+    /// Weeks start on the parser's [week start](struct.DateParser.html#method.with_week_start).
+    NextWeek,
+    /// The first such weekday after today, e.g. on a Wednesday "next Friday" is two days away and on a Friday seven.
+    Upcoming,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// The order the parts of slash dates like "5/6/2021" are written in.
+pub enum DateOrder {
+    /// Month first, e.g. "5/6" is May 6. Usual in the US.
+    #[default]
+    MonthDayYear,
+    /// Day first, e.g. "5/6" is June 5. Usual in most of Europe, Latin America and elsewhere. A date that only makes
+    /// sense month first, like "6/25", is still read.
+    DayMonthYear,
+    /// Year first, e.g. "2021/5/6" is May 6, 2021. Usual in East Asia, where dates without a year like "5/6" are
+    /// written month first.
+    YearMonthDay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Settings for how a [`DateParser`](struct.DateParser.html) reads dates, applied with
+/// [`DateParser::with_options`](struct.DateParser.html#method.with_options).
+pub struct DateOptions {
+    /// The order slash dates are written in. Defaults to [`DateOrder::MonthDayYear`](enum.DateOrder.html).
+    pub order: DateOrder,
+}
 
+#[derive(Clone)]
 /// Container for parsing dates from string slices.  
-pub struct DateParser {}
+///
+/// The associated `parse` functions use the default configuration. Build a `DateParser` with
+/// [`DateParser::new`](#method.new) to plug in alternative calendars or holidays.
+pub struct DateParser {
+    calendars: Vec<Arc<dyn CalendarSystem>>,
+    holidays: Arc<dyn HolidayProvider>,
+    company: CompanyCalendar,
+    week_start: Weekday,
+    weekend_start: Weekday,
+    direction: Direction,
+    next_weekday: NextWeekdayPolicy,
+    prefer_future: bool,
+    order: DateOrder,
+    language: Locale,
+    /// Whether numbers that are not dates, like phone numbers, prices and versions, are left out
+    context_filters: bool,
+    /// Whether text can be skipped when [`may_be_temporal`](../fn.may_be_temporal.html) rules it out, which stops
+    /// being safe once calendars or holidays with words of their own are added
+    prefilter: bool,
+}
+
+impl Default for DateParser {
+    fn default() -> DateParser {
+        DateParser::new()
+    }
+}
 
 impl DateParser {
+    /// Creates a parser with the calendars enabled by crate features (`hijri`, `lunar`).
+    pub fn new() -> DateParser {
+        #[allow(unused_mut)]
+        let mut parser = DateParser {
+            calendars: Vec::new(),
+            holidays: Arc::new(HolidayRegion::default()),
+            company: CompanyCalendar::default(),
+            week_start: Weekday::Mon,
+            weekend_start: Weekday::Sat,
+            direction: Direction::Future,
+            next_weekday: NextWeekdayPolicy::WeekAfterComing,
+            prefer_future: true,
+            order: DateOrder::MonthDayYear,
+            language: Locale::English,
+            context_filters: true,
+            prefilter: true,
+        };
+        #[cfg(feature = "hijri")]
+        {
+            parser = parser.with_calendar(HijriCalendar);
+        }
+        #[cfg(feature = "lunar")]
+        {
+            parser = parser.with_calendar(LunarCalendar);
+        }
+        // the words of the calendars enabled by features are known to the prefilter
+        parser.prefilter = true;
+        parser
+    }
+
+    /// Registers an alternative calendar. Calendars are consulted in the order they were added, before the built-in Gregorian rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar` - A [`CalendarSystem`](../date_time_parser/calendar/trait.CalendarSystem.html) to recognize dates with
+    pub fn with_calendar<C: CalendarSystem + 'static>(mut self, calendar: C) -> DateParser {
+        self.calendars.push(Arc::new(calendar));
+        self.prefilter = false;
+        self
+    }
+
+    /// Selects the holidays whose names are recognized and that business days skip. Defaults to
+    /// [`HolidayRegion::UsFederal`](../date_time_parser/holiday/enum.HolidayRegion.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `holidays` - A [`HolidayProvider`](../date_time_parser/holiday/trait.HolidayProvider.html), e.g. a
+    ///   [`HolidayRegion`](../date_time_parser/holiday/enum.HolidayRegion.html)
+    pub fn with_holidays<H: HolidayProvider + 'static>(mut self, holidays: H) -> DateParser {
+        self.holidays = Arc::new(holidays);
+        self.prefilter = false;
+        self
+    }
+
+    /// Sets the company calendar used for workplace terms like "payday", "end of quarter" or "fiscal year end".
+    ///
+    /// # Arguments
+    ///
+    /// * `company` - A [`CompanyCalendar`](../date_time_parser/business/struct.CompanyCalendar.html) with the payday rule and fiscal year start
+    pub fn with_company_calendar(mut self, company: CompanyCalendar) -> DateParser {
+        self.company = company;
+        self
+    }
+
+    /// Sets the first day of the week used for spans like "all week". Defaults to Monday.
+    ///
+    /// # Arguments
+    ///
+    /// * `week_start` - The [`Weekday`](https://docs.rs/chrono/0.4.0/chrono/enum.Weekday.html) weeks start on
+    pub fn with_week_start(mut self, week_start: Weekday) -> DateParser {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the first day of weekends found with "the weekend of ...", which always end on Sunday. Defaults to Saturday;
+    /// use Friday for long weekends.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekend_start` - The [`Weekday`](https://docs.rs/chrono/0.4.0/chrono/enum.Weekday.html) weekends start on
+    pub fn with_weekend_start(mut self, weekend_start: Weekday) -> DateParser {
+        self.weekend_start = weekend_start;
+        self
+    }
+
+    /// Sets which way dates without a year or week, like "Friday", "June 5" or "Thanksgiving", are read from the
+    /// current date. Defaults to [`Direction::Future`](../date_time_parser/date_parse/enum.Direction.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - The [`Direction`](../date_time_parser/date_parse/enum.Direction.html) to read dates in
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::{DateParser, Direction};
+    ///
+    /// let parser = DateParser::new().with_direction(Direction::Past);
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap(); // a Wednesday
+    ///
+    /// assert_eq!(parser.find("Friday", now), NaiveDate::from_ymd_opt(2021, 2, 26));
+    /// assert_eq!(parser.find("June 5", now), NaiveDate::from_ymd_opt(2020, 6, 5));
+    /// ```
+    pub fn with_direction(mut self, direction: Direction) -> DateParser {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets what "next" means before a weekday, as in "next Friday". Defaults to
+    /// [`NextWeekdayPolicy::WeekAfterComing`](../date_time_parser/date_parse/enum.NextWeekdayPolicy.html). "this Friday" is always the
+    /// coming Friday, today included, and "last Friday" the one before today.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`NextWeekdayPolicy`](../date_time_parser/date_parse/enum.NextWeekdayPolicy.html) to read "next" with
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::{DateParser, NextWeekdayPolicy};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap(); // a Wednesday
+    /// let upcoming = DateParser::new().with_next_weekday_policy(NextWeekdayPolicy::Upcoming);
+    ///
+    /// assert_eq!(upcoming.find("next Friday", now), NaiveDate::from_ymd_opt(2021, 3, 5));
+    /// assert_eq!(DateParser::new().find("next Friday", now), NaiveDate::from_ymd_opt(2021, 3, 12));
+    ///
+    /// let sunday = NaiveDate::from_ymd_opt(2021, 6, 6).unwrap();
+    /// let next_week = DateParser::new().with_next_weekday_policy(NextWeekdayPolicy::NextWeek);
+    ///
+    /// assert_eq!(next_week.find("next Monday", sunday), NaiveDate::from_ymd_opt(2021, 6, 7));
+    /// assert_eq!(DateParser::new().find("next Monday", sunday), NaiveDate::from_ymd_opt(2021, 6, 14));
+    /// ```
+    pub fn with_next_weekday_policy(mut self, policy: NextWeekdayPolicy) -> DateParser {
+        self.next_weekday = policy;
+        self
+    }
+
+    /// Sets whether a bare weekday like "Friday" that has already gone by this week is read as the one next week. On
+    /// by default; when off, a bare weekday is the one in this week, even if it has passed. Reading toward the
+    /// [past](../date_time_parser/date_parse/enum.Direction.html#variant.Past) takes precedence.
+    ///
+    /// The `event_parser` crate also moves a time without a date, like "Dinner at 7" written at 11pm, to tomorrow
+    /// when this is on.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefer_future` - Whether weekdays and times that have gone by are moved to their next occurrence
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap(); // a Wednesday
+    /// let this_week = DateParser::new().with_prefer_future(false);
+    ///
+    /// assert_eq!(DateParser::new().find("Monday", now), NaiveDate::from_ymd_opt(2021, 3, 8));
+    /// assert_eq!(this_week.find("Monday", now), NaiveDate::from_ymd_opt(2021, 3, 1));
+    /// ```
+    pub fn with_prefer_future(mut self, prefer_future: bool) -> DateParser {
+        self.prefer_future = prefer_future;
+        self
+    }
+
+    /// Reads slash dates like "5/6" in the order usual in `locale`, e.g. day first for "en_GB". Defaults to month first,
+    /// as in the US. A slash date that only makes sense the other way round, like "6/25" in "en_GB", is still read.
+    /// Dates written in the language of `locale` are read too if its feature is enabled, as with
+    /// [`with_language`](#method.with_language).
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - A locale like "en_US" or "de-DE"; without a region, dates are read month first
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let parser = DateParser::new().with_locale("en_GB");
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    ///
+    /// assert_eq!(parser.find("Party 5/6", now), NaiveDate::from_ymd_opt(2021, 6, 5));
+    /// assert_eq!(parser.find("Party 6/25", now), NaiveDate::from_ymd_opt(2021, 6, 25));
+    /// ```
+    pub fn with_locale(mut self, locale: &str) -> DateParser {
+        self.order = if reads_month_first(locale) {
+            DateOrder::MonthDayYear
+        } else {
+            DateOrder::DayMonthYear
+        };
+        self.language = Locale::from_tag(locale).unwrap_or_default();
+        self
+    }
+
+    /// Reads dates written in `language`, like "el próximo viernes" in Spanish, as well as in English. Defaults to
+    /// [`Locale::English`](enum.Locale.html#variant.English).
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The [`Locale`](enum.Locale.html) whose words for weekdays, months and the like are read
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::{DateParser, Locale};
+    ///
+    /// let parser = DateParser::new().with_language(Locale::English);
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    ///
+    /// assert_eq!(parser.find("Lunch tomorrow", now), NaiveDate::from_ymd_opt(2021, 3, 4));
+    /// ```
+    pub fn with_language(mut self, language: Locale) -> DateParser {
+        self.language = language;
+        self
+    }
+
+    /// Applies `options`, e.g. the order slash dates are written in.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The [`DateOptions`](../date_time_parser/date_parse/struct.DateOptions.html) to read dates with
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::{DateOptions, DateOrder, DateParser};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    /// let european = DateParser::new().with_options(DateOptions { order: DateOrder::DayMonthYear });
+    /// let japanese = DateParser::new().with_options(DateOptions { order: DateOrder::YearMonthDay });
+    ///
+    /// assert_eq!(european.find("Party 5/6", now), NaiveDate::from_ymd_opt(2021, 6, 5));
+    /// assert_eq!(japanese.find("Party 2022/5/6", now), NaiveDate::from_ymd_opt(2022, 5, 6));
+    /// ```
+    pub fn with_options(mut self, options: DateOptions) -> DateParser {
+        self.order = options.order;
+        self
+    }
+
+    /// Sets whether numbers that are not dates are left out, as they are by default: phone numbers like "555-1230",
+    /// prices like "$5" and versions like "v2.30", matched by
+    /// [`NOT_TEMPORAL`](patterns/static.NOT_TEMPORAL.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `context_filters` - Whether to leave the numbers out
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+    ///
+    /// assert_eq!(DateParser::new().find("Pay $6/5", now), None);
+    /// assert_eq!(
+    ///     DateParser::new().with_context_filters(false).find("Pay $6/5", now),
+    ///     NaiveDate::from_ymd_opt(2021, 6, 5)
+    /// );
+    /// ```
+    pub fn with_context_filters(mut self, context_filters: bool) -> DateParser {
+        self.context_filters = context_filters;
+        self
+    }
+
+    /// Returns which way this parser reads dates from the current date.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the order this parser reads the parts of slash dates in, as set with [`with_locale`](#method.with_locale)
+    /// or [`with_options`](#method.with_options).
+    pub fn order(&self) -> DateOrder {
+        self.order
+    }
+
+    /// Returns whether weekdays and times that have gone by are moved to their next occurrence, as set with
+    /// [`with_prefer_future`](#method.with_prefer_future).
+    pub fn prefers_future(&self) -> bool {
+        self.prefer_future
+    }
+
+    /// Returns whether `text` certainly holds no date this parser reads, so it need not be parsed.
+    fn skips(&self, text: &str) -> bool {
+        self.prefilter && !may_be_temporal(text)
+    }
+
+    /// Finds a span of days like "all week", "all of next month", "all June", "the weekend of July 4", "this weekend",
+    /// "Mon-Wed", "the week of June 7", "week 32 2025", "this summer" or "Q3" in a string slice of natural language text with respect to a given date. Returns the first and last day of the span if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) to interpret the natural language span around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use date_time_parser::DateParser;
+    ///
+    /// let parser = DateParser::new().with_week_start(Weekday::Sun);
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    ///
+    /// assert_eq!(
+    ///     parser.find_span("Conference all week", now),
+    ///     Some((NaiveDate::from_ymd_opt(2021, 2, 28).unwrap(), NaiveDate::from_ymd_opt(2021, 3, 6).unwrap()))
+    /// );
+    /// ```
+    pub fn find_span(&self, text: &str, now: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.skips(text) {
+            return None;
+        }
+        if let Some(caps) = WEEKEND_OF.captures(text) {
+            let anchor = self.find(&caps["date"], now)?;
+            return Some(weekend_around(anchor, self.weekend_start));
+        }
+
+        if let Some(caps) = WEEKEND.captures(text) {
+            let n = match caps["rel"].to_lowercase().as_str() {
+                "next" => 1,
+                "last" => -1,
+                _ => 0,
+            };
+            let (start, end) = upcoming_weekend(now, self.weekend_start);
+            return Some((start + Duration::weeks(n), end + Duration::weeks(n)));
+        }
+
+        // the run ends on the first of its last weekday from its start, so "Fri-Mon" spans the weekend
+        if let Some(caps) = WEEKDAY_RANGE.captures(text) {
+            let start = self.find(&caps["start"], now)?;
+            let days = weekday_named(&caps["end"])?.days_since(weekday_named(&caps["start"])?);
+            return Some((start, start + Duration::days(days as i64)));
+        }
+
+        if let Some(caps) = WEEK_OF.captures(text) {
+            let date = self.find(&caps["date"], now)?;
+            let start = date - Duration::days(date.weekday().days_since(self.week_start) as i64);
+            return Some((start, start + Duration::days(6)));
+        }
+
+        // ISO weeks start on Monday whatever the first day of the week, and belong to the year their Thursday is in.
+        // A number starting a date or time, like the "6" of "Senior Week 6/17", is not a week's
+        let iso_week = ISO_WEEK
+            .captures(text)
+            .filter(|caps| !text[caps.get(0).unwrap().end()..].starts_with(['/', ':']));
+        if let Some(caps) = iso_week {
+            let week = caps.name("week").or_else(|| caps.name("iso_week"))?;
+            let year = match caps.name("year").or_else(|| caps.name("iso_year")) {
+                Some(year) => year.as_str().parse().ok()?,
+                None => now.iso_week().year(),
+            };
+            let start =
+                NaiveDate::from_isoywd_opt(year, week.as_str().parse().ok()?, Weekday::Mon)?;
+            return Some((start, start + Duration::days(6)));
+        }
+
+        if let Some(caps) = SPAN.captures(text) {
+            let n = match caps.name("rel").map(|m| m.as_str().to_lowercase()) {
+                Some(rel) if rel == "next" => 1,
+                Some(rel) if rel == "last" => -1,
+                _ => 0,
+            };
+            if caps["unit"].eq_ignore_ascii_case("week") {
+                let days_in = (7 + now.weekday().num_days_from_monday()
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                let start = now - Duration::days(days_in as i64) + Duration::weeks(n);
+                return Some((start, start + Duration::days(6)));
+            }
+            return month_span(now.year(), now.month() as i32 + n as i32);
+        }
+
+        if let Some(expr) = parse_season(text).or_else(|| parse_quarter(text)) {
+            return period_span(&expr, now, self.direction);
+        }
+
+        let caps = ALL_MONTH.captures(text)?;
+        let month = MonthOfYear::recognize(&caps["month"])?;
+        month_span(now.year(), month as i32)
+    }
+
+    /// Finds an enumerated list of dates like "June 5, 6, and 7", "May 30 and June 2" or "Mon and Wed" in a string slice
+    /// of natural language text with respect to a given date. Days without a month take the month before them.
+    /// Returns the dates in the order written if a list of at least two is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) to interpret the natural language dates around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    /// let dates = DateParser::new().find_list("Workshop June 5, 6, and 7", now).unwrap();
+    ///
+    /// assert_eq!(dates.len(), 3);
+    /// assert_eq!(dates[2], NaiveDate::from_ymd_opt(2021, 6, 7).unwrap());
+    /// ```
+    pub fn find_list(&self, text: &str, now: NaiveDate) -> Option<Vec<NaiveDate>> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.skips(text) {
+            return None;
+        }
+        let list = DATE_LIST
+            .find(text)
+            .or_else(|| WEEKDAY_LIST.find(text))?
+            .as_str();
+
+        let mut current_month = "";
+        let mut found = Vec::new();
+        for item in LIST_SEPARATOR.split(list) {
+            let item = item.trim();
+            if let Some(m) = LIST_ITEM_MONTH.find(item) {
+                current_month = m.as_str();
+                found.push(self.find(item, now)?);
+            } else if item.starts_with(|c: char| c.is_ascii_digit()) {
+                found.push(self.find(&format!("{} {}", current_month, item), now)?);
+            } else {
+                found.push(self.find(item, now)?);
+            }
+        }
+        Some(found)
+    }
+
+    /// Finds a date in a string slice of natural language text with respect to a given date, using this parser's calendars.
+    /// Returns a [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) to interpret the natural language date around
+    pub fn find(&self, text: &str, now: NaiveDate) -> Option<NaiveDate> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.skips(text) {
+            return None;
+        }
+        let blanked = if self.context_filters {
+            blank_non_temporal(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        let text = blanked.as_ref();
+        for calendar in &self.calendars {
+            if let Some(date) = calendar.recognize(text) {
+                return calendar.to_gregorian(&date, now);
+            }
+        }
+
+        // "2 days before Christmas" is not Christmas
+        if let Some(date_expr) = self.offset_from(text) {
+            return self.resolve(date_expr, now);
+        }
+
+        // a date stated with a holiday named as a word, like "Christmas party Dec 20", is the date stated
+        if let Some(date_expr) = self.recognize(text).filter(DateExpr::is_explicit) {
+            return self.resolve(date_expr, now);
+        }
+
+        if let Some(date) = self.holidays.holiday(text, now.year()) {
+            if self.direction == Direction::Past && date > now {
+                return self.holidays.holiday(text, now.year() - 1);
+            }
+            return Some(date);
+        }
+
+        if let Some(date) = self.company.find(text, now, self.holidays.as_ref()) {
+            return Some(date);
+        }
+
+        self.resolve(self.recognize(text)?, now)
+    }
+
+    /// Finds a date in a string slice of natural language text like [`find`](#method.find), but returns what it is
+    /// written as rather than which day it is, e.g. "next Friday" is `DateExpr::DayInNWeeks(1, Fri)`. Holidays are
+    /// read with the rule [`HolidayProvider::holiday_rule`](trait.HolidayProvider.html#method.holiday_rule) gives
+    /// them. Company dates and dates in other calendars have no [`DateExpr`](enum.DateExpr.html), so `None` is
+    /// returned for those as for text without a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::Weekday;
+    /// use date_time_parser::{DateExpr, DateParser, HolidayDate, MonthOfYear};
+    ///
+    /// let parser = DateParser::new();
+    /// assert_eq!(parser.parse_expr("Lunch next Friday"), Some(DateExpr::DayInNWeeks(1, Weekday::Fri)));
+    /// assert_eq!(parser.parse_expr("Dentist June 5th"), Some(DateExpr::InMonth(MonthOfYear::Jun, 5)));
+    /// assert_eq!(
+    ///     parser.parse_expr("Party on Thanksgiving"),
+    ///     Some(DateExpr::Holiday(HolidayDate::NthWeekday(4, Weekday::Thu, 11)))
+    /// );
+    /// ```
+    pub fn parse_expr(&self, text: &str) -> Option<DateExpr> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.skips(text) {
+            return None;
+        }
+        let blanked = if self.context_filters {
+            blank_non_temporal(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        if let Some(date_expr) = self.offset_from(&blanked) {
+            return Some(date_expr);
+        }
+        if let Some(date_expr) = self.recognize(&blanked).filter(DateExpr::is_explicit) {
+            return Some(date_expr);
+        }
+        if let Some(holiday) = self.holidays.holiday_rule(&blanked) {
+            return Some(DateExpr::Holiday(holiday));
+        }
+        self.recognize(&blanked)
+    }
+
+    /// Recognizes a date written as an offset from another, like "a week from Friday" or "3 days before Christmas", in
+    /// `text`. The date it is from can be a holiday or anything else this parser reads, or "now".
+    fn offset_from(&self, text: &str) -> Option<DateExpr> {
+        if let Some(caps) = AFTER_NEXT.captures(text) {
+            let day = weekday_named(&caps["day"])?;
+            return Some(DateExpr::OffsetFrom(
+                Box::new(DateExpr::DayInNWeeks(1, day)),
+                Duration::weeks(1),
+            ));
+        }
+
+        let caps = OFFSET_FROM.captures(text)?;
+        let num = parse_number(&caps["num"]).filter(|num| num.fract() == 0.0)? as i64;
+        let days = match caps["unit"].to_lowercase().as_str() {
+            "week" => 7 * num,
+            _ => num,
+        };
+        let offset = match caps["dir"].to_lowercase().as_str() {
+            "before" => Duration::days(-days),
+            _ => Duration::days(days),
+        };
+        let anchor = &caps["anchor"];
+        let anchor = if anchor.to_lowercase().starts_with("now") {
+            DateExpr::InNDays(0)
+        } else if let Some(holiday) = self.holidays.holiday_rule(anchor) {
+            DateExpr::Holiday(holiday)
+        } else {
+            self.recognize(anchor)?
+        };
+        Some(DateExpr::OffsetFrom(Box::new(anchor), offset))
+    }
+
+    /// Recognizes a date expression in `text`, read in this parser's order.
+    fn recognize(&self, text: &str) -> Option<DateExpr> {
+        let text = match self.order {
+            DateOrder::MonthDayYear => Cow::Borrowed(text),
+            DateOrder::DayMonthYear => day_first(text),
+            DateOrder::YearMonthDay => year_first(text),
+        };
+        DateExpr::recognize(&text)
+    }
+
+    /// Resolves `date_expr` with respect to `now` with this parser's settings.
+    fn resolve(&self, date_expr: DateExpr, now: NaiveDate) -> Option<NaiveDate> {
+        let next_weekday = (self.next_weekday, self.week_start);
+        resolve_date_expr(
+            date_expr,
+            now,
+            self.holidays.as_ref(),
+            self.direction,
+            next_weekday,
+            self.prefer_future,
+        )
+    }
+
+    /// Finds a date in a string slice of natural language text with respect to a given date like
+    /// [`find`](#method.find), along with where in the text it was read from, in bytes, like "June 5th" of "Lunch on
+    /// June 5th at noon". Returns `None` if no date is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) to interpret the natural language date around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    /// let (date, span) = DateParser::new().find_with_span("Party on Thanksgiving", now).unwrap();
+    ///
+    /// assert_eq!(date, NaiveDate::from_ymd_opt(2021, 11, 25).unwrap());
+    /// assert_eq!(span, 9..21);
+    /// ```
+    pub fn find_with_span(&self, text: &str, now: NaiveDate) -> Option<(NaiveDate, Range<usize>)> {
+        narrowest(text, &DATE_CANDIDATES, |part| self.find(part, now))
+    }
+
+    /// Finds a date in a string slice of natural language text with respect to a given date and time, using this parser's calendars.
+    /// Relative times like "in 2 hours" resolve to the date they land on, so late in the evening they can fall on the next day.
+    /// Returns a [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDateTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDateTime.html) to interpret the natural language date around
+    pub fn find_at(&self, text: &str, now: NaiveDateTime) -> Option<NaiveDate> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.skips(text) {
+            return None;
+        }
+        if let Some(date) = self.find(text, now.date()) {
+            return Some(date);
+        }
+
+        let offset = relative_offset(text)?;
+        now.checked_add_signed(offset).map(|dt| dt.date())
+    }
+
     /// Parses a string slice of natural language text with respect to the current date. Returns a [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) if a match is found, `None` otherwise.
     ///
     /// # Arguments
@@ -22,12 +872,13 @@ impl DateParser {
     /// use date_time_parser::{DateParser, Recognizable};
     ///
     /// let year = Local::now().year();
-    /// let date = DateParser::parse("July 4 2020");
+    /// let date = DateParser::parse("July 4");
     ///
     /// assert_eq!(date, Some(NaiveDate::from_ymd(year, 7, 4)));
+    /// assert_eq!(DateParser::parse("July 4 2020"), Some(NaiveDate::from_ymd(2020, 7, 4)));
     /// ```
     pub fn parse(text: &str) -> Option<NaiveDate> {
-        DateParser::parse_relative(text, Utc::now().date().naive_utc())
+        DateParser::parse_relative(text, UtcClock.now().date())
     }
 
     /// Parses a string slice of natural language text with respect to a given date. Returns a [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) if a match is found, `None` otherwise.
@@ -49,48 +900,392 @@ impl DateParser {
     /// assert_eq!(date, Some(NaiveDate::from_ymd(year, 7, 4)));
     /// ```
     pub fn parse_relative(text: &str, now: NaiveDate) -> Option<NaiveDate> {
-        if let Some(date_expr) = DateExpr::recognize(text) {
-            match date_expr {
-                DateExpr::InMonth(m, d) => {
-                    let nd = NaiveDate::from_ymd(now.year(), m as u32, d);
-                    return Some(nd);
-                }
-                DateExpr::InYear(m, d, y) => {
-                    let nd = NaiveDate::from_ymd(y, m as u32, d);
-                    return Some(nd);
-                }
-                DateExpr::InNDays(n) => {
-                    let d = Duration::days(n as i64);
-                    return Some(now.checked_add_signed(d).unwrap());
-                }
-                DateExpr::DayInNWeeks(n, d) => {
-                    let mut difference: i32 = (d.num_days_from_sunday() as i32)
-                        - (now.weekday().num_days_from_sunday() as i32);
-                    if difference < 0 {
-                        difference += 7;
-                    }
-                    difference += 7 * (n as i32);
-                    let dur = Duration::days(difference as i64);
-                    return Some(now.checked_add_signed(dur).unwrap());
+        DateParser::new().find(text, now)
+    }
+
+    /// Parses a string slice of natural language text written in the language of `locale` with respect to the current
+    /// date, reading slash dates in the order usual there, as [`with_locale`](#method.with_locale) does. Returns a
+    /// [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) if a match is found, `None`
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `locale` - A locale like "en_GB" or "es_ES"; languages other than English need their feature enabled
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Utc};
+    /// use date_time_parser::DateParser;
+    ///
+    /// let year = Utc::now().year();
+    /// assert_eq!(DateParser::parse_with_locale("Party 5/6", "en_GB"), NaiveDate::from_ymd_opt(year, 6, 5));
+    /// ```
+    pub fn parse_with_locale(text: &str, locale: &str) -> Option<NaiveDate> {
+        DateParser::new()
+            .with_locale(locale)
+            .find(text, UtcClock.now().date())
+    }
+
+    /// Parses a string slice of natural language text with respect to a given date and time, so that relative times
+    /// crossing midnight land on the right date. Returns a [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDateTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDateTime.html) to interpret the natural language date around
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(23, 30, 0).unwrap();
+    ///
+    /// assert_eq!(DateParser::parse_relative_datetime("in 2 hours", now), NaiveDate::from_ymd_opt(2021, 3, 2));
+    /// assert_eq!(DateParser::parse_relative_datetime("tomorrow", now), NaiveDate::from_ymd_opt(2021, 3, 2));
+    /// ```
+    pub fn parse_relative_datetime(text: &str, now: NaiveDateTime) -> Option<NaiveDate> {
+        DateParser::new().find_at(text, now)
+    }
+
+    /// Returns every valid reading of the first slash date in a string slice, like "6/5" as both June 5 (month first, as
+    /// written in the US) and May 6 (day first, as written in most other places), with the reading usual in `locale`
+    /// first. The other parsing functions always read slash dates month first.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) whose year is used when the date has none
+    /// * `locale` - A locale like "en_US.UTF-8" or "en-GB". Locales without a region, like "C", rank month first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+    /// let june_5 = NaiveDate::from_ymd_opt(2021, 6, 5).unwrap();
+    /// let may_6 = NaiveDate::from_ymd_opt(2021, 5, 6).unwrap();
+    ///
+    /// assert_eq!(DateParser::slash_date_readings("Party 6/5", now, "en_US"), vec![june_5, may_6]);
+    /// assert_eq!(DateParser::slash_date_readings("Party 6/5", now, "en_GB"), vec![may_6, june_5]);
+    /// let june_25 = NaiveDate::from_ymd_opt(2021, 6, 25).unwrap();
+    /// assert_eq!(DateParser::slash_date_readings("Party 6/25", now, "en_GB"), vec![june_25]);
+    /// ```
+    pub fn slash_date_readings(text: &str, now: NaiveDate, locale: &str) -> Vec<NaiveDate> {
+        slash_readings(text, now, reads_month_first(locale))
+    }
+
+    /// Returns every reading of a date in a string slice of natural language text with respect to a given date, each
+    /// with a score from 0 to 1 of how likely it is meant, most likely first. The reading [`find`](#method.find) gives
+    /// comes first; a slash date like "3/4" also reads the other way round, and one with nothing else marking it as a
+    /// date, like "on" or a weekday, scores lower, as it may be a fraction. Returns an empty `Vec` if no date is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) to interpret the natural language date around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::DateParser;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+    /// let candidates = DateParser::new().candidates("Party on 3/4", now);
+    ///
+    /// assert_eq!(candidates[0], (NaiveDate::from_ymd_opt(2021, 3, 4).unwrap(), 0.6));
+    /// assert_eq!(candidates[1], (NaiveDate::from_ymd_opt(2021, 4, 3).unwrap(), 0.4));
+    /// assert_eq!(DateParser::new().candidates("Party on June 5", now)[0].1, 1.0);
+    /// ```
+    pub fn candidates(&self, text: &str, now: NaiveDate) -> Vec<(NaiveDate, f32)> {
+        let found = match self.find(text, now) {
+            Some(date) => date,
+            None => return Vec::new(),
+        };
+        let readings = match self.order {
+            DateOrder::MonthDayYear => slash_readings(text, now, true),
+            DateOrder::DayMonthYear => slash_readings(text, now, false),
+            DateOrder::YearMonthDay => Vec::new(),
+        };
+        let mut candidates = match readings[..] {
+            [first, other] if first == found => vec![(found, 0.6), (other, 0.4)],
+            _ => vec![(found, 1.0)],
+        };
+        let bare_slash_date = readings.first() == Some(&found)
+            && !NUMERIC_DATE_WITH_YEAR.is_match(text)
+            && !DATE_CUE.is_match(text);
+        if bare_slash_date {
+            for (_, score) in &mut candidates {
+                *score *= 0.8;
+            }
+        }
+        candidates
+    }
+}
+
+/// Returns every valid reading of the first slash date in `text`, month first and then day first if `month_first`,
+/// or the other way round.
+fn slash_readings(text: &str, now: NaiveDate, month_first: bool) -> Vec<NaiveDate> {
+    let caps = match NUMERIC_DATE_WITH_YEAR
+        .captures(text)
+        .or_else(|| NUMERIC_DATE.captures(text))
+    {
+        Some(caps) => caps,
+        None => return Vec::new(),
+    };
+    let first: u32 = caps["month"].parse().unwrap();
+    let second: u32 = caps["date"].parse().unwrap();
+    let year = match caps.name("year") {
+        Some(year) => match written_year(year.as_str()) {
+            Some(year) => year,
+            None => return Vec::new(),
+        },
+        None => now.year(),
+    };
+
+    let as_month_first = NaiveDate::from_ymd_opt(year, first, second);
+    let as_day_first = NaiveDate::from_ymd_opt(year, second, first).filter(|_| first != second);
+    let mut readings: Vec<NaiveDate> = if month_first {
+        [as_month_first, as_day_first]
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    } else {
+        [as_day_first, as_month_first]
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    };
+    readings.dedup();
+    readings
+}
+
+/// Returns the weekend starting on `weekend_start` that contains `date`, or the one nearest to it.
+/// Midweek dates belong to the following weekend.
+fn weekend_around(date: NaiveDate, weekend_start: Weekday) -> (NaiveDate, NaiveDate) {
+    let day = date.weekday().num_days_from_monday() as i64;
+    let start_day = weekend_start.num_days_from_monday() as i64;
+
+    let start = if day >= start_day {
+        date - Duration::days(day - start_day)
+    } else if day + 1 < start_day - day {
+        // closer to the weekend that just ended on Sunday
+        date - Duration::days(day + 1 + 6 - start_day)
+    } else {
+        date + Duration::days(start_day - day)
+    };
+    (start, start + Duration::days(6 - start_day))
+}
+
+/// Returns the weekend starting on `weekend_start` that `date` is in, or the next one if it is midweek.
+fn upcoming_weekend(date: NaiveDate, weekend_start: Weekday) -> (NaiveDate, NaiveDate) {
+    let day = date.weekday().num_days_from_monday() as i64;
+    let start_day = weekend_start.num_days_from_monday() as i64;
+    let start = if day >= start_day {
+        date - Duration::days(day - start_day)
+    } else {
+        date + Duration::days(start_day - day)
+    };
+    (start, start + Duration::days(6 - start_day))
+}
+
+/// Returns the first day of the week starting on `week_start` that contains `date`.
+fn week_of(date: NaiveDate, week_start: Weekday) -> Option<NaiveDate> {
+    date.checked_sub_signed(Duration::days(date.weekday().days_since(week_start) as i64))
+}
+
+/// Returns the first and last day of `month` in `year`, where months past December roll into the following years.
+fn month_span(year: i32, month: i32) -> Option<(NaiveDate, NaiveDate)> {
+    let months = year * 12 + month - 1;
+    let start =
+        NaiveDate::from_ymd_opt(months.div_euclid(12), months.rem_euclid(12) as u32 + 1, 1)?;
+    let next = NaiveDate::from_ymd_opt(
+        (months + 1).div_euclid(12),
+        (months + 1).rem_euclid(12) as u32 + 1,
+        1,
+    )?;
+    Some((start, next.pred_opt()?))
+}
+
+/// Returns whether slash dates are usually written month first in `locale`, which is true in the US and a few places
+/// that follow it, and when `locale` names no region.
+fn reads_month_first(locale: &str) -> bool {
+    let language_and_region = locale.split(['.', '@']).next().unwrap_or("");
+    match language_and_region.split(['_', '-']).nth(1) {
+        Some(region) if region.len() == 2 => matches!(
+            region.to_uppercase().as_str(),
+            "US" | "AS" | "GU" | "MP" | "PR" | "UM" | "VI" | "PH" | "FM" | "MH" | "PW"
+        ),
+        _ => true,
+    }
+}
+
+/// Rewrites slash dates in `text` written day first, like "5/6" for June 5, month first, leaving those that only make
+/// sense month first, like "6/25", as they are.
+fn day_first(text: &str) -> Cow<'_, str> {
+    NUMERIC_DATE.replace_all(text, |caps: &Captures| {
+        let (day, month) = (&caps["month"], &caps["date"]);
+        match month.parse::<u32>() {
+            Ok(1..=12) => format!("{}/{}", month, day),
+            _ => caps[0].to_owned(),
+        }
+    })
+}
+
+/// Rewrites slash dates in `text` written year first, like "2021/6/5", month first, leaving dates without a year as
+/// they are.
+fn year_first(text: &str) -> Cow<'_, str> {
+    YEAR_FIRST_DATE.replace_all(text, "$month/$date/$year")
+}
+
+/// Returns the date `day` of `month` in `year`, or the last day of the month for days past its end like "Feb 30".
+fn clamped_date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        None if (29..=31).contains(&day) => month_span(year, month as i32).map(|(_, last)| last),
+        date => date,
+    }
+}
+
+/// Resolves a `DateExpr` into a `NaiveDate` with respect to `now`, counting business days around `holidays`, reading
+/// dates without a year or week in `direction` and "next" weekdays by `next_weekday`. Bare weekdays that have gone by
+/// this week are next week's if `prefer_future`.
+fn resolve_date_expr(
+    date_expr: DateExpr,
+    now: NaiveDate,
+    holidays: &dyn HolidayProvider,
+    direction: Direction,
+    next_weekday: (NextWeekdayPolicy, Weekday),
+    prefer_future: bool,
+) -> Option<NaiveDate> {
+    match date_expr {
+        DateExpr::InMonth(m, d) => {
+            let month = m as u32;
+            match clamped_date(now.year(), month, d)? {
+                date if direction == Direction::Past && date > now => {
+                    clamped_date(now.year() - 1, month, d)
                 }
-                DateExpr::InNMonths(n) => {
-                    let now_month = now.month();
-                    let to_month = (now_month as i32) + n;
-                    return Some(NaiveDate::from_ymd(now.year(), to_month as u32, now.day()));
+                date => Some(date),
+            }
+        }
+        DateExpr::InYear(m, d, y) => clamped_date(y, m as u32, d),
+        DateExpr::NthWeekdayOfMonth(n, d, MonthSpec::Named(m)) => {
+            match nth_weekday_of_month(now.year(), m as u32, d, n)? {
+                date if direction == Direction::Past && date > now => {
+                    nth_weekday_of_month(now.year() - 1, m as u32, d, n)
                 }
+                date => Some(date),
             }
         }
-        None
+        DateExpr::NthWeekdayOfMonth(n, d, MonthSpec::InNMonths(k)) => {
+            let months = now.year() * 12 + now.month0() as i32 + k;
+            nth_weekday_of_month(
+                months.div_euclid(12),
+                months.rem_euclid(12) as u32 + 1,
+                d,
+                n,
+            )
+        }
+        DateExpr::Season(..) | DateExpr::SeasonOfYear(..) | DateExpr::Quarter(..) => {
+            period_span(&date_expr, now, direction).map(|(start, _)| start)
+        }
+        DateExpr::OffsetFrom(anchor, offset) => resolve_date_expr(
+            *anchor,
+            now,
+            holidays,
+            direction,
+            next_weekday,
+            prefer_future,
+        )?
+        .checked_add_signed(offset),
+        DateExpr::Holiday(holiday) => match holiday.in_year(now.year())? {
+            date if direction == Direction::Past && date > now => holiday.in_year(now.year() - 1),
+            date => Some(date),
+        },
+        DateExpr::InNDays(n) => {
+            let d = Duration::days(n as i64);
+            now.checked_add_signed(d)
+        }
+        DateExpr::InNBusinessDays(n) => add_business_days(now, n, holidays),
+        DateExpr::DayInNWeeks(0, d) if direction == Direction::Past => {
+            let back = now.weekday().days_since(d);
+            now.checked_sub_signed(Duration::days(back as i64))
+        }
+        DateExpr::DayInNWeeks(0, d) if !prefer_future => week_of(now, next_weekday.1)?
+            .checked_add_signed(Duration::days(d.days_since(next_weekday.1) as i64)),
+        DateExpr::DayInNWeeks(1, d) if next_weekday.0 == NextWeekdayPolicy::Upcoming => {
+            let ahead = match d.days_since(now.weekday()) {
+                0 => 7,
+                days => days,
+            };
+            now.checked_add_signed(Duration::days(ahead as i64))
+        }
+        DateExpr::DayInNWeeks(1, d) if next_weekday.0 == NextWeekdayPolicy::NextWeek => {
+            let week_start = next_weekday.1;
+            let this_week = now
+                .checked_sub_signed(Duration::days(now.weekday().days_since(week_start) as i64))?;
+            this_week.checked_add_signed(Duration::days(7 + d.days_since(week_start) as i64))
+        }
+        DateExpr::DayInNWeeks(n, d) => {
+            let mut difference: i32 =
+                (d.num_days_from_sunday() as i32) - (now.weekday().num_days_from_sunday() as i32);
+            if difference < 0 {
+                difference += 7;
+            }
+            difference += 7 * (n as i32);
+            let dur = Duration::days(difference as i64);
+            now.checked_add_signed(dur)
+        }
+        DateExpr::InNMonths(n) => {
+            // months are counted across years, and a day past the end of the month lands on its last day
+            let months = now.year() * 12 + now.month0() as i32 + n;
+            clamped_date(
+                months.div_euclid(12),
+                months.rem_euclid(12) as u32 + 1,
+                now.day(),
+            )
+        }
+        // February 29 lands on February 28 in a year that is not a leap year
+        DateExpr::InNYears(n) => clamped_date(now.year() + n, now.month(), now.day()),
+        DateExpr::Ordinal(y, ddd) => NaiveDate::from_yo_opt(y.unwrap_or_else(|| now.year()), ddd),
+        DateExpr::StartOfWeek(n) => {
+            week_of(now, next_weekday.1)?.checked_add_signed(Duration::weeks(n as i64))
+        }
+        DateExpr::EndOfWeek(n) => week_of(now, next_weekday.1)?
+            .checked_add_signed(Duration::weeks(n as i64) + Duration::days(6)),
+        DateExpr::StartOfMonth(n) => {
+            month_span(now.year(), now.month() as i32 + n).map(|(start, _)| start)
+        }
+        DateExpr::EndOfMonth(n) => {
+            month_span(now.year(), now.month() as i32 + n).map(|(_, end)| end)
+        }
+        DateExpr::StartOfYear(n) => NaiveDate::from_ymd_opt(now.year() + n, 1, 1),
+        DateExpr::EndOfYear(n) => NaiveDate::from_ymd_opt(now.year() + n, 12, 31),
+        DateExpr::NthOfMonth(d) => {
+            // the nearest month with that day, e.g. "the 31st" on April 5 is May 31
+            let step = if direction == Direction::Past { -1 } else { 1 };
+            let months = now.year() * 12 + now.month0() as i32;
+            (0..12)
+                .map(|n| months + n * step)
+                .filter_map(|m| {
+                    NaiveDate::from_ymd_opt(m.div_euclid(12), m.rem_euclid(12) as u32 + 1, d)
+                })
+                .find(|date| match direction {
+                    Direction::Future => *date >= now,
+                    Direction::Past => *date <= now,
+                })
+        }
     }
 }
 
-#[derive(Debug, PartialEq)]
-/// A year as defined by the Gregorian calendar i.e. AD 1 = Year(1).
-struct Year(pub isize);
-
-#[derive(Debug, PartialEq)]
-/// The month of the year.
-enum MonthOfYear {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The month of the year, numbered from 1 for January.
+pub enum MonthOfYear {
     Jan = 1,
     Feb = 2,
     Mar = 3,
@@ -124,22 +1319,159 @@ fn num_to_month(num: u32) -> Option<MonthOfYear> {
     }
 }
 
-#[derive(Debug, PartialEq)]
-/// An abstract syntax for parsing dates.
-enum DateExpr {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The month a weekday is counted in by [`DateExpr::NthWeekdayOfMonth`](enum.DateExpr.html#variant.NthWeekdayOfMonth).
+pub enum MonthSpec {
+    /// A month by name, in no given year, e.g. June => Named(Jun)
+    Named(MonthOfYear),
+    /// A month a number of months from this one, e.g. next month => InNMonths(1)
+    InNMonths(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A season of the year, as the months it is reckoned by in the northern hemisphere, numbered by the month it starts
+/// in. Winter starts in December, so winter 2025 runs into 2026.
+pub enum Season {
+    Spring = 3,
+    Summer = 6,
+    Fall = 9,
+    Winter = 12,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A month of a season, e.g. "early" of "early fall".
+pub enum PeriodPart {
+    /// The first month
+    Early,
+    /// The second month
+    Mid,
+    /// The last month
+    Late,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An abstract syntax for dates, saying what a date is written as rather than which day it is, e.g. "next Friday" is
+/// `DayInNWeeks(1, Fri)`. Found in text by [`DateParser::parse_expr`](struct.DateParser.html#method.parse_expr) and
+/// read around a date with [`resolve`](#method.resolve).
+pub enum DateExpr {
+    /// A number of days from now, e.g. tomorrow => InNDays(1)
     InNDays(i32),
-    DayInNWeeks(i8, Weekday), // e.g. next week monday => DayInNWeeks(1, Mon)
-    InNMonths(i32),           // e.g. in 2 months => InNMonths(2)
-    InMonth(MonthOfYear, u32), // e.g. June 8th => InMonth(Jun, 8)
-    InYear(MonthOfYear, u32, i32), // e.g. June 8th, 2019 => InYear(Jun, 8, 2019)
+    /// A number of business days from now, e.g. in 3 business days => InNBusinessDays(3)
+    InNBusinessDays(i32),
+    /// A weekday a number of weeks from now, e.g. next week monday => DayInNWeeks(1, Mon)
+    DayInNWeeks(i8, Weekday),
+    /// The same day a number of months from now, e.g. in 2 months => InNMonths(2)
+    InNMonths(i32),
+    /// The same day a number of years from now, e.g. in 3 years => InNYears(3)
+    InNYears(i32),
+    /// A day of a month in no given year, e.g. June 8th => InMonth(Jun, 8)
+    InMonth(MonthOfYear, u32),
+    /// A day of a month in a year, e.g. June 8th, 2019 => InYear(Jun, 8, 2019)
+    InYear(MonthOfYear, u32, i32),
+    /// A day of the year, maybe in a given year, e.g. day 200 of 2025 => Ordinal(Some(2025), 200)
+    Ordinal(Option<i32>, u32),
+    /// A day of the nearest month that has it, e.g. the 15th => NthOfMonth(15)
+    NthOfMonth(u32),
+    /// A weekday counted from the start of a month, or from its end when negative, e.g. the first Monday of June =>
+    /// NthWeekdayOfMonth(1, Mon, Named(Jun)) and the last Friday of the month => NthWeekdayOfMonth(-1, Fri, InNMonths(0))
+    NthWeekdayOfMonth(i8, Weekday, MonthSpec),
+    /// The first day of a week a number of weeks from now, e.g. next week => StartOfWeek(1)
+    StartOfWeek(i32),
+    /// The last day of a week a number of weeks from now, e.g. end of this week => EndOfWeek(0)
+    EndOfWeek(i32),
+    /// The first day of a month a number of months from now, e.g. beginning of next month => StartOfMonth(1)
+    StartOfMonth(i32),
+    /// The last day of a month a number of months from now, e.g. end of the month => EndOfMonth(0)
+    EndOfMonth(i32),
+    /// The first day of a year a number of years from now, e.g. beginning of next year => StartOfYear(1)
+    StartOfYear(i32),
+    /// The last day of a year a number of years from now, e.g. end of last year => EndOfYear(-1)
+    EndOfYear(i32),
+    /// A holiday in no given year, e.g. Christmas => Holiday(Fixed(12, 25))
+    Holiday(HolidayDate),
+    /// All or part of a season a number of years from this one, e.g. early next fall => Season(Some(Early), Fall, 1)
+    Season(Option<PeriodPart>, Season, i32),
+    /// All or part of a season in a year, e.g. winter 2025 => SeasonOfYear(None, Winter, 2025)
+    SeasonOfYear(Option<PeriodPart>, Season, i32),
+    /// A quarter of a year, maybe in a given year, e.g. Q3 => Quarter(3, None)
+    Quarter(u32, Option<i32>),
+    /// A number of days before or after another date, e.g. a week from Friday => OffsetFrom(DayInNWeeks(0, Fri), 7 days)
+    OffsetFrom(Box<DateExpr>, Duration),
+}
+
+impl DateExpr {
+    /// Returns the date this expression names with respect to `now`, read as [`DateParser::new`](struct.DateParser.html#method.new)
+    /// reads dates: upcoming rather than past, with weeks starting on Monday. Returns `None` if there is no such date,
+    /// like day 366 of 2021.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use date_time_parser::DateExpr;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap(); // a Wednesday
+    /// assert_eq!(DateExpr::DayInNWeeks(1, Weekday::Fri).resolve(now), NaiveDate::from_ymd_opt(2021, 3, 12));
+    /// assert_eq!(DateExpr::Ordinal(Some(2021), 366).resolve(now), None);
+    /// ```
+    pub fn resolve(&self, now: NaiveDate) -> Option<NaiveDate> {
+        DateParser::new().resolve(self.clone(), now)
+    }
+
+    /// Returns the first and last day this expression names with respect to `now`, read like
+    /// [`resolve`](#method.resolve). Seasons and quarters span several days, and other expressions a single one.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::{DateExpr, Season};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    /// assert_eq!(
+    ///     DateExpr::Season(None, Season::Summer, 0).resolve_span(now),
+    ///     Some((NaiveDate::from_ymd_opt(2021, 6, 1).unwrap(), NaiveDate::from_ymd_opt(2021, 8, 31).unwrap()))
+    /// );
+    /// ```
+    pub fn resolve_span(&self, now: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match period_span(self, now, Direction::Future) {
+            Some(span) => Some(span),
+            None => self.resolve(now).map(|date| (date, date)),
+        }
+    }
+
+    /// Returns whether this expression is a date written out, with a month and day like "Dec 20" or "12/20", or a day
+    /// of the year, rather than a word for one.
+    fn is_explicit(&self) -> bool {
+        matches!(
+            self,
+            DateExpr::InMonth(..) | DateExpr::InYear(..) | DateExpr::Ordinal(..)
+        )
+    }
 }
 
 /// Parsing a `str` into a DateExpr uses both structured formats and common phrases.
 impl Recognizable for DateExpr {
     fn recognize(text: &str) -> Option<DateExpr> {
+        if let Some(date) = parse_iso_date(text) {
+            return Some(date);
+        }
+        if let Some(date) = parse_season(text) {
+            return Some(date);
+        }
+        if let Some(date) = parse_quarter(text) {
+            return Some(date);
+        }
+        if let Some(date) = parse_nth_weekday_of_month(text) {
+            return Some(date);
+        }
         if let Some(date) = parse_keywords(text) {
             return Some(date);
         }
+        if let Some(date) = parse_ordinal(text) {
+            return Some(date);
+        }
+        if let Some(date) = parse_business_days(text) {
+            return Some(date);
+        }
         if let Some(date) = parse_relative_date(text) {
             return Some(date);
         }
@@ -155,12 +1487,21 @@ impl Recognizable for DateExpr {
         if let Some(date) = parse_date_in_week(text) {
             return Some(date);
         }
+        if let Some(date) = parse_period_edge(text) {
+            return Some(date);
+        }
         if let Some(date) = parse_in_n_months(text) {
             return Some(date);
         }
         if let Some(date) = parse_relative_month(text) {
             return Some(date);
         }
+        if let Some(date) = parse_nth_of_month(text) {
+            return Some(date);
+        }
+        if let Some(date) = parse_next_period(text) {
+            return Some(date);
+        }
         if let Some(date) = parse_day_alone(text) {
             return Some(date);
         }
@@ -173,10 +1514,10 @@ impl Recognizable for DateExpr {
     }
 }
 
-/// Parsing a str into a `Weekday` uses the format %W.
+/// Parsing a str into a `Weekday` uses english full names and abbreviations, e.g. "thurs".
 impl Recognizable for Weekday {
     fn recognize(text: &str) -> Option<Weekday> {
-        text.parse::<Weekday>().ok()
+        weekday_named(text)
     }
 
     fn describe() -> &'static str {
@@ -197,16 +1538,17 @@ impl Recognizable for MonthOfYear {
 
 /// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
 fn parse_keywords(text: &str) -> Option<DateExpr> {
-    // today, tomorrow, yesterday
-
-    let re = Regex::new(r"(?i)\b(?P<key>today|tomorrow|yesterday)\b").unwrap();
+    // today, tomorrow, yesterday, last night, the day after tomorrow, the night before last
 
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = DAY_KEYWORD.captures(text) {
         if let Some(key_match) = caps.name("key") {
-            let n = match key_match.as_str().to_lowercase().as_ref() {
-                "today" => 0,
-                "tomorrow" => 1,
-                "yesterday" => -1,
+            let key = key_match.as_str().to_lowercase();
+            let n = match key.split_whitespace().collect::<Vec<_>>().as_slice() {
+                ["today"] => 0,
+                ["tomorrow"] => 1,
+                ["yesterday"] | ["last", "night"] => -1,
+                ["day", "after", "tomorrow"] => 2,
+                ["day", "before", "yesterday"] | ["night", "before", "last"] => -2,
                 _ => 0,
             };
             return Some(DateExpr::InNDays(n));
@@ -220,14 +1562,12 @@ fn parse_keywords(text: &str) -> Option<DateExpr> {
 fn parse_in_month(text: &str) -> Option<DateExpr> {
     // 6/1, 06/01, 06-01-15
 
-    let re = Regex::new(r"(?P<month>\d{1,2})(/)(?P<date>\d{1,2})").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = NUMERIC_DATE.captures(text) {
         if let Some(month_match) = caps.name("month") {
             if let Some(date_match) = caps.name("date") {
                 let month: u32 = month_match.as_str().parse().unwrap();
                 let date: u32 = date_match.as_str().parse().unwrap();
-                return Some(DateExpr::InMonth(num_to_month(month).unwrap(), date));
+                return Some(DateExpr::InMonth(num_to_month(month)?, date));
             }
         }
     }
@@ -235,20 +1575,31 @@ fn parse_in_month(text: &str) -> Option<DateExpr> {
     None
 }
 
+/// Parses a `str` into an `Option` containing a `DateExpr::InYear(MonthOfYear, u32, i32)`.
+fn parse_iso_date(text: &str) -> Option<DateExpr> {
+    // 2021-06-05, 2021-06-05T14:30:00Z
+
+    let caps = ISO_DATE_TIME.captures(text)?;
+    let year: i32 = caps["year"].parse().unwrap();
+    let month: u32 = caps["month"].parse().unwrap();
+    let day: u32 = caps["day"].parse().unwrap();
+    // "2021-02-30" is not a date, unlike "Feb 30" which is read as the end of February
+    NaiveDate::from_ymd_opt(year, month, day)?;
+    Some(DateExpr::InYear(num_to_month(month)?, day, year))
+}
+
 /// Parses a `str` into an `Option` containing a `DateExpr::InYear(MonthOfYear, u32, i32)`.
 fn parse_in_year(text: &str) -> Option<DateExpr> {
     // 6/1, 06/01, 06-01-15
 
-    let re = Regex::new(r"(?P<month>\d{1,2})(/)(?P<date>\d{1,2})(/)(?P<year>\d{4}|\d{2})").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = NUMERIC_DATE_WITH_YEAR.captures(text) {
         if let Some(month_match) = caps.name("month") {
             if let Some(date_match) = caps.name("date") {
                 if let Some(year_match) = caps.name("year") {
                     let month: u32 = month_match.as_str().parse().unwrap();
                     let date: u32 = date_match.as_str().parse().unwrap();
-                    let year: i32 = year_match.as_str().parse().unwrap();
-                    return Some(DateExpr::InYear(num_to_month(month).unwrap(), date, year));
+                    let year = written_year(year_match.as_str())?;
+                    return Some(DateExpr::InYear(num_to_month(month)?, date, year));
                 }
             }
         }
@@ -257,20 +1608,39 @@ fn parse_in_year(text: &str) -> Option<DateExpr> {
     None
 }
 
-/// Parses a `str` into an `Option` containing a `DateExpr::InMonth(MonthOfYear, u32)`.
+/// Parses a `str` into an `Option` containing a `DateExpr::InMonth(MonthOfYear, u32)`, or a
+/// `DateExpr::InYear(MonthOfYear, u32, i32)` for a date with a year.
 fn parse_month_date_english(text: &str) -> Option<DateExpr> {
-    //june 1, june 1st
-
-    // TODO: Generalize for having the date before the month, not just after
-    let re = Regex::new(r"(?i)(?P<month>jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)\s(?P<date>\d{1,2})?").unwrap();
+    //june 1, june 1st, june 5th 2026, jun 5 '26, 4 july, the 1st of june, 12 dec 2023
+
+    // the date written first in the text is read, whichever way round its day and month are
+    let month_first = MONTH_DATE
+        .captures(text)
+        .filter(|caps| caps.name("date").is_some());
+    if let Some(caps) = DAY_MONTH.captures(text).filter(|day_first| {
+        month_first.as_ref().is_none_or(|month_first| {
+            day_first.get(0).unwrap().start() < month_first.get(0).unwrap().start()
+        })
+    }) {
+        // months are named by their first three letters, like "sep" of "Sept"
+        let m = MonthOfYear::recognize(&caps["month"][..3])?;
+        let date = caps["date"].parse().ok()?;
+        return match caps.name("year") {
+            Some(year) => Some(DateExpr::InYear(m, date, written_year(year.as_str())?)),
+            None => Some(DateExpr::InMonth(m, date)),
+        };
+    }
 
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = month_first {
         if let Some(month_match) = caps.name("month") {
             if let Some(date_match) = caps.name("date") {
                 let date: u32 = date_match.as_str().parse().unwrap();
                 let month = month_match.as_str();
                 if let Some(m) = MonthOfYear::recognize(month) {
-                    return Some(DateExpr::InMonth(m, date));
+                    return match caps.name("year") {
+                        Some(year) => Some(DateExpr::InYear(m, date, written_year(year.as_str())?)),
+                        None => Some(DateExpr::InMonth(m, date)),
+                    };
                 }
             }
         }
@@ -279,17 +1649,27 @@ fn parse_month_date_english(text: &str) -> Option<DateExpr> {
     None
 }
 
+/// Returns the year written with a month and day, e.g. 2026 for "2026", "'26" or the "26" of "6/5/26". Years written
+/// with two digits are in this century.
+fn written_year(year: &str) -> Option<i32> {
+    let digits = year.strip_prefix('\'').unwrap_or(year);
+    let number: i32 = digits.parse().ok()?;
+    Some(if digits.len() == 2 {
+        2000 + number
+    } else {
+        number
+    })
+}
+
 /// Parses a `str` into an `Option` containing a `DateExpr::InWeek(i8, Weekday)`
 fn parse_date_in_week(text: &str) -> Option<DateExpr> {
-    // sat, this saturday, next saturday, last saturday, this sat,
+    // sat, this saturday, next saturday, last saturday, this sat, this past saturday
 
-    let re = Regex::new(r"(?i)(?P<prep>next|last|this)\s(?P<day>\w+)").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = RELATIVE_WEEKDAY.captures(text) {
         if let Some(prep_match) = caps.name("prep") {
             let p = match prep_match.as_str().to_lowercase().as_ref() {
                 "next" => 1,
-                "last" => -1,
+                "last" | "this past" => -1,
                 "this" => 0,
                 _ => 0,
             };
@@ -307,48 +1687,281 @@ fn parse_date_in_week(text: &str) -> Option<DateExpr> {
     None
 }
 
+/// Parses a `str` into an `Option` containing a `DateExpr::NthOfMonth(u32)`
+fn parse_nth_of_month(text: &str) -> Option<DateExpr> {
+    // the 15th, on the 3rd
+
+    let caps = DAY_OF_MONTH.captures(text)?;
+    // "the 5th of June" names its month
+    if caps.name("of").is_some() {
+        return None;
+    }
+    match caps["day"].parse() {
+        Ok(day @ 1..=31) => Some(DateExpr::NthOfMonth(day)),
+        _ => None,
+    }
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::NthWeekdayOfMonth(i8, Weekday, MonthSpec)`
+fn parse_nth_weekday_of_month(text: &str) -> Option<DateExpr> {
+    // the first Monday of June, last Friday of the month, the 2nd Tuesday of next month
+
+    let caps = NTH_WEEKDAY_OF_MONTH.captures(text)?;
+    let n = weekday_count(&caps["nth"])?;
+    let d = weekday_named(&caps["day"])?;
+    let month = match caps.name("month") {
+        Some(month) => MonthSpec::Named(parse_month_of_year_english(month.as_str())?),
+        None => MonthSpec::InNMonths(match caps["rel"].to_lowercase().as_ref() {
+            "next" => 1,
+            "last" => -1,
+            _ => 0,
+        }),
+    };
+    Some(DateExpr::NthWeekdayOfMonth(n, d, month))
+}
+
+/// Returns which of the weekdays of a month `nth` counts, e.g. 2 for "second" and -1 for "last".
+pub(crate) fn weekday_count(nth: &str) -> Option<i8> {
+    match nth.to_lowercase().as_ref() {
+        "first" | "1st" => Some(1),
+        "second" | "2nd" => Some(2),
+        "third" | "3rd" => Some(3),
+        "fourth" | "4th" => Some(4),
+        "fifth" | "5th" => Some(5),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
 /// Parses a `str` into an `Option` containing a `DateExpr::InWeek(i8, Weekday)`
 fn parse_day_alone(text: &str) -> Option<DateExpr> {
     // saturday
 
-    let re = Regex::new(r"(?i)(?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesay|urday)?\b")
-        .unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = WEEKDAY.captures(text) {
         if let Some(day_match) = caps.name("day") {
-            let d = day_match
-                .as_str()
-                .to_lowercase()
-                .parse::<Weekday>()
-                .unwrap();
+            let d = weekday_named(day_match.as_str())?;
             return Some(DateExpr::DayInNWeeks(0, d));
         }
     }
 
-    None
+    None
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`, or a `DateExpr::InNMonths(i32)` or
+/// `DateExpr::InNYears(i32)` for months and years ago
+fn parse_relative_date(text: &str) -> Option<DateExpr> {
+    // in two days, in 2 days, in a week, 3 days ago, a week ago
+
+    if let Some(caps) = AGO.captures(text) {
+        let num = parse_number(&caps["num"]).filter(|num| num.fract() == 0.0)? as i32;
+        return Some(match caps["unit"].to_lowercase().as_str() {
+            "day" => DateExpr::InNDays(-num),
+            "week" => DateExpr::InNDays(-7 * num),
+            "month" => DateExpr::InNMonths(-num),
+            _ => DateExpr::InNYears(-num),
+        });
+    }
+
+    let caps = IN_DAYS.captures(text)?;
+    let num = parse_number(&caps["num"])?;
+    // "in two and a half days" is not a date
+    if num.fract() != 0.0 {
+        return None;
+    }
+    match caps["unit"].to_lowercase().as_str() {
+        "week" => Some(DateExpr::InNDays(7 * num as i32)),
+        _ => Some(DateExpr::InNDays(num as i32)),
+    }
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNBusinessDays(i32)`
+fn parse_business_days(text: &str) -> Option<DateExpr> {
+    // in 3 business days, 2 working days from now, next business day
+
+    if NEXT_BUSINESS_DAY.is_match(text) {
+        return Some(DateExpr::InNBusinessDays(1));
+    }
+
+    let caps = IN_BUSINESS_DAYS.captures(text)?;
+    let num = caps.name("in").or_else(|| caps.name("from"))?;
+    Some(DateExpr::InNBusinessDays(num.as_str().parse().unwrap()))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::Ordinal(Option<i32>, u32)`
+fn parse_ordinal(text: &str) -> Option<DateExpr> {
+    // day 200 of 2025, julian day 045, 2025-200
+
+    let caps = ORDINAL_DATE.captures(text)?;
+    let ddd = caps
+        .name("ddd")
+        .or_else(|| caps.name("day"))
+        .or_else(|| caps.name("iso_ddd"))?;
+    let year = caps
+        .name("year")
+        .or_else(|| caps.name("day_year"))
+        .or_else(|| caps.name("iso_year"));
+    Some(DateExpr::Ordinal(
+        year.map(|y| y.as_str().parse().unwrap()),
+        ddd.as_str().parse().unwrap(),
+    ))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::Season(Option<PeriodPart>, Season, i32)` or a
+/// `DateExpr::SeasonOfYear(Option<PeriodPart>, Season, i32)`.
+fn parse_season(text: &str) -> Option<DateExpr> {
+    // this summer, early fall, in the winter, spring 2025
+
+    let caps = SEASON.captures(text)?;
+    let season = caps.name("season").or_else(|| caps.name("bare_season"))?;
+    let year = caps.name("year").or_else(|| caps.name("bare_year"));
+    let season = match season.as_str().to_lowercase().as_str() {
+        "spring" => Season::Spring,
+        "summer" => Season::Summer,
+        "winter" => Season::Winter,
+        _ => Season::Fall,
+    };
+    let part = caps
+        .name("part")
+        .map(|m| match m.as_str().to_lowercase().as_str() {
+            "early" => PeriodPart::Early,
+            "mid" => PeriodPart::Mid,
+            _ => PeriodPart::Late,
+        });
+    if let Some(year) = year {
+        return Some(DateExpr::SeasonOfYear(
+            part,
+            season,
+            year.as_str().parse().ok()?,
+        ));
+    }
+    let n = match caps
+        .name("rel")
+        .map(|m| m.as_str().to_lowercase())
+        .as_deref()
+    {
+        Some("next") => 1,
+        Some("last") => -1,
+        _ => 0,
+    };
+    Some(DateExpr::Season(part, season, n))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::Quarter(u32, Option<i32>)`.
+fn parse_quarter(text: &str) -> Option<DateExpr> {
+    // Q3, Q1 2025, the first quarter of 2025
+
+    let caps = QUARTER.captures(text)?;
+    let q = match caps.name("q") {
+        Some(q) => q.as_str().parse().ok()?,
+        None => match caps["nth"].to_lowercase().as_str() {
+            "first" | "1st" => 1,
+            "second" | "2nd" => 2,
+            "third" | "3rd" => 3,
+            _ => 4,
+        },
+    };
+    let year = caps.name("year").and_then(|y| y.as_str().parse().ok());
+    Some(DateExpr::Quarter(q, year))
+}
+
+/// Returns the first and last day of a season or quarter with respect to `now`, reading those in no given year in
+/// `direction`, or `None` for other expressions.
+fn period_span(
+    date_expr: &DateExpr,
+    now: NaiveDate,
+    direction: Direction,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let (first_month, months, year, yearless) = match *date_expr {
+        DateExpr::Season(part, season, n) => {
+            // January and February are still last year's winter
+            let this = now.year() - (season == Season::Winter && now.month() <= 2) as i32;
+            let (first, months) = season_months(part, season);
+            (first, months, this + n, n == 0)
+        }
+        DateExpr::SeasonOfYear(part, season, year) => {
+            let (first, months) = season_months(part, season);
+            (first, months, year, false)
+        }
+        DateExpr::Quarter(q, year) => (
+            3 * q as i32 - 2,
+            3,
+            year.unwrap_or(now.year()),
+            year.is_none(),
+        ),
+        _ => return None,
+    };
+    let span = |year: i32| {
+        let (start, _) = month_span(year, first_month)?;
+        let (_, end) = month_span(year, first_month + months - 1)?;
+        Some((start, end))
+    };
+    match span(year)? {
+        (start, _) if yearless && direction == Direction::Past && start > now => span(year - 1),
+        found => Some(found),
+    }
+}
+
+/// Returns the first month of `part` of `season` and how many months it lasts.
+fn season_months(part: Option<PeriodPart>, season: Season) -> (i32, i32) {
+    let first = season as i32;
+    match part {
+        None => (first, 3),
+        Some(PeriodPart::Early) => (first, 1),
+        Some(PeriodPart::Mid) => (first + 1, 1),
+        Some(PeriodPart::Late) => (first + 2, 1),
+    }
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::StartOfWeek(i32)`, `DateExpr::EndOfMonth(i32)` or another
+/// start or end of a period.
+fn parse_period_edge(text: &str) -> Option<DateExpr> {
+    // end of this month, the beginning of next year, start of the week
+
+    let caps = PERIOD.captures(text)?;
+    let edge = caps.name("edge")?.as_str().to_lowercase();
+    let n = match caps
+        .name("rel")
+        .map(|m| m.as_str().to_lowercase())
+        .as_deref()
+    {
+        Some("next") => 1,
+        Some("last") => -1,
+        _ => 0,
+    };
+    let start = edge != "end";
+    Some(match (caps["unit"].to_lowercase().as_str(), start) {
+        ("week", true) => DateExpr::StartOfWeek(n),
+        ("week", false) => DateExpr::EndOfWeek(n),
+        ("month", true) => DateExpr::StartOfMonth(n),
+        ("month", false) => DateExpr::EndOfMonth(n),
+        (_, true) => DateExpr::StartOfYear(n),
+        (_, false) => DateExpr::EndOfYear(n),
+    })
 }
 
-/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
-fn parse_relative_date(text: &str) -> Option<DateExpr> {
-    // in two days, in 2 days
+/// Parses a `str` into an `Option` containing a `DateExpr::StartOfWeek(i32)` or `DateExpr::StartOfYear(i32)`, or a
+/// `DateExpr::DayInNWeeks(i8, Weekday)` when a weekday is given with the week.
+fn parse_next_period(text: &str) -> Option<DateExpr> {
+    // next week, last year, Friday next week
 
-    let re = Regex::new(r"(in\s(?P<num>\d{1,3})\s(days?))").unwrap();
-    if let Some(caps) = re.captures(text) {
-        if let Some(num_match) = caps.name("num") {
-            let num: i32 = num_match.as_str().parse().unwrap();
-            return Some(DateExpr::InNDays(num));
-        }
+    let caps = PERIOD.captures(text)?;
+    let n = match caps.name("bare_rel")?.as_str().to_lowercase().as_str() {
+        "next" => 1,
+        _ => -1,
+    };
+    if caps["bare_unit"].eq_ignore_ascii_case("year") {
+        return Some(DateExpr::StartOfYear(n));
+    }
+    match parse_day_alone(text) {
+        Some(DateExpr::DayInNWeeks(_, d)) => Some(DateExpr::DayInNWeeks(n as i8, d)),
+        _ => Some(DateExpr::StartOfWeek(n)),
     }
-
-    None
 }
 
 /// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
 fn parse_relative_month(text: &str) -> Option<DateExpr> {
     // this month, next month, last month
-    let re = Regex::new(r"(?i)(?P<prep>next|last|this)\smonth").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = RELATIVE_MONTH.captures(text) {
         if let Some(prep_match) = caps.name("prep") {
             let p = match prep_match.as_str().to_lowercase().as_ref() {
                 "next" => 1,
@@ -364,26 +1977,21 @@ fn parse_relative_month(text: &str) -> Option<DateExpr> {
     None
 }
 
-/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`, or a `DateExpr::InNYears(i32)` for years
 fn parse_in_n_months(text: &str) -> Option<DateExpr> {
-    // in 2 months
+    // in 2 months, in a month, in three years
 
-    let re = Regex::new(r"(in\s(?P<num>\d{1,3})\s(months?))").unwrap();
-    if let Some(caps) = re.captures(text) {
-        if let Some(num_match) = caps.name("num") {
-            let num: i32 = num_match.as_str().parse().unwrap();
-            return Some(DateExpr::InNMonths(num));
-        }
+    let caps = IN_MONTHS.captures(text)?;
+    let num = parse_number(&caps["num"]).filter(|num| num.fract() == 0.0)? as i32;
+    match caps["unit"].to_lowercase().as_str() {
+        "year" => Some(DateExpr::InNYears(num)),
+        _ => Some(DateExpr::InNMonths(num)),
     }
-
-    None
 }
 
 /// Parses a `str` into an `Option` containing a `MonthOfYear`.
 fn parse_month_of_year_english(text: &str) -> Option<MonthOfYear> {
-    let re = Regex::new(r"(?i)(?P<month>jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = MONTH_NAME.captures(text) {
         if let Some(month_match) = caps.name("month") {
             match month_match.as_str().to_lowercase().as_ref() {
                 "jan" => return Some(MonthOfYear::Jan),
@@ -410,7 +2018,7 @@ mod date_expr_tests {
     use super::{
         num_to_month, DateExpr,
         MonthOfYear::{self, *},
-        Recognizable,
+        PeriodPart, Recognizable, Season,
     };
 
     use chrono::Weekday::{self, *};
@@ -427,10 +2035,19 @@ mod date_expr_tests {
 
     #[test]
     fn in_year_tests() {
-        assert_recognize_in_year("12/15/19", 12, 15, 19);
+        assert_recognize_in_year("12/15/19", 12, 15, 2019);
         assert_recognize_in_year("12/15/2000", 12, 15, 2000);
     }
 
+    #[test]
+    fn iso_date_tests() {
+        assert_recognize_in_year("Launch 2021-06-05", 6, 5, 2021);
+        assert_recognize_in_year("2021-06-05T14:30:00Z", 6, 5, 2021);
+        assert_recognize_in_year("deploy at 2021-12-31 23:00", 12, 31, 2021);
+        assert_eq!(DateExpr::recognize("2021-02-30"), None);
+        assert_eq!(DateExpr::recognize("2021-13-05"), None);
+    }
+
     #[test]
     fn absolute_english_date_tests() {
         assert_recognize_in_month("Jun 15", Jun, 15);
@@ -440,6 +2057,30 @@ mod date_expr_tests {
         assert_recognize_in_month("Jan 15", Jan, 15);
         assert_recognize_in_month("February 5th", Feb, 5);
         assert_recognize_in_month("May 25", May, 25);
+
+        assert_recognize_in_year("June 5 2026", 6, 5, 2026);
+        assert_recognize_in_year("Party June 5th, 2026 at 7", 6, 5, 2026);
+        assert_recognize_in_year("Jun 5 '26", 6, 5, 2026);
+        assert_recognize_in_year("4 July '27", 7, 4, 2027);
+        // a year without a day is not one
+        assert_eq!(DateExpr::recognize("Launch in June 2026"), None);
+    }
+
+    #[test]
+    fn day_first_english_date_tests() {
+        assert_recognize_in_month("Party 4 July", Jul, 4);
+        assert_recognize_in_month("the 1st of June", Jun, 1);
+        assert_recognize_in_month("5th of Sept.", Sep, 5);
+        assert_recognize_in_month("Dinner 21st December at 7", Dec, 21);
+        assert_recognize_in_year("4 July 2021", 7, 4, 2021);
+        assert_recognize_in_year("on the 21st of June, 2022", 6, 21, 2022);
+        assert_recognize_in_year("12 jun 2023", 6, 12, 2023);
+        // the date written first is read
+        assert_recognize_in_month("3 May or June 7", May, 3);
+        assert_recognize_in_month("June 7 or 3 May", Jun, 7);
+        // words starting like a month are not one
+        assert_eq!(DateExpr::recognize("run 3 marathons"), None);
+        assert_eq!(DateExpr::recognize("at 5 maybe"), None);
     }
 
     #[test]
@@ -447,7 +2088,24 @@ mod date_expr_tests {
         assert_in_n_days("Lunch in 6 days", 6);
         assert_in_n_days("Lunch in 1 day", 1);
         assert_in_n_days("Lunch in 300 days", 300);
-        // assert_in_n_days("Lunch in six days", 6);
+        assert_in_n_days("Lunch in six days", 6);
+        assert_in_n_days("Lunch in Three days", 3);
+        assert_in_n_days("Lunch in twenty-one days", 21);
+        assert_in_n_days("Lunch in a day", 1);
+    }
+
+    #[test]
+    fn ago_tests() {
+        assert_in_n_days("Met Bob 3 days ago", -3);
+        assert_in_n_days("a day ago", -1);
+        assert_in_n_days("Dinner a week ago", -7);
+        assert_in_n_days("two weeks ago", -14);
+        assert_relative_month("moved in 2 months ago", -2);
+        assert_eq!(
+            DateExpr::recognize("Graduated a year ago"),
+            Some(DateExpr::InNYears(-1))
+        );
+        assert_eq!(DateExpr::recognize("two and a half days ago"), None);
     }
 
     #[test]
@@ -456,6 +2114,13 @@ mod date_expr_tests {
         assert_day_in_n_weeks("last wed", Wed, -1);
         assert_day_in_n_weeks("this monday", Mon, 0);
         assert_day_in_n_weeks("next friday", Fri, 1);
+        assert_day_in_n_weeks("this past Monday", Mon, -1);
+        assert_day_in_n_weeks("This Past sat", Sat, -1);
+        assert_day_in_n_weeks("next tues", Tue, 1);
+        assert_day_in_n_weeks("this Thurs", Thu, 0);
+        assert_day_in_n_weeks("last weds", Wed, -1);
+        assert_day_in_n_weeks("Lunch wednesday", Wed, 0);
+        assert_day_in_n_weeks("Call Simon thur", Thu, 0);
     }
 
     #[test]
@@ -463,12 +2128,33 @@ mod date_expr_tests {
         assert_in_n_days("tomorrow", 1);
         assert_in_n_days("yesterday", -1);
         assert_in_n_days("today", 0);
+        assert_in_n_days("last night", -1);
+        assert_in_n_days("Pizza last night", -1);
+        assert_in_n_days("Party the day after tomorrow", 2);
+        assert_in_n_days("day after tomorrow", 2);
+        assert_in_n_days("Lunch the day before yesterday", -2);
+        assert_in_n_days("Drinks the night before last", -2);
+        assert_in_n_days("Dinner tomorrow night", 1);
     }
 
     #[test]
     fn relative_month_tests() {
         assert_relative_month("in 4 months", 4);
         assert_relative_month("in 1 month", 1);
+        assert_relative_month("in a month", 1);
+        assert_relative_month("Trip In Two Months", 2);
+        assert_eq!(
+            DateExpr::recognize("in a year"),
+            Some(DateExpr::InNYears(1))
+        );
+        assert_eq!(
+            DateExpr::recognize("in 3 years"),
+            Some(DateExpr::InNYears(3))
+        );
+        assert_in_n_days("Trip in a week", 7);
+        assert_in_n_days("in 2 weeks", 14);
+        assert_in_n_days("in three weeks", 21);
+        assert_eq!(DateExpr::recognize("in two and a half weeks"), None);
     }
 
     #[test]
@@ -477,6 +2163,106 @@ mod date_expr_tests {
         assert_relative_month("this month", 0);
     }
 
+    #[test]
+    fn ordinal_tests() {
+        assert_ordinal("day 200 of 2025", Some(2025), 200);
+        assert_ordinal("Day 7, 2021", Some(2021), 7);
+        assert_ordinal("julian day 045", None, 45);
+        assert_ordinal("shipment 2025-200", Some(2025), 200);
+        // a day without "julian" or a year is a day of something else
+        assert_eq!(DateExpr::recognize("Day 2 of the conference"), None);
+        assert_eq!(DateExpr::recognize("Sprint day 3 standup"), None);
+    }
+
+    #[test]
+    fn period_tests() {
+        let recognize = |text| DateExpr::recognize(text).unwrap();
+        assert_eq!(recognize("Review next week"), DateExpr::StartOfWeek(1));
+        assert_eq!(recognize("last week"), DateExpr::StartOfWeek(-1));
+        assert_eq!(
+            recognize("Report due end of this month"),
+            DateExpr::EndOfMonth(0)
+        );
+        assert_eq!(
+            recognize("by the end of the month"),
+            DateExpr::EndOfMonth(0)
+        );
+        assert_eq!(recognize("end of month"), DateExpr::EndOfMonth(0));
+        assert_eq!(recognize("start of next month"), DateExpr::StartOfMonth(1));
+        assert_eq!(
+            recognize("beginning of next year"),
+            DateExpr::StartOfYear(1)
+        );
+        assert_eq!(recognize("Next Year"), DateExpr::StartOfYear(1));
+        assert_eq!(recognize("the end of last year"), DateExpr::EndOfYear(-1));
+        assert_eq!(recognize("end of the week"), DateExpr::EndOfWeek(0));
+        assert_eq!(recognize("Friday next week"), DateExpr::DayInNWeeks(1, Fri));
+        assert_eq!(recognize("next month"), DateExpr::InNMonths(1));
+    }
+
+    #[test]
+    fn season_and_quarter_tests() {
+        let recognize = |text| DateExpr::recognize(text);
+        assert_eq!(
+            recognize("Vacation this summer"),
+            Some(DateExpr::Season(None, Season::Summer, 0))
+        );
+        assert_eq!(
+            recognize("Trip early fall"),
+            Some(DateExpr::Season(Some(PeriodPart::Early), Season::Fall, 0))
+        );
+        assert_eq!(
+            recognize("late-autumn hike"),
+            Some(DateExpr::Season(Some(PeriodPart::Late), Season::Fall, 0))
+        );
+        assert_eq!(
+            recognize("Ski trip next winter"),
+            Some(DateExpr::Season(None, Season::Winter, 1))
+        );
+        assert_eq!(
+            recognize("Review in the spring"),
+            Some(DateExpr::Season(None, Season::Spring, 0))
+        );
+        assert_eq!(
+            recognize("Ski trip winter 2025"),
+            Some(DateExpr::SeasonOfYear(None, Season::Winter, 2025))
+        );
+        assert_eq!(recognize("Planning Q3"), Some(DateExpr::Quarter(3, None)));
+        assert_eq!(
+            recognize("Q1 2025 kickoff"),
+            Some(DateExpr::Quarter(1, Some(2025)))
+        );
+        assert_eq!(
+            recognize("Report first quarter of 2025"),
+            Some(DateExpr::Quarter(1, Some(2025)))
+        );
+        assert_eq!(
+            recognize("the 4th quarter"),
+            Some(DateExpr::Quarter(4, None))
+        );
+        assert_eq!(recognize("Summer Camp"), None);
+        assert_eq!(recognize("Don't fall"), None);
+    }
+
+    #[test]
+    fn nth_of_month_tests() {
+        let nth = |n| Some(DateExpr::NthOfMonth(n));
+        assert_eq!(DateExpr::recognize("Rent due the 15th"), nth(15));
+        assert_eq!(DateExpr::recognize("dinner on the 3rd"), nth(3));
+        assert_eq!(DateExpr::recognize("The 21st"), nth(21));
+        assert_eq!(DateExpr::recognize("the 32nd"), None);
+        assert_eq!(DateExpr::recognize("the 5th of"), None);
+        assert_eq!(DateExpr::recognize("5th avenue"), None);
+    }
+
+    #[test]
+    fn business_day_tests() {
+        assert_business_days("due in 3 business days", 3);
+        assert_business_days("2 working days from now", 2);
+        assert_business_days("next business day", 1);
+        assert_in_n_days("in 3 days", 3);
+    }
+
     fn assert_recognize_in_month(text: &str, expected_m: MonthOfYear, expected_d: u32) {
         assert_eq!(
             DateExpr::recognize(text),
@@ -499,6 +2285,20 @@ mod date_expr_tests {
         assert_eq!(DateExpr::recognize(text), Some(DateExpr::DayInNWeeks(n, d)))
     }
 
+    fn assert_ordinal(text: &str, expected_y: Option<i32>, expected_ddd: u32) {
+        assert_eq!(
+            DateExpr::recognize(text),
+            Some(DateExpr::Ordinal(expected_y, expected_ddd))
+        )
+    }
+
+    fn assert_business_days(text: &str, expected_n: i32) {
+        assert_eq!(
+            DateExpr::recognize(text),
+            Some(DateExpr::InNBusinessDays(expected_n))
+        )
+    }
+
     fn assert_relative_month(text: &str, expected_n: i32) {
         assert_eq!(
             DateExpr::recognize(text),
@@ -507,6 +2307,949 @@ mod date_expr_tests {
     }
 }
 
+#[cfg(test)]
+mod date_parser_tests {
+    use super::{
+        DateExpr, DateOptions, DateOrder, DateParser, Direction, HolidayDate, MonthOfYear,
+        MonthSpec, NextWeekdayPolicy,
+    };
+    use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+
+    #[test]
+    fn datetime_anchor_tests() {
+        assert_find_at("in 2 hours", at(23, 30), ymd(2021, 3, 2));
+        assert_find_at("in 45 minutes", at(23, 50), ymd(2021, 3, 2));
+        assert_find_at("in 2 hours", at(20, 0), ymd(2021, 3, 1));
+        assert_find_at("soon", at(23, 45), ymd(2021, 3, 2));
+        assert_find_at("tomorrow", at(23, 50), ymd(2021, 3, 2));
+        assert_eq!(
+            DateParser::parse_relative_datetime("tonight", at(23, 50)),
+            None
+        );
+    }
+
+    #[test]
+    fn season_and_quarter_span_tests() {
+        let parser = DateParser::new();
+        let now = ymd(2021, 1, 20);
+        let span = |text| parser.find_span(text, now);
+        assert_eq!(
+            span("Vacation this summer"),
+            Some((ymd(2021, 6, 1), ymd(2021, 8, 31)))
+        );
+        assert_eq!(
+            span("Trip early fall"),
+            Some((ymd(2021, 9, 1), ymd(2021, 9, 30)))
+        );
+        // January is still the winter that started in December
+        assert_eq!(
+            span("Skiing this winter"),
+            Some((ymd(2020, 12, 1), ymd(2021, 2, 28)))
+        );
+        assert_eq!(
+            span("Skiing next winter"),
+            Some((ymd(2021, 12, 1), ymd(2022, 2, 28)))
+        );
+        assert_eq!(
+            span("Skiing winter 2023"),
+            Some((ymd(2023, 12, 1), ymd(2024, 2, 29)))
+        );
+        assert_eq!(
+            span("Planning Q3"),
+            Some((ymd(2021, 7, 1), ymd(2021, 9, 30)))
+        );
+        assert_eq!(
+            span("Report first quarter of 2025"),
+            Some((ymd(2025, 1, 1), ymd(2025, 3, 31)))
+        );
+        assert_eq!(
+            DateParser::new()
+                .with_direction(Direction::Past)
+                .find_span("Sales in Q4", now),
+            Some((ymd(2020, 10, 1), ymd(2020, 12, 31)))
+        );
+        assert_eq!(parser.find("Planning Q3", now), Some(ymd(2021, 7, 1)));
+        assert_eq!(
+            DateExpr::Quarter(2, Some(2022)).resolve_span(now),
+            Some((ymd(2022, 4, 1), ymd(2022, 6, 30)))
+        );
+        assert_eq!(
+            DateExpr::InNDays(1).resolve_span(now),
+            Some((ymd(2021, 1, 21), ymd(2021, 1, 21)))
+        );
+    }
+
+    #[test]
+    fn span_tests() {
+        let parser = DateParser::new();
+        let now = ymd(2021, 3, 3);
+        assert_span(
+            &parser,
+            "away all week",
+            now,
+            ymd(2021, 3, 1),
+            ymd(2021, 3, 7),
+        );
+        assert_span(
+            &parser,
+            "all next week",
+            now,
+            ymd(2021, 3, 8),
+            ymd(2021, 3, 14),
+        );
+        assert_span(
+            &parser,
+            "All of last week",
+            now,
+            ymd(2021, 2, 22),
+            ymd(2021, 2, 28),
+        );
+        assert_span(&parser, "all month", now, ymd(2021, 3, 1), ymd(2021, 3, 31));
+        assert_span(
+            &parser,
+            "all next month",
+            ymd(2021, 12, 3),
+            ymd(2022, 1, 1),
+            ymd(2022, 1, 31),
+        );
+        assert_span(
+            &parser,
+            "all of February",
+            now,
+            ymd(2021, 2, 1),
+            ymd(2021, 2, 28),
+        );
+        assert_eq!(parser.find_span("all day", now), None);
+
+        let sunday = DateParser::new().with_week_start(Weekday::Sun);
+        assert_span(
+            &sunday,
+            "all week",
+            ymd(2021, 3, 7),
+            ymd(2021, 3, 7),
+            ymd(2021, 3, 13),
+        );
+    }
+
+    #[test]
+    fn list_tests() {
+        let parser = DateParser::new();
+        let now = ymd(2021, 3, 3);
+        assert_eq!(
+            parser.find_list("Workshop June 5, 6, and 7", now),
+            Some(vec![ymd(2021, 6, 5), ymd(2021, 6, 6), ymd(2021, 6, 7)])
+        );
+        assert_eq!(
+            parser.find_list("June 5 and June 12", now),
+            Some(vec![ymd(2021, 6, 5), ymd(2021, 6, 12)])
+        );
+        assert_eq!(
+            parser.find_list("May 30, 31 & June 1", now),
+            Some(vec![ymd(2021, 5, 30), ymd(2021, 5, 31), ymd(2021, 6, 1)])
+        );
+        // Wednesday March 3rd
+        assert_eq!(
+            parser.find_list("Gym Mon and Wed", now),
+            Some(vec![ymd(2021, 3, 8), ymd(2021, 3, 3)])
+        );
+        assert_eq!(parser.find_list("June 5", now), None);
+        assert_eq!(parser.find_list("Lunch and dinner", now), None);
+    }
+
+    #[test]
+    fn offset_from_tests() {
+        let parser = DateParser::new();
+        // a Wednesday
+        let now = ymd(2021, 3, 3);
+        assert_eq!(
+            parser.find("Dinner a week from Friday", now),
+            Some(ymd(2021, 3, 12))
+        );
+        assert_eq!(
+            parser.find("Review two weeks from tomorrow", now),
+            Some(ymd(2021, 3, 18))
+        );
+        assert_eq!(
+            parser.find("Payment 3 days after June 1", now),
+            Some(ymd(2021, 6, 4))
+        );
+        assert_eq!(
+            parser.find("Shop 2 days before Christmas", now),
+            Some(ymd(2021, 12, 23))
+        );
+        assert_eq!(
+            parser.find("Christmas party Dec 20", now),
+            Some(ymd(2021, 12, 20))
+        );
+        assert_eq!(
+            parser.find("Halloween costume swap 10/29", now),
+            Some(ymd(2021, 10, 29))
+        );
+        assert_eq!(
+            parser.find("Launch 2 weeks from now", now),
+            Some(ymd(2021, 3, 17))
+        );
+        // the Monday after next Monday, however "next" is read
+        assert_eq!(
+            parser.find("Standup the Monday after next", now),
+            Some(ymd(2021, 3, 22))
+        );
+        assert_eq!(
+            parser
+                .clone()
+                .with_next_weekday_policy(NextWeekdayPolicy::NextWeek)
+                .find("Standup the Monday after next", now),
+            Some(ymd(2021, 3, 15))
+        );
+        // without a date to count from, only what is there is read
+        assert_eq!(parser.find("3 days after the launch", now), None);
+
+        assert_eq!(
+            parser.parse_expr("a week from Friday"),
+            Some(DateExpr::OffsetFrom(
+                Box::new(DateExpr::DayInNWeeks(0, Weekday::Fri)),
+                Duration::days(7)
+            ))
+        );
+        assert_eq!(
+            parser.parse_expr("2 days before Christmas"),
+            Some(DateExpr::OffsetFrom(
+                Box::new(DateExpr::Holiday(HolidayDate::Fixed(12, 25))),
+                Duration::days(-2)
+            ))
+        );
+    }
+
+    #[test]
+    fn weekend_and_weekday_range_tests() {
+        let parser = DateParser::new();
+        // a Wednesday
+        let now = ymd(2021, 3, 3);
+        let span = |parser: &DateParser, text, now| parser.find_span(text, now);
+        assert_eq!(
+            span(&parser, "BBQ this weekend", now),
+            Some((ymd(2021, 3, 6), ymd(2021, 3, 7)))
+        );
+        assert_eq!(
+            span(&parser, "BBQ next weekend", now),
+            Some((ymd(2021, 3, 13), ymd(2021, 3, 14)))
+        );
+        assert_eq!(
+            span(&parser, "hiking last weekend", now),
+            Some((ymd(2021, 2, 27), ymd(2021, 2, 28)))
+        );
+        // on a Sunday the weekend is the one under way
+        assert_eq!(
+            span(&parser, "BBQ on the weekend", ymd(2021, 3, 7)),
+            Some((ymd(2021, 3, 6), ymd(2021, 3, 7)))
+        );
+        let long = DateParser::new().with_weekend_start(Weekday::Fri);
+        assert_eq!(
+            span(&long, "BBQ this weekend", now),
+            Some((ymd(2021, 3, 5), ymd(2021, 3, 7)))
+        );
+
+        assert_eq!(
+            span(&parser, "free Mon-Wed", now),
+            Some((ymd(2021, 3, 8), ymd(2021, 3, 10)))
+        );
+        assert_eq!(
+            span(&parser, "Off Tuesday through Thursday", now),
+            Some((ymd(2021, 3, 9), ymd(2021, 3, 11)))
+        );
+        assert_eq!(
+            span(&parser, "Conference Wed–Fri", now),
+            Some((ymd(2021, 3, 3), ymd(2021, 3, 5)))
+        );
+        assert_eq!(
+            span(&parser, "Trip Fri to Mon", now),
+            Some((ymd(2021, 3, 5), ymd(2021, 3, 8)))
+        );
+    }
+
+    #[test]
+    fn week_of_tests() {
+        let parser = DateParser::new();
+        let now = ymd(2025, 3, 3);
+        assert_span(
+            &parser,
+            "Vacation the week of June 7",
+            now,
+            ymd(2025, 6, 2),
+            ymd(2025, 6, 8),
+        );
+        assert_span(
+            &DateParser::new().with_week_start(Weekday::Sun),
+            "the week of June 7",
+            now,
+            ymd(2025, 6, 1),
+            ymd(2025, 6, 7),
+        );
+        assert_span(
+            &parser,
+            "week of the 12th",
+            now,
+            ymd(2025, 3, 10),
+            ymd(2025, 3, 16),
+        );
+
+        // ISO weeks start on Monday, the first taking in the year's first Thursday
+        assert_span(
+            &parser,
+            "Sprint week 32 2025",
+            now,
+            ymd(2025, 8, 4),
+            ymd(2025, 8, 10),
+        );
+        assert_span(
+            &parser,
+            "ISO week 10",
+            now,
+            ymd(2025, 3, 3),
+            ymd(2025, 3, 9),
+        );
+        assert_span(&parser, "2025-W01", now, ymd(2024, 12, 30), ymd(2025, 1, 5));
+        assert_span(
+            &DateParser::new().with_week_start(Weekday::Sun),
+            "week 1 of 2026",
+            now,
+            ymd(2025, 12, 29),
+            ymd(2026, 1, 4),
+        );
+        assert_span(
+            &parser,
+            "week 53 2026",
+            now,
+            ymd(2026, 12, 28),
+            ymd(2027, 1, 3),
+        );
+        assert_eq!(parser.find_span("week 53 2025", now), None);
+        assert_eq!(parser.find_span("week 0", now), None);
+        assert_eq!(parser.find_span("Senior Week 6/17", now), None);
+    }
+
+    #[test]
+    fn weekend_of_tests() {
+        let parser = DateParser::new();
+        let now = ymd(2025, 3, 3);
+        // Friday, Saturday, Tuesday and Wednesday anchors
+        assert_span(
+            &parser,
+            "the weekend of July 4",
+            now,
+            ymd(2025, 7, 5),
+            ymd(2025, 7, 6),
+        );
+        assert_span(
+            &parser,
+            "weekend of 7/4/2026",
+            now,
+            ymd(2026, 7, 4),
+            ymd(2026, 7, 5),
+        );
+        assert_span(
+            &parser,
+            "the weekend of July 8",
+            now,
+            ymd(2025, 7, 5),
+            ymd(2025, 7, 6),
+        );
+        assert_span(
+            &parser,
+            "the weekend of July 9",
+            now,
+            ymd(2025, 7, 12),
+            ymd(2025, 7, 13),
+        );
+        assert_span(
+            &parser,
+            "the weekend of Christmas",
+            now,
+            ymd(2025, 12, 27),
+            ymd(2025, 12, 28),
+        );
+
+        let long = DateParser::new().with_weekend_start(Weekday::Fri);
+        assert_span(
+            &long,
+            "the weekend of July 4",
+            now,
+            ymd(2025, 7, 4),
+            ymd(2025, 7, 6),
+        );
+        assert_span(
+            &long,
+            "the weekend of July 7",
+            now,
+            ymd(2025, 7, 4),
+            ymd(2025, 7, 6),
+        );
+    }
+
+    #[test]
+    fn clamped_day_tests() {
+        let now = ymd(2021, 1, 10);
+        assert_eq!(
+            DateParser::parse_relative("Feb 30", now),
+            Some(ymd(2021, 2, 28))
+        );
+        assert_eq!(
+            DateParser::parse_relative("June 31", now),
+            Some(ymd(2021, 6, 30))
+        );
+        assert_eq!(
+            DateParser::parse_relative("2/29/2024", now),
+            Some(ymd(2024, 2, 29))
+        );
+        assert_eq!(DateParser::parse_relative("June 32", now), None);
+
+        // counting months and years rolls over into the next year
+        assert_eq!(
+            DateParser::parse_relative("in 3 months", ymd(2021, 11, 30)),
+            Some(ymd(2022, 2, 28))
+        );
+        assert_eq!(
+            DateParser::parse_relative("in a year", ymd(2024, 2, 29)),
+            Some(ymd(2025, 2, 28))
+        );
+        assert_eq!(
+            DateParser::parse_relative("2 years ago", ymd(2021, 3, 3)),
+            Some(ymd(2019, 3, 3))
+        );
+    }
+
+    #[test]
+    fn parse_expr_tests() {
+        let parser = DateParser::new();
+        assert_eq!(
+            parser.parse_expr("Lunch next Friday"),
+            Some(DateExpr::DayInNWeeks(1, Weekday::Fri))
+        );
+        assert_eq!(
+            parser.parse_expr("Dentist 6/5/2021"),
+            Some(DateExpr::InYear(MonthOfYear::Jun, 5, 2021))
+        );
+        assert_eq!(
+            parser.parse_expr("in 3 business days"),
+            Some(DateExpr::InNBusinessDays(3))
+        );
+        assert_eq!(
+            parser.parse_expr("Party on Thanksgiving"),
+            Some(DateExpr::Holiday(HolidayDate::NthWeekday(
+                4,
+                Weekday::Thu,
+                11
+            )))
+        );
+        assert_eq!(
+            parser.parse_expr("New Year's Eve party"),
+            Some(DateExpr::Holiday(HolidayDate::Fixed(12, 31)))
+        );
+        // a holiday named as a word gives way to the date stated
+        assert_eq!(
+            parser.parse_expr("Christmas party Dec 20"),
+            Some(DateExpr::InMonth(MonthOfYear::Dec, 20))
+        );
+        assert_eq!(parser.parse_expr("Independence Day observed"), None);
+        assert_eq!(parser.parse_expr("Pay $6/5"), None);
+        assert_eq!(
+            DateParser::new()
+                .with_locale("en_GB")
+                .parse_expr("Dentist 6/5"),
+            Some(DateExpr::InMonth(MonthOfYear::May, 6))
+        );
+
+        let now = ymd(2021, 3, 3);
+        assert_eq!(DateExpr::InNDays(1).resolve(now), Some(ymd(2021, 3, 4)));
+        assert_eq!(DateExpr::EndOfMonth(0).resolve(now), Some(ymd(2021, 3, 31)));
+        assert_eq!(
+            DateExpr::InMonth(MonthOfYear::Feb, 30).resolve(now),
+            Some(ymd(2021, 2, 28))
+        );
+        assert_eq!(DateExpr::InNMonths(12).resolve(now), Some(ymd(2022, 3, 3)));
+        // months are counted across years, clamping days past the end of the month
+        assert_eq!(
+            DateExpr::InNMonths(3).resolve(ymd(2021, 11, 30)),
+            Some(ymd(2022, 2, 28))
+        );
+        assert_eq!(
+            DateExpr::InNMonths(1).resolve(ymd(2021, 1, 31)),
+            Some(ymd(2021, 2, 28))
+        );
+        assert_eq!(
+            DateExpr::InNMonths(-2).resolve(ymd(2021, 1, 15)),
+            Some(ymd(2020, 11, 15))
+        );
+        assert_eq!(DateExpr::InNYears(3).resolve(now), Some(ymd(2024, 3, 3)));
+        // February 29 falls back to February 28 in a year that is not a leap year
+        assert_eq!(
+            DateExpr::InNYears(1).resolve(ymd(2024, 2, 29)),
+            Some(ymd(2025, 2, 28))
+        );
+        assert_eq!(
+            DateExpr::InNYears(-4).resolve(ymd(2024, 2, 29)),
+            Some(ymd(2020, 2, 29))
+        );
+        for text in &[
+            "tomorrow",
+            "next Friday",
+            "June 5th",
+            "the 15th",
+            "end of next month",
+            "Memorial Day",
+            "Christmas",
+        ] {
+            assert_eq!(
+                parser.parse_expr(text).and_then(|expr| expr.resolve(now)),
+                parser.find(text, now),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_date_tests() {
+        let now = ymd(2021, 1, 10);
+        for text in &[
+            "13/40",
+            "0/0",
+            "31/31/31",
+            "99/99/9999",
+            "2021-13-45",
+            "June 45th",
+        ] {
+            assert_eq!(DateParser::parse_relative(text, now), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn weekday_policy_tests() {
+        let days = [
+            "monday",
+            "tuesday",
+            "wednesday",
+            "thursday",
+            "friday",
+            "saturday",
+            "sunday",
+        ];
+        let upcoming = DateParser::new().with_next_weekday_policy(NextWeekdayPolicy::Upcoming);
+        let week_after_coming = DateParser::new();
+        let next_week = DateParser::new().with_next_weekday_policy(NextWeekdayPolicy::NextWeek);
+        let sunday_weeks = next_week.clone().with_week_start(Weekday::Sun);
+
+        // every weekday from Mon Mar 1 to Sun Mar 7, 2021
+        for now in (1..=7).map(|d| ymd(2021, 3, d)) {
+            for day in days {
+                let ahead = |parser: &DateParser, text: &str| {
+                    let date = parser.find(text, now).unwrap();
+                    assert_eq!(
+                        date.format("%A").to_string().to_lowercase(),
+                        day,
+                        "{} from {}",
+                        text,
+                        now
+                    );
+                    (date - now).num_days()
+                };
+                let this = ahead(&next_week, &format!("this {}", day));
+                assert!((0..=6).contains(&this), "this {} from {}", day, now);
+                let last = ahead(&next_week, &format!("last {}", day));
+                assert!((-7..=-1).contains(&last), "last {} from {}", day, now);
+
+                let next = ahead(&upcoming, &format!("next {}", day));
+                assert!(
+                    (1..=7).contains(&next),
+                    "upcoming next {} from {}",
+                    day,
+                    now
+                );
+                let next = ahead(&week_after_coming, &format!("next {}", day));
+                assert_eq!(next, this + 7, "next {} from {}", day, now);
+
+                // the Monday or Sunday of the week after this one
+                for (parser, week_start) in
+                    [(&next_week, Weekday::Mon), (&sunday_weeks, Weekday::Sun)]
+                {
+                    let next = ahead(parser, &format!("next {}", day));
+                    let into_week = now.weekday().days_since(week_start) as i64;
+                    assert!(
+                        (7..=13).contains(&(next + into_week)),
+                        "next {} from {} with weeks from {}",
+                        day,
+                        now,
+                        week_start
+                    );
+                }
+            }
+        }
+
+        // on a weekend "next Monday" is a week after the coming one by default, and the coming one by the week
+        for now in [ymd(2021, 6, 5), ymd(2021, 6, 6)] {
+            assert_eq!(
+                week_after_coming.find("next Monday", now),
+                Some(ymd(2021, 6, 14))
+            );
+            assert_eq!(next_week.find("next Monday", now), Some(ymd(2021, 6, 7)));
+        }
+    }
+
+    #[test]
+    fn nth_of_month_date_tests() {
+        let parser = DateParser::new();
+        let now = ymd(2021, 3, 15);
+        assert_eq!(parser.find("on the 20th", now), Some(ymd(2021, 3, 20)));
+        assert_eq!(parser.find("the 15th", now), Some(now));
+        assert_eq!(parser.find("the 3rd", now), Some(ymd(2021, 4, 3)));
+        // April has no 31st
+        assert_eq!(
+            parser.find("the 31st", ymd(2021, 4, 5)),
+            Some(ymd(2021, 5, 31))
+        );
+        assert_eq!(
+            parser.find("the 1st", ymd(2021, 12, 5)),
+            Some(ymd(2022, 1, 1))
+        );
+        assert_eq!(
+            parser.find("the 30th", ymd(2021, 1, 31)),
+            Some(ymd(2021, 3, 30))
+        );
+
+        let past = DateParser::new().with_direction(Direction::Past);
+        assert_eq!(past.find("the 20th", now), Some(ymd(2021, 2, 20)));
+        assert_eq!(past.find("the 10th", now), Some(ymd(2021, 3, 10)));
+        assert_eq!(past.find("the 31st", now), Some(ymd(2021, 1, 31)));
+    }
+
+    #[test]
+    fn nth_weekday_of_month_tests() {
+        let parser = DateParser::new();
+        assert_eq!(
+            parser.parse_expr("Club the first Monday of June"),
+            Some(DateExpr::NthWeekdayOfMonth(
+                1,
+                Weekday::Mon,
+                MonthSpec::Named(MonthOfYear::Jun)
+            ))
+        );
+        assert_eq!(
+            parser.parse_expr("Review last Fri of the month"),
+            Some(DateExpr::NthWeekdayOfMonth(
+                -1,
+                Weekday::Fri,
+                MonthSpec::InNMonths(0)
+            ))
+        );
+        assert_eq!(
+            parser.parse_expr("Meetup 2nd Tuesday of next month"),
+            Some(DateExpr::NthWeekdayOfMonth(
+                2,
+                Weekday::Tue,
+                MonthSpec::InNMonths(1)
+            ))
+        );
+
+        let now = ymd(2021, 3, 3);
+        assert_eq!(
+            parser.find("the first Monday of June", now),
+            Some(ymd(2021, 6, 7))
+        );
+        assert_eq!(
+            parser.find("the third Thursday in November", now),
+            Some(ymd(2021, 11, 18))
+        );
+        assert_eq!(
+            parser.find("last Friday of the month", now),
+            Some(ymd(2021, 3, 26))
+        );
+        assert_eq!(
+            parser.find("the last Sunday of next month", now),
+            Some(ymd(2021, 4, 25))
+        );
+        assert_eq!(
+            parser.find("the first Monday of last month", now),
+            Some(ymd(2021, 2, 1))
+        );
+        assert_eq!(
+            parser.find("the 2nd Tuesday of next month", ymd(2021, 12, 20)),
+            Some(ymd(2022, 1, 11))
+        );
+        // April 2021 has only four Mondays
+        assert_eq!(parser.find("the fifth Monday of next month", now), None);
+        assert_eq!(
+            parser.find("the fifth Monday of May", now),
+            Some(ymd(2021, 5, 31))
+        );
+
+        let past = DateParser::new().with_direction(Direction::Past);
+        assert_eq!(
+            past.find("the first Monday of June", now),
+            Some(ymd(2020, 6, 1))
+        );
+    }
+
+    #[test]
+    fn period_date_tests() {
+        let now = ymd(2021, 3, 3); // a Wednesday
+        let parser = DateParser::new();
+        assert_eq!(parser.find("next week", now), Some(ymd(2021, 3, 8)));
+        assert_eq!(parser.find("last week", now), Some(ymd(2021, 2, 22)));
+        assert_eq!(parser.find("end of this week", now), Some(ymd(2021, 3, 7)));
+        assert_eq!(parser.find("end of the month", now), Some(ymd(2021, 3, 31)));
+        assert_eq!(
+            parser.find("end of next month", now),
+            Some(ymd(2021, 4, 30))
+        );
+        assert_eq!(
+            parser.find("beginning of next month", now),
+            Some(ymd(2021, 4, 1))
+        );
+        assert_eq!(
+            parser.find("end of last month", now),
+            Some(ymd(2021, 2, 28))
+        );
+        assert_eq!(
+            parser.find("beginning of next year", now),
+            Some(ymd(2022, 1, 1))
+        );
+        assert_eq!(parser.find("end of the year", now), Some(ymd(2021, 12, 31)));
+        assert_eq!(parser.find("Friday next week", now), Some(ymd(2021, 3, 12)));
+        assert_eq!(
+            parser.find("end of next month", ymd(2021, 12, 15)),
+            Some(ymd(2022, 1, 31))
+        );
+
+        let sunday_weeks = DateParser::new().with_week_start(Weekday::Sun);
+        assert_eq!(sunday_weeks.find("next week", now), Some(ymd(2021, 3, 7)));
+        assert_eq!(
+            sunday_weeks.find("end of the week", now),
+            Some(ymd(2021, 3, 6))
+        );
+    }
+
+    #[test]
+    fn prefer_future_tests() {
+        let now = ymd(2021, 3, 3); // a Wednesday
+        let future = DateParser::new();
+        assert!(future.prefers_future());
+        assert_eq!(future.find("Monday", now), Some(ymd(2021, 3, 8)));
+        assert_eq!(future.find("Wednesday", now), Some(now));
+        assert_eq!(future.find("Friday", now), Some(ymd(2021, 3, 5)));
+
+        let this_week = DateParser::new().with_prefer_future(false);
+        assert_eq!(this_week.find("Monday", now), Some(ymd(2021, 3, 1)));
+        assert_eq!(this_week.find("Friday", now), Some(ymd(2021, 3, 5)));
+        assert_eq!(this_week.find("next Monday", now), Some(ymd(2021, 3, 15)));
+        let sunday_weeks = this_week.with_week_start(Weekday::Sun);
+        assert_eq!(sunday_weeks.find("Sunday", now), Some(ymd(2021, 2, 28)));
+    }
+
+    #[test]
+    fn past_direction_tests() {
+        let past = DateParser::new().with_direction(Direction::Past);
+        let now = ymd(2021, 3, 3); // a Wednesday
+        assert_eq!(past.find("Friday", now), Some(ymd(2021, 2, 26)));
+        assert_eq!(past.find("this Monday", now), Some(ymd(2021, 3, 1)));
+        assert_eq!(past.find("Wed", now), Some(now));
+        assert_eq!(past.find("last Friday", now), Some(ymd(2021, 2, 26)));
+        assert_eq!(past.find("next Friday", now), Some(ymd(2021, 3, 12)));
+        assert_eq!(past.find("June 5", now), Some(ymd(2020, 6, 5)));
+        assert_eq!(past.find("Feb 5", now), Some(ymd(2021, 2, 5)));
+        assert_eq!(past.find("3/3", now), Some(now));
+        assert_eq!(past.find("yesterday", now), Some(ymd(2021, 3, 2)));
+        assert_eq!(past.find("July 4th", now), Some(ymd(2020, 7, 4)));
+        assert_eq!(past.find("6/5/2022", now), Some(ymd(2022, 6, 5)));
+        assert_eq!(past.find("Met Bob 3 days ago", now), Some(ymd(2021, 2, 28)));
+        assert_eq!(past.find("a week ago", now), Some(ymd(2021, 2, 24)));
+        assert_eq!(past.find("two months ago", now), Some(ymd(2021, 1, 3)));
+
+        let future = DateParser::new();
+        assert_eq!(future.direction(), Direction::Future);
+        assert_eq!(future.find("Friday", now), Some(ymd(2021, 3, 5)));
+        assert_eq!(future.find("June 5", now), Some(ymd(2021, 6, 5)));
+    }
+
+    #[test]
+    fn slash_date_readings_tests() {
+        let now = ymd(2021, 3, 1);
+        let us = vec![ymd(2021, 6, 5), ymd(2021, 5, 6)];
+        let eu = vec![ymd(2021, 5, 6), ymd(2021, 6, 5)];
+        assert_eq!(
+            DateParser::slash_date_readings("6/5", now, "en_US.UTF-8"),
+            us
+        );
+        assert_eq!(DateParser::slash_date_readings("6/5", now, "C"), us);
+        assert_eq!(DateParser::slash_date_readings("6/5", now, ""), us);
+        assert_eq!(
+            DateParser::slash_date_readings("6/5", now, "fr_FR.UTF-8"),
+            eu
+        );
+        assert_eq!(DateParser::slash_date_readings("6/5", now, "de-DE"), eu);
+        assert_eq!(
+            DateParser::slash_date_readings("Trip 25/12/2022", now, "en_US"),
+            vec![ymd(2022, 12, 25)]
+        );
+        assert_eq!(
+            DateParser::slash_date_readings("Party 6/5/21", now, "en_US"),
+            vec![ymd(2021, 6, 5), ymd(2021, 5, 6)]
+        );
+        assert_eq!(
+            DateParser::slash_date_readings("5/5", now, "en_GB"),
+            vec![ymd(2021, 5, 5)]
+        );
+        assert_eq!(
+            DateParser::slash_date_readings("June 5", now, "en_GB"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn candidates_tests() {
+        let now = ymd(2021, 3, 1);
+        let parser = DateParser::new();
+        assert_eq!(
+            parser.candidates("Party on 3/4", now),
+            vec![(ymd(2021, 3, 4), 0.6), (ymd(2021, 4, 3), 0.4)]
+        );
+        // with nothing marking it as a date, it may be a fraction
+        let candidates = parser.candidates("add 3/4", now);
+        assert_eq!(candidates[0].0, ymd(2021, 3, 4));
+        assert!(candidates[0].1 < 0.6);
+        assert_eq!(
+            parser.candidates("Fri 3/5", now),
+            vec![(ymd(2021, 3, 5), 0.6), (ymd(2021, 5, 3), 0.4)]
+        );
+        assert_eq!(
+            parser.candidates("Party 6/25", now),
+            vec![(ymd(2021, 6, 25), 0.8)]
+        );
+        assert_eq!(
+            parser.candidates("Party on 3/4/2022", now),
+            vec![(ymd(2022, 3, 4), 0.6), (ymd(2022, 4, 3), 0.4)]
+        );
+        assert_eq!(
+            parser.candidates("Party on 3/4/22", now),
+            vec![(ymd(2022, 3, 4), 0.6), (ymd(2022, 4, 3), 0.4)]
+        );
+        assert_eq!(
+            DateParser::new()
+                .with_locale("en_GB")
+                .candidates("Party on 3/4", now),
+            vec![(ymd(2021, 4, 3), 0.6), (ymd(2021, 3, 4), 0.4)]
+        );
+        assert_eq!(
+            parser.candidates("Party tomorrow", now),
+            vec![(ymd(2021, 3, 2), 1.0)]
+        );
+        assert_eq!(parser.candidates("Party", now), vec![]);
+    }
+
+    #[test]
+    fn locale_tests() {
+        let now = ymd(2021, 3, 1);
+        let gb = DateParser::new().with_locale("en_GB");
+        assert_eq!(gb.find("Party 5/6", now), Some(ymd(2021, 6, 5)));
+        assert_eq!(gb.find("Party 6/25", now), Some(ymd(2021, 6, 25)));
+        assert_eq!(gb.find("Trip 1/2/2022", now), Some(ymd(2022, 2, 1)));
+        assert_eq!(gb.find("June 5", now), Some(ymd(2021, 6, 5)));
+        let us = DateParser::new().with_locale("en_US");
+        assert_eq!(us.find("Party 5/6", now), Some(ymd(2021, 5, 6)));
+    }
+
+    #[cfg(feature = "locale-es")]
+    #[test]
+    fn spanish_date_tests() {
+        // a Monday
+        let now = ymd(2021, 3, 1);
+        let es = DateParser::new().with_locale("es_MX");
+        assert_eq!(es.find("Cena mañana", now), Some(ymd(2021, 3, 2)));
+        assert_eq!(es.find("Fiesta el viernes", now), Some(ymd(2021, 3, 5)));
+        assert_eq!(es.find("Boda el 5 de junio", now), Some(ymd(2021, 6, 5)));
+        assert_eq!(es.find("Reunión en 3 días", now), Some(ymd(2021, 3, 4)));
+        assert_eq!(es.find("Cena con amigos", now), None);
+        assert_eq!(
+            DateParser::parse_with_locale("Boda el 5 de junio", "es").map(|d| (d.month(), d.day())),
+            Some((6, 5))
+        );
+    }
+
+    #[cfg(feature = "locale-fr")]
+    #[test]
+    fn french_date_tests() {
+        let now = ymd(2021, 3, 1);
+        let fr = DateParser::new().with_locale("fr_FR");
+        assert_eq!(fr.find("Dîner demain", now), Some(ymd(2021, 3, 2)));
+        assert_eq!(fr.find("Réunion le 5 juin", now), Some(ymd(2021, 6, 5)));
+        assert_eq!(fr.find("Fête vendredi", now), Some(ymd(2021, 3, 5)));
+    }
+
+    #[cfg(feature = "locale-de")]
+    #[test]
+    fn german_date_tests() {
+        let now = ymd(2021, 3, 1);
+        let de = DateParser::new().with_locale("de_DE");
+        assert_eq!(de.find("Essen morgen", now), Some(ymd(2021, 3, 2)));
+        assert_eq!(de.find("Urlaub am 5. Juni", now), Some(ymd(2021, 6, 5)));
+        assert_eq!(de.find("Party am Freitag", now), Some(ymd(2021, 3, 5)));
+        // English is still read
+        assert_eq!(de.find("Party June 5", now), Some(ymd(2021, 6, 5)));
+    }
+
+    #[test]
+    fn date_order_tests() {
+        let now = ymd(2021, 3, 1);
+        let with_order = |order| DateParser::new().with_options(DateOptions { order });
+
+        let mdy = with_order(DateOrder::MonthDayYear);
+        assert_eq!(mdy.find("Party 5/6", now), Some(ymd(2021, 5, 6)));
+        assert_eq!(mdy.find("Trip 5/6/2022", now), Some(ymd(2022, 5, 6)));
+
+        let dmy = with_order(DateOrder::DayMonthYear);
+        assert_eq!(dmy.find("Party 5/6", now), Some(ymd(2021, 6, 5)));
+        assert_eq!(dmy.find("Trip 5/6/2022", now), Some(ymd(2022, 6, 5)));
+        assert_eq!(dmy.find("Party 6/25", now), Some(ymd(2021, 6, 25)));
+
+        let ymd_order = with_order(DateOrder::YearMonthDay);
+        assert_eq!(ymd_order.find("Trip 2022/5/6", now), Some(ymd(2022, 5, 6)));
+        assert_eq!(ymd_order.find("Party 5/6", now), Some(ymd(2021, 5, 6)));
+
+        // the locale and the options both set the order, whichever comes last wins
+        let gb = DateParser::new()
+            .with_options(DateOptions::default())
+            .with_locale("en_GB");
+        assert_eq!(gb.find("Party 5/6", now), Some(ymd(2021, 6, 5)));
+    }
+
+    fn assert_span(
+        parser: &DateParser,
+        text: &str,
+        now: NaiveDate,
+        expected_start: NaiveDate,
+        expected_end: NaiveDate,
+    ) {
+        assert_eq!(
+            parser.find_span(text, now),
+            Some((expected_start, expected_end))
+        )
+    }
+
+    fn assert_find_at(text: &str, now: NaiveDateTime, expected: NaiveDate) {
+        assert_eq!(
+            DateParser::parse_relative_datetime(text, now),
+            Some(expected)
+        )
+    }
+
+    fn at(h: u32, m: u32) -> NaiveDateTime {
+        ymd(2021, 3, 1).and_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
+
 mod month_of_year_tests {
     use super::{
         MonthOfYear::{self},
@@ -0,0 +1,107 @@
+//! Parse durations like "1h30m", "45m" or "2 hours" into the [`Duration`](https://docs.rs/chrono/0.4.0/chrono/struct.Duration.html) format.
+
+use chrono::Duration;
+use std::ops::Range;
+
+use crate::patterns::{Pattern, CONTINENTAL_TIME, DURATION};
+
+/// An "in" just before a duration, which makes it a time from now rather than how long something lasts.
+static RELATIVE: Pattern = Pattern::new(r"(?i)\bin\s$");
+
+/// Converts shorthand captured by [`DURATION`](../patterns/static.DURATION.html) into a `Duration`.
+pub(crate) fn shorthand_duration(caps: &regex::Captures) -> Duration {
+    let number = |name: &str| {
+        caps.name(name)
+            .map_or(0, |m| m.as_str().parse::<i64>().unwrap_or(0))
+    };
+    Duration::hours(number("h")) + Duration::minutes(number("m") + number("mins"))
+}
+
+#[derive(Default)]
+/// Container for parsing durations from string slices.
+pub struct DurationParser {}
+
+impl DurationParser {
+    /// Parses a string slice of natural language text for how long something lasts, like "2h" or "90 minutes".
+    /// A duration introduced by "in", like "in 1h30m", is a relative time rather than a duration and is skipped, as is
+    /// an hour like "at 19h". Returns a [`Duration`](https://docs.rs/chrono/0.4.0/chrono/struct.Duration.html) if a
+    /// match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::Duration;
+    /// use date_time_parser::DurationParser;
+    ///
+    /// assert_eq!(DurationParser::parse("deep work 2h tomorrow"), Some(Duration::hours(2)));
+    /// assert_eq!(DurationParser::parse("standup 15m"), Some(Duration::minutes(15)));
+    /// assert_eq!(DurationParser::parse("block 2 hours"), Some(Duration::hours(2)));
+    /// assert_eq!(DurationParser::parse("call back in 1h30m"), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Duration> {
+        DurationParser::find(text).map(|(duration, _)| duration)
+    }
+
+    /// Parses `text` for a duration like [`parse`](#method.parse), also returning where it is in the text, so the
+    /// number in "2 hours" is not read again as a time.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::Duration;
+    /// use date_time_parser::DurationParser;
+    ///
+    /// assert_eq!(DurationParser::find("block 2 hours"), Some((Duration::hours(2), 6..13)));
+    /// ```
+    pub fn find(text: &str) -> Option<(Duration, Range<usize>)> {
+        let times: Vec<_> = CONTINENTAL_TIME
+            .find_iter(text)
+            .map(|m| m.range())
+            .collect();
+
+        let caps = DURATION.captures_iter(text).find(|caps| {
+            let found = caps.get(0).unwrap();
+            !RELATIVE.is_match(&text[..found.start()])
+                && !times.iter().any(|time| time.contains(&found.start()))
+        })?;
+        Some((shorthand_duration(&caps), caps.get(0).unwrap().range()))
+    }
+}
+
+#[cfg(test)]
+mod duration_tests {
+    use super::DurationParser;
+    use chrono::Duration;
+
+    #[test]
+    fn shorthand_tests() {
+        assert_duration("2h", Duration::hours(2));
+        assert_duration("45m", Duration::minutes(45));
+        assert_duration("1h30m", Duration::minutes(90));
+        assert_duration("1h 30m", Duration::minutes(90));
+        assert_duration("review 90min", Duration::minutes(90));
+        assert_duration("focus 3hrs", Duration::hours(3));
+        assert_duration("block 2 hours", Duration::hours(2));
+        assert_duration("walk 1 hour 15 minutes", Duration::minutes(75));
+        assert_duration("stretch 20 mins", Duration::minutes(20));
+    }
+
+    #[test]
+    fn not_duration_tests() {
+        assert_eq!(DurationParser::parse("in 2h"), None);
+        assert_eq!(DurationParser::parse("9am"), None);
+        assert_eq!(DurationParser::parse("in 5 mins"), None);
+        assert_eq!(DurationParser::parse("2hours"), None);
+        assert_eq!(DurationParser::parse("dinner at 19h"), None);
+        assert_eq!(
+            DurationParser::parse("dîner 19h30 2h"),
+            Some(Duration::hours(2))
+        );
+    }
+
+    fn assert_duration(text: &str, expected: Duration) {
+        assert_eq!(DurationParser::parse(text), Some(expected))
+    }
+}
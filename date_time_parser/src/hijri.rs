@@ -0,0 +1,165 @@
+//! Recognize Islamic (Hijri) calendar dates and convert them into the [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) format.
+//!
+//! Conversion uses the tabular (arithmetical) Islamic calendar, so a converted date can differ by a day
+//! from a calendar based on moon sighting.
+
+use crate::calendar::{CalendarDate, CalendarSystem};
+use crate::patterns::{compiled, BuiltPattern};
+use chrono::{Datelike, NaiveDate};
+
+/// Julian Day Number of 1 Muharram 1 AH.
+const HIJRI_EPOCH: i64 = 1_948_440;
+
+/// Julian Day Number of 0000-12-31, i.e. `num_days_from_ce() == 0`.
+const CE_EPOCH: i64 = 1_721_425;
+
+/// Month names with the common English transliterations, in calendar order.
+const MONTHS: [&str; 12] = [
+    r"muharram",
+    r"safar",
+    r"rabi'?\s?(al|ul)?[-\s]?aw+al|rabi'?\s(i|1)\b",
+    r"rabi'?\s?(al|ul|us)?[-\s]?(thani|akhir|sani)|rabi'?\s(ii|2)\b",
+    r"jumad[ai]\s?(al|ul)?[-\s]?(ula|awwal|oola)|jumad[ai]\s(i|1)\b",
+    r"jumad[ai]\s?(al|ul|us)?[-\s]?(akhirah?|thani|sani)|jumad[ai]\s(ii|2)\b",
+    r"rajab",
+    r"sha'?ban",
+    r"ramad(h)?an",
+    r"shaw+al",
+    r"dhu'?\s?(al|l)?[-\s]?q[ai]'?dah?",
+    r"dhu'?\s?(al|l)?[-\s]?hij+ah?",
+];
+
+/// A day-first Hijri date, e.g. "1st of Ramadan 1445 AH", with the captures `day`, `month` and `year`.
+static HIJRI_DATE: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b(?P<day>\d{{1,2}})(st|nd|rd|th)?\s(of\s)?(?P<month>{})(\s(?P<year>\d{{3,4}})(\s?(ah|a\.h\.))?)?",
+        MONTHS.join("|")
+    )
+});
+
+#[derive(Debug, Default, Clone, Copy)]
+/// The tabular Islamic calendar, recognizing day-first dates like "10 Dhu al-Hijjah" or "1st of Ramadan 1445 AH".
+pub struct HijriCalendar;
+
+impl CalendarSystem for HijriCalendar {
+    fn name(&self) -> &'static str {
+        "hijri"
+    }
+
+    fn recognize(&self, text: &str) -> Option<CalendarDate> {
+        let caps = HIJRI_DATE.captures(text)?;
+        let day: u32 = caps["day"].parse().unwrap();
+        let year = caps.name("year").map(|y| y.as_str().parse().unwrap());
+
+        let month_text = &caps["month"];
+        let month = MONTHS.iter().position(|pattern| {
+            compiled(&format!(r"(?i)^({})$", pattern))
+                .unwrap()
+                .is_match(month_text)
+        })?;
+
+        Some(CalendarDate {
+            year,
+            month: month as u32 + 1,
+            day,
+        })
+    }
+
+    /// Without an explicit year, the occurrence in the same Gregorian year as `now` is used.
+    fn to_gregorian(&self, date: &CalendarDate, now: NaiveDate) -> Option<NaiveDate> {
+        if let Some(year) = date.year {
+            return hijri_to_gregorian(year, date.month, date.day);
+        }
+
+        let jan_1 = NaiveDate::from_ymd_opt(now.year(), 1, 1)?;
+        let year = hijri_year(jan_1);
+        (year..=year + 1)
+            .filter_map(|y| hijri_to_gregorian(y, date.month, date.day))
+            .find(|d| d.year() == now.year())
+    }
+}
+
+/// Returns `true` if `year` has 355 days in the tabular Islamic calendar.
+fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+/// Returns the number of days in `month` of `year`.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// Converts a tabular Islamic date into a Gregorian `NaiveDate`, or `None` if the date does not exist.
+fn hijri_to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if year < 1 || !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let (y, m, d) = (year as i64, month as i64, day as i64);
+    let jdn = d + (59 * (m - 1) + 1) / 2 + (y - 1) * 354 + (3 + 11 * y) / 30 + HIJRI_EPOCH - 1;
+    NaiveDate::from_num_days_from_ce_opt((jdn - CE_EPOCH) as i32)
+}
+
+/// Returns the Islamic year that `date` falls in.
+fn hijri_year(date: NaiveDate) -> i32 {
+    let jdn = date.num_days_from_ce() as i64 + CE_EPOCH;
+    ((30 * (jdn - HIJRI_EPOCH) + 10646) / 10631) as i32
+}
+
+#[cfg(test)]
+mod hijri_tests {
+    use super::{hijri_to_gregorian, CalendarDate, CalendarSystem, HijriCalendar};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn recognize_tests() {
+        assert_recognize_hijri("1 Ramadan", None, 9, 1);
+        assert_recognize_hijri("Eid on 10 Dhu al-Hijjah", None, 12, 10);
+        assert_recognize_hijri("1st of Muharram 1446 AH", Some(1446), 1, 1);
+        assert_recognize_hijri("12 Rabi al-Awwal", None, 3, 12);
+        assert_recognize_hijri("5 Rabi II", None, 4, 5);
+        assert_recognize_hijri("27 Rajab 1445", Some(1445), 7, 27);
+        assert_recognize_hijri("15 Sha'ban", None, 8, 15);
+        assert_eq!(HijriCalendar.recognize("Ramadan"), None);
+    }
+
+    #[test]
+    fn conversion_tests() {
+        assert_eq!(hijri_to_gregorian(1445, 9, 1), Some(ymd(2024, 3, 11)));
+        assert_eq!(hijri_to_gregorian(1446, 1, 1), Some(ymd(2024, 7, 8)));
+        assert_eq!(hijri_to_gregorian(1, 1, 1), Some(ymd(622, 7, 19)));
+        assert_eq!(hijri_to_gregorian(1445, 2, 30), None);
+    }
+
+    #[test]
+    fn to_gregorian_tests() {
+        let ramadan = CalendarDate {
+            year: None,
+            month: 9,
+            day: 1,
+        };
+        assert_eq!(
+            HijriCalendar.to_gregorian(&ramadan, ymd(2024, 6, 1)),
+            Some(ymd(2024, 3, 11))
+        );
+        assert_eq!(
+            HijriCalendar.to_gregorian(&ramadan, ymd(2025, 1, 1)),
+            Some(ymd(2025, 3, 1))
+        );
+    }
+
+    fn assert_recognize_hijri(text: &str, year: Option<i32>, month: u32, day: u32) {
+        assert_eq!(
+            HijriCalendar.recognize(text),
+            Some(CalendarDate { year, month, day })
+        )
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
@@ -0,0 +1,672 @@
+//! Recognize holiday names and resolve them into the [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) format.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::patterns::{compiled, Pattern};
+
+/// Asks for the day a holiday is observed on, e.g. "Independence Day observed".
+static OBSERVED: Pattern = Pattern::new(r"(?i)\bobserved\b");
+
+/// An interface for holiday data, used to resolve holiday names and to skip days off when counting business days.
+/// Implement this trait and pass it to
+/// [`DateParser::with_holidays`](../date_time_parser/date_parse/struct.DateParser.html#method.with_holidays)
+/// to use holidays other than the bundled [`HolidayRegion`](enum.HolidayRegion.html)s, e.g. a company's own days off.
+///
+/// # Example
+/// ```
+/// use chrono::{Datelike, NaiveDate};
+/// use date_time_parser::{DateParser, HolidayProvider, HolidayRegion};
+///
+/// /// US federal holidays plus a company-wide Founders Day on March 14th.
+/// struct Company;
+///
+/// impl HolidayProvider for Company {
+///     fn holiday(&self, text: &str, year: i32) -> Option<NaiveDate> {
+///         if text.to_lowercase().contains("founders day") {
+///             return NaiveDate::from_ymd_opt(year, 3, 14);
+///         }
+///         HolidayRegion::UsFederal.holiday(text, year)
+///     }
+///
+///     fn is_day_off(&self, date: NaiveDate) -> bool {
+///         (date.month(), date.day()) == (3, 14) || HolidayRegion::UsFederal.is_day_off(date)
+///     }
+/// }
+///
+/// let parser = DateParser::new().with_holidays(Company);
+/// let now = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+/// assert_eq!(parser.find("Founders Day", now), NaiveDate::from_ymd_opt(2025, 3, 14));
+/// assert_eq!(parser.find("in 2 business days", now), NaiveDate::from_ymd_opt(2025, 3, 17));
+/// ```
+pub trait HolidayProvider: Send + Sync {
+    /// Takes unstructured text, and returns the date in `year` of the holiday named in it if a match is found.
+    fn holiday(&self, text: &str, year: i32) -> Option<NaiveDate>;
+
+    /// Returns `true` if `date` is a holiday that business days should skip. Weekends are skipped regardless.
+    fn is_day_off(&self, date: NaiveDate) -> bool;
+
+    /// Takes unstructured text, and returns the rule placing the holiday named in it in any year, for
+    /// [`DateParser::parse_expr`](../date_time_parser/date_parse/struct.DateParser.html#method.parse_expr). Holidays
+    /// without one are still found by [`holiday`](#tymethod.holiday). Returns `None` by default.
+    fn holiday_rule(&self, _text: &str) -> Option<HolidayDate> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// A bundled dataset of public holidays, selected on the [`DateParser`](../date_time_parser/date_parse/struct.DateParser.html)
+/// with [`with_holidays`](../date_time_parser/date_parse/struct.DateParser.html#method.with_holidays).
+///
+/// Holiday names from the selected region are recognized alongside common observances like "Christmas Eve" or "Easter".
+/// When the text says "observed", a holiday falling on a weekend moves to the weekday it is observed on.
+/// Regions other than the United States are enabled with the `holidays-uk`, `holidays-ca` and `holidays-eu` features.
+pub enum HolidayRegion {
+    /// United States federal holidays. This is the default.
+    #[default]
+    UsFederal,
+    /// Bank holidays in England and Wales.
+    #[cfg(feature = "holidays-uk")]
+    UnitedKingdom,
+    /// Canadian federal statutory holidays.
+    #[cfg(feature = "holidays-ca")]
+    Canada,
+    /// Public holidays shared by most European Union countries.
+    #[cfg(feature = "holidays-eu")]
+    EuropeanUnion,
+}
+
+/// How a holiday falling on a weekend is moved to a weekday.
+#[allow(dead_code)] // some rules are only used by feature-gated regions
+enum Observance {
+    /// Saturdays move to Friday and Sundays to Monday.
+    NearestWeekday,
+    /// Weekends move to the next weekday that is not already a holiday.
+    NextFreeWeekday,
+    /// Holidays are not moved.
+    None,
+}
+
+impl HolidayRegion {
+    /// Returns the region for an ISO 3166 country code ("US", "GB" or "UK", "CA") or "EU", `None` if the code is unknown
+    /// or its feature is not enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A case-insensitive region code
+    pub fn from_code(code: &str) -> Option<HolidayRegion> {
+        match code.to_uppercase().as_str() {
+            "US" => Some(HolidayRegion::UsFederal),
+            #[cfg(feature = "holidays-uk")]
+            "GB" | "UK" => Some(HolidayRegion::UnitedKingdom),
+            #[cfg(feature = "holidays-ca")]
+            "CA" => Some(HolidayRegion::Canada),
+            #[cfg(feature = "holidays-eu")]
+            "EU" => Some(HolidayRegion::EuropeanUnion),
+            _ => None,
+        }
+    }
+
+    /// Returns the holidays of this region.
+    fn holidays(self) -> &'static [(&'static str, HolidayDate)] {
+        match self {
+            HolidayRegion::UsFederal => &US_FEDERAL,
+            #[cfg(feature = "holidays-uk")]
+            HolidayRegion::UnitedKingdom => &UK_BANK,
+            #[cfg(feature = "holidays-ca")]
+            HolidayRegion::Canada => &CA_STATUTORY,
+            #[cfg(feature = "holidays-eu")]
+            HolidayRegion::EuropeanUnion => &EU_PUBLIC,
+        }
+    }
+
+    fn observance(self) -> Observance {
+        match self {
+            HolidayRegion::UsFederal => Observance::NearestWeekday,
+            #[cfg(feature = "holidays-uk")]
+            HolidayRegion::UnitedKingdom => Observance::NextFreeWeekday,
+            #[cfg(feature = "holidays-ca")]
+            HolidayRegion::Canada => Observance::NextFreeWeekday,
+            #[cfg(feature = "holidays-eu")]
+            HolidayRegion::EuropeanUnion => Observance::None,
+        }
+    }
+
+    /// Finds the holiday named in `text` and returns its date in `year`.
+    pub(crate) fn find(self, text: &str, year: i32) -> Option<NaiveDate> {
+        let (holiday, index) = recognize_holiday(self.holidays(), text)?;
+
+        match index {
+            Some(i) if OBSERVED.is_match(text) => self.observed_dates(year)[i],
+            _ => holiday.in_year(year),
+        }
+    }
+
+    /// Returns the date each holiday of this region is observed on in `year`, in table order.
+    fn observed_dates(self, year: i32) -> Vec<Option<NaiveDate>> {
+        let mut dates: Vec<Option<NaiveDate>> = self
+            .holidays()
+            .iter()
+            .map(|(_, holiday)| holiday.in_year(year))
+            .collect();
+
+        match self.observance() {
+            Observance::NearestWeekday => {
+                for date in dates.iter_mut() {
+                    *date = date.map(nearest_weekday);
+                }
+            }
+            Observance::NextFreeWeekday => {
+                let mut taken: Vec<NaiveDate> = dates
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|d| !is_weekend(*d))
+                    .collect();
+                let mut order: Vec<usize> = (0..dates.len()).collect();
+                order.sort_by_key(|&i| dates[i]);
+
+                for i in order {
+                    if let Some(mut date) = dates[i].filter(|d| is_weekend(*d)) {
+                        while is_weekend(date) || taken.contains(&date) {
+                            date += Duration::days(1);
+                        }
+                        taken.push(date);
+                        dates[i] = Some(date);
+                    }
+                }
+            }
+            Observance::None => {}
+        }
+        dates
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The rule that places a holiday in a given year.
+pub enum HolidayDate {
+    /// A day of a month, e.g. Christmas => Fixed(12, 25)
+    Fixed(u32, u32),
+    /// A number of days from Easter Sunday, e.g. Good Friday => Easter(-2)
+    Easter(i64),
+    /// The nth weekday of a month, counting from its end when negative, e.g. Thanksgiving => NthWeekday(4, Thu, 11)
+    /// and Memorial Day => NthWeekday(-1, Mon, 5)
+    NthWeekday(i8, Weekday, u32),
+    /// The last weekday before a day of a month, e.g. Victoria Day => WeekdayBefore(Mon, 5, 25)
+    WeekdayBefore(Weekday, u32, u32),
+}
+
+impl HolidayDate {
+    /// Returns the date of the holiday in `year`.
+    pub fn in_year(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            HolidayDate::Fixed(month, day) => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayDate::Easter(offset) => {
+                easter_sunday(year)?.checked_add_signed(Duration::days(offset))
+            }
+            HolidayDate::NthWeekday(n, weekday, month) => {
+                nth_weekday_of_month(year, month, weekday, n)
+            }
+            HolidayDate::WeekdayBefore(weekday, month, day) => {
+                let date = NaiveDate::from_ymd_opt(year, month, day)?;
+                let offset = (7 + date.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday()
+                    - 1)
+                    % 7
+                    + 1;
+                Some(date - Duration::days(offset as i64))
+            }
+        }
+    }
+}
+
+/// United States federal holidays (5 U.S.C. 6103).
+const US_FEDERAL: [(&str, HolidayDate); 11] = [
+    (r"new\s?year'?s(\sday)?", HolidayDate::Fixed(1, 1)),
+    (
+        r"(martin\sluther\sking(\sjr\.?)?|mlk)(\sday)?",
+        HolidayDate::NthWeekday(3, Weekday::Mon, 1),
+    ),
+    (
+        r"presidents'?\sday|washington'?s\sbirthday",
+        HolidayDate::NthWeekday(3, Weekday::Mon, 2),
+    ),
+    (
+        r"memorial\sday",
+        HolidayDate::NthWeekday(-1, Weekday::Mon, 5),
+    ),
+    (r"juneteenth", HolidayDate::Fixed(6, 19)),
+    (
+        r"independence\sday|(the\s)?fourth\sof\sjuly",
+        HolidayDate::Fixed(7, 4),
+    ),
+    (r"labou?r\sday", HolidayDate::NthWeekday(1, Weekday::Mon, 9)),
+    (
+        r"columbus\sday|indigenous\speoples'?\sday",
+        HolidayDate::NthWeekday(2, Weekday::Mon, 10),
+    ),
+    (r"veterans'?\sday", HolidayDate::Fixed(11, 11)),
+    (
+        r"thanksgiving(\sday)?",
+        HolidayDate::NthWeekday(4, Weekday::Thu, 11),
+    ),
+    (r"christmas(\sday)?|xmas", HolidayDate::Fixed(12, 25)),
+];
+
+/// Bank holidays in England and Wales.
+#[cfg(feature = "holidays-uk")]
+const UK_BANK: [(&str, HolidayDate); 8] = [
+    (r"new\s?year'?s(\sday)?", HolidayDate::Fixed(1, 1)),
+    (r"good\sfriday", HolidayDate::Easter(-2)),
+    (r"easter\smonday", HolidayDate::Easter(1)),
+    (
+        r"early\smay\sbank\sholiday|may\sday(\sbank\sholiday)?",
+        HolidayDate::NthWeekday(1, Weekday::Mon, 5),
+    ),
+    (
+        r"spring\sbank\sholiday|whitsun\sbank\sholiday",
+        HolidayDate::NthWeekday(-1, Weekday::Mon, 5),
+    ),
+    (
+        r"(summer|august)\sbank\sholiday",
+        HolidayDate::NthWeekday(-1, Weekday::Mon, 8),
+    ),
+    (r"christmas(\sday)?|xmas", HolidayDate::Fixed(12, 25)),
+    (r"boxing\sday", HolidayDate::Fixed(12, 26)),
+];
+
+/// Canadian federal statutory holidays.
+#[cfg(feature = "holidays-ca")]
+const CA_STATUTORY: [(&str, HolidayDate); 11] = [
+    (r"new\s?year'?s(\sday)?", HolidayDate::Fixed(1, 1)),
+    (r"good\sfriday", HolidayDate::Easter(-2)),
+    (r"easter\smonday", HolidayDate::Easter(1)),
+    (
+        r"victoria\sday|fete\sde\sla\sreine",
+        HolidayDate::WeekdayBefore(Weekday::Mon, 5, 25),
+    ),
+    (r"canada\sday|dominion\sday", HolidayDate::Fixed(7, 1)),
+    (
+        r"civic\sholiday|august\scivic\sholiday",
+        HolidayDate::NthWeekday(1, Weekday::Mon, 8),
+    ),
+    (r"labou?r\sday", HolidayDate::NthWeekday(1, Weekday::Mon, 9)),
+    (
+        r"(national\sday\sfor\s)?truth\sand\sreconciliation(\sday)?|orange\sshirt\sday",
+        HolidayDate::Fixed(9, 30),
+    ),
+    (
+        r"thanksgiving(\sday)?",
+        HolidayDate::NthWeekday(2, Weekday::Mon, 10),
+    ),
+    (r"remembrance\sday", HolidayDate::Fixed(11, 11)),
+    (r"christmas(\sday)?|xmas", HolidayDate::Fixed(12, 25)),
+];
+
+/// Public holidays shared by most European Union countries.
+#[cfg(feature = "holidays-eu")]
+const EU_PUBLIC: [(&str, HolidayDate); 11] = [
+    (r"new\s?year'?s(\sday)?", HolidayDate::Fixed(1, 1)),
+    (r"good\sfriday", HolidayDate::Easter(-2)),
+    (r"easter\smonday", HolidayDate::Easter(1)),
+    (r"labou?r\sday|may\sday", HolidayDate::Fixed(5, 1)),
+    (r"europe\sday|schuman\sday", HolidayDate::Fixed(5, 9)),
+    (r"ascension(\sday)?", HolidayDate::Easter(39)),
+    (r"whit\smonday|pentecost\smonday", HolidayDate::Easter(50)),
+    (
+        r"assumption(\sday)?|assumption\sof\smary",
+        HolidayDate::Fixed(8, 15),
+    ),
+    (r"all\ssaints'?(\sday)?", HolidayDate::Fixed(11, 1)),
+    (r"christmas(\sday)?|xmas", HolidayDate::Fixed(12, 25)),
+    (
+        r"st\.?\s?stephen'?s(\sday)?|boxing\sday",
+        HolidayDate::Fixed(12, 26),
+    ),
+];
+
+/// Holiday names, their eves and other days derived from them, plus informal names.
+const HOLIDAYS: [(&str, HolidayDate); 17] = [
+    (r"new\s?year'?s\seve|nye", HolidayDate::Fixed(12, 31)),
+    (r"new\s?year'?s(\sday)?", HolidayDate::Fixed(1, 1)),
+    (r"(christmas|xmas)\seve", HolidayDate::Fixed(12, 24)),
+    (r"christmas(\sday)?|xmas", HolidayDate::Fixed(12, 25)),
+    (
+        r"hallowe'?en|all\shallows'?\seve",
+        HolidayDate::Fixed(10, 31),
+    ),
+    (r"valentine'?s(\sday)?", HolidayDate::Fixed(2, 14)),
+    (
+        r"st\.?\s?(patrick|paddy)'?s(\sday)?",
+        HolidayDate::Fixed(3, 17),
+    ),
+    (r"april\sfools'?(\sday)?", HolidayDate::Fixed(4, 1)),
+    (r"easter\seve|holy\ssaturday", HolidayDate::Easter(-1)),
+    (r"easter\smonday", HolidayDate::Easter(1)),
+    (r"easter(\ssunday)?", HolidayDate::Easter(0)),
+    (r"good\sfriday", HolidayDate::Easter(-2)),
+    (r"(maundy|holy)\sthursday", HolidayDate::Easter(-3)),
+    (r"palm\ssunday", HolidayDate::Easter(-7)),
+    (r"ash\swednesday", HolidayDate::Easter(-46)),
+    (
+        r"mardi\sgras|fat\stuesday|shrove\stuesday|pancake\sday",
+        HolidayDate::Easter(-47),
+    ),
+    (r"pentecost|whit\ssunday", HolidayDate::Easter(49)),
+];
+
+/// Finds the holiday named in `text` among `regional` and the common holidays. The longest name wins, so
+/// "New Year's Eve" is not read as "New Year's". Returns the holiday and its index in `regional`, if it came from there.
+fn recognize_holiday(
+    regional: &[(&str, HolidayDate)],
+    text: &str,
+) -> Option<(HolidayDate, Option<usize>)> {
+    let candidates = regional
+        .iter()
+        .enumerate()
+        .map(|(i, h)| (h, Some(i)))
+        .chain(HOLIDAYS.iter().map(|h| (h, None)));
+
+    let mut best: Option<(usize, HolidayDate, Option<usize>)> = None;
+    for ((pattern, date), index) in candidates {
+        let re = compiled(&format!(r"(?i)\b({})\b", pattern)).unwrap();
+        if let Some(m) = re.find(text) {
+            let len = m.end() - m.start();
+            if best.is_none_or(|(best_len, _, _)| len > best_len) {
+                best = Some((len, *date, index));
+            }
+        }
+    }
+    best.map(|(_, date, index)| (date, index))
+}
+
+/// Returns `true` if `date` falls on a Saturday or Sunday.
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Moves a weekend `date` to the nearest weekday: Saturdays to Friday and Sundays to Monday.
+fn nearest_weekday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+/// Returns the `n`th `weekday` of `month` in `year`, counting from the end of the month when `n` is negative.
+pub(crate) fn nth_weekday_of_month(
+    year: i32,
+    month: u32,
+    weekday: Weekday,
+    n: i8,
+) -> Option<NaiveDate> {
+    if n > 0 {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let offset =
+            (7 + weekday.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+        let date = first + Duration::days(offset as i64 + 7 * (n as i64 - 1));
+        Some(date).filter(|d| d.month() == month)
+    } else {
+        let next_month = NaiveDate::from_ymd_opt(year + month as i32 / 12, month % 12 + 1, 1)?;
+        let last = next_month.pred_opt()?;
+        let offset =
+            (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        let date = last - Duration::days(offset as i64 + 7 * (-n as i64 - 1));
+        Some(date).filter(|d| d.month() == month)
+    }
+}
+
+/// Returns the date of Easter Sunday in `year` (Anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+impl HolidayProvider for HolidayRegion {
+    fn holiday(&self, text: &str, year: i32) -> Option<NaiveDate> {
+        self.find(text, year)
+    }
+
+    /// The day a holiday is observed on depends on the year, so "observed" holidays have no rule.
+    fn holiday_rule(&self, text: &str) -> Option<HolidayDate> {
+        if OBSERVED.is_match(text) {
+            return None;
+        }
+        recognize_holiday(self.holidays(), text).map(|(holiday, _)| holiday)
+    }
+
+    /// Holidays are days off on the date they are observed.
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        // a holiday early in next year can be observed at the end of this one
+        (date.year()..=date.year() + 1)
+            .flat_map(|year| self.observed_dates(year))
+            .any(|observed| observed == Some(date))
+    }
+}
+
+/// Returns the date `n` business days after `from`, skipping weekends and the days off of `holidays`.
+/// Counts backwards when `n` is negative.
+pub(crate) fn add_business_days(
+    from: NaiveDate,
+    n: i32,
+    holidays: &dyn HolidayProvider,
+) -> Option<NaiveDate> {
+    let step = Duration::days(if n < 0 { -1 } else { 1 });
+    let mut date = from;
+    let mut remaining = n.abs();
+    while remaining > 0 {
+        date = date.checked_add_signed(step)?;
+        if !is_weekend(date) && !holidays.is_day_off(date) {
+            remaining -= 1;
+        }
+    }
+    Some(date)
+}
+
+#[cfg(test)]
+mod holiday_tests {
+    use super::{add_business_days, easter_sunday, HolidayDate, HolidayProvider, HolidayRegion};
+    use chrono::{NaiveDate, Weekday};
+
+    #[test]
+    fn eve_tests() {
+        assert_holiday("Christmas Eve dinner", 2024, ymd(2024, 12, 24));
+        assert_holiday("xmas eve", 2024, ymd(2024, 12, 24));
+        assert_holiday("New Year's Eve party", 2024, ymd(2024, 12, 31));
+        assert_holiday("NYE", 2024, ymd(2024, 12, 31));
+        assert_holiday("Halloween", 2024, ymd(2024, 10, 31));
+        assert_holiday("easter eve", 2024, ymd(2024, 3, 30));
+    }
+
+    #[test]
+    fn named_day_tests() {
+        assert_holiday("Christmas", 2024, ymd(2024, 12, 25));
+        assert_holiday("New Years Day brunch", 2025, ymd(2025, 1, 1));
+        assert_holiday("Valentine's Day", 2025, ymd(2025, 2, 14));
+        assert_holiday("St. Patrick's Day parade", 2025, ymd(2025, 3, 17));
+        assert_holiday("st paddys", 2025, ymd(2025, 3, 17));
+    }
+
+    #[test]
+    fn easter_derived_tests() {
+        assert_eq!(easter_sunday(2024), Some(ymd(2024, 3, 31)));
+        assert_eq!(easter_sunday(2025), Some(ymd(2025, 4, 20)));
+        assert_holiday("Good Friday", 2025, ymd(2025, 4, 18));
+        assert_holiday("Easter Monday", 2025, ymd(2025, 4, 21));
+        assert_holiday("Ash Wednesday", 2025, ymd(2025, 3, 5));
+        assert_holiday("mardi gras", 2025, ymd(2025, 3, 4));
+    }
+
+    #[test]
+    fn us_federal_tests() {
+        assert_holiday("MLK Day", 2025, ymd(2025, 1, 20));
+        assert_holiday("Presidents' Day", 2025, ymd(2025, 2, 17));
+        assert_holiday("Memorial Day BBQ", 2025, ymd(2025, 5, 26));
+        assert_holiday("Juneteenth", 2025, ymd(2025, 6, 19));
+        assert_holiday("Independence Day", 2025, ymd(2025, 7, 4));
+        assert_holiday("Labor Day", 2025, ymd(2025, 9, 1));
+        assert_holiday("Columbus Day", 2025, ymd(2025, 10, 13));
+        assert_holiday("Veterans Day", 2025, ymd(2025, 11, 11));
+        assert_holiday("Thanksgiving dinner", 2025, ymd(2025, 11, 27));
+    }
+
+    #[test]
+    fn observed_tests() {
+        assert_holiday("Independence Day (observed)", 2026, ymd(2026, 7, 3));
+        assert_holiday("Independence Day observed", 2027, ymd(2027, 7, 5));
+        assert_holiday("Independence Day observed", 2025, ymd(2025, 7, 4));
+        assert_holiday("Christmas observed", 2022, ymd(2022, 12, 26));
+        assert_holiday("Christmas", 2022, ymd(2022, 12, 25));
+    }
+
+    #[test]
+    fn holiday_rule_tests() {
+        let us = HolidayRegion::UsFederal;
+        assert_eq!(
+            us.holiday_rule("Memorial Day BBQ"),
+            Some(HolidayDate::NthWeekday(-1, Weekday::Mon, 5))
+        );
+        assert_eq!(
+            us.holiday_rule("Christmas Eve dinner"),
+            Some(HolidayDate::Fixed(12, 24))
+        );
+        assert_eq!(
+            us.holiday_rule("Good Friday"),
+            Some(HolidayDate::Easter(-2))
+        );
+        assert_eq!(us.holiday_rule("Christmas observed"), None);
+        assert_eq!(us.holiday_rule("Lunch at noon"), None);
+    }
+
+    #[test]
+    fn not_holiday_tests() {
+        assert_eq!(HolidayRegion::UsFederal.find("Lunch at noon", 2025), None);
+        assert_eq!(HolidayRegion::UsFederal.find("christmastime", 2025), None);
+    }
+
+    #[test]
+    fn region_code_tests() {
+        assert_eq!(
+            HolidayRegion::from_code("us"),
+            Some(HolidayRegion::UsFederal)
+        );
+        assert_eq!(HolidayRegion::from_code("XX"), None);
+    }
+
+    #[cfg(feature = "holidays-uk")]
+    #[test]
+    fn uk_bank_tests() {
+        let uk = HolidayRegion::from_code("GB");
+        assert_eq!(uk, Some(HolidayRegion::UnitedKingdom));
+        assert_eq!(
+            uk.unwrap().find("August bank holiday", 2025),
+            Some(ymd(2025, 8, 25))
+        );
+        assert_eq!(
+            uk.unwrap().find("Early May bank holiday", 2025),
+            Some(ymd(2025, 5, 5))
+        );
+        assert_eq!(
+            uk.unwrap().find("Boxing Day", 2025),
+            Some(ymd(2025, 12, 26))
+        );
+        assert_eq!(
+            uk.unwrap().find("Christmas Eve", 2025),
+            Some(ymd(2025, 12, 24))
+        );
+        // Christmas and Boxing Day on a weekend are both substituted, in order.
+        assert_eq!(
+            uk.unwrap().find("Christmas (observed)", 2021),
+            Some(ymd(2021, 12, 27))
+        );
+        assert_eq!(
+            uk.unwrap().find("Boxing Day (observed)", 2021),
+            Some(ymd(2021, 12, 28))
+        );
+        assert_eq!(
+            uk.unwrap().find("Christmas observed", 2022),
+            Some(ymd(2022, 12, 27))
+        );
+    }
+
+    #[cfg(feature = "holidays-ca")]
+    #[test]
+    fn ca_statutory_tests() {
+        let ca = HolidayRegion::Canada;
+        assert_eq!(ca.find("Victoria Day", 2025), Some(ymd(2025, 5, 19)));
+        assert_eq!(ca.find("Victoria Day", 2021), Some(ymd(2021, 5, 24)));
+        assert_eq!(ca.find("Canada Day", 2025), Some(ymd(2025, 7, 1)));
+        assert_eq!(ca.find("Thanksgiving", 2025), Some(ymd(2025, 10, 13)));
+        assert_eq!(ca.find("Boxing Day", 2025), None);
+        assert_eq!(ca.find("Canada Day observed", 2023), Some(ymd(2023, 7, 3)));
+    }
+
+    #[cfg(feature = "holidays-eu")]
+    #[test]
+    fn eu_public_tests() {
+        let eu = HolidayRegion::EuropeanUnion;
+        assert_eq!(eu.find("Ascension Day", 2025), Some(ymd(2025, 5, 29)));
+        assert_eq!(eu.find("Whit Monday", 2025), Some(ymd(2025, 6, 9)));
+        assert_eq!(eu.find("Boxing Day", 2025), Some(ymd(2025, 12, 26)));
+        assert_eq!(eu.find("May Day", 2027), Some(ymd(2027, 5, 1)));
+        assert_eq!(eu.find("May Day observed", 2027), Some(ymd(2027, 5, 1)));
+    }
+
+    #[test]
+    fn day_off_tests() {
+        let us = HolidayRegion::UsFederal;
+        assert!(us.is_day_off(ymd(2025, 11, 27)));
+        assert!(us.is_day_off(ymd(2026, 7, 3)));
+        assert!(!us.is_day_off(ymd(2026, 7, 4)));
+        assert!(us.is_day_off(ymd(2021, 12, 31)));
+        assert!(!us.is_day_off(ymd(2024, 12, 24)));
+    }
+
+    #[test]
+    fn business_day_tests() {
+        let us = HolidayRegion::UsFederal;
+        // Wednesday before Thanksgiving
+        assert_eq!(
+            add_business_days(ymd(2025, 11, 26), 1, &us),
+            Some(ymd(2025, 11, 28))
+        );
+        assert_eq!(
+            add_business_days(ymd(2025, 11, 26), 3, &us),
+            Some(ymd(2025, 12, 2))
+        );
+        // Friday before Memorial Day
+        assert_eq!(
+            add_business_days(ymd(2025, 5, 23), 1, &us),
+            Some(ymd(2025, 5, 27))
+        );
+        assert_eq!(
+            add_business_days(ymd(2025, 5, 27), -1, &us),
+            Some(ymd(2025, 5, 23))
+        );
+        assert_eq!(
+            add_business_days(ymd(2025, 5, 27), 0, &us),
+            Some(ymd(2025, 5, 27))
+        );
+    }
+
+    fn assert_holiday(text: &str, year: i32, expected: NaiveDate) {
+        assert_eq!(HolidayRegion::UsFederal.find(text, year), Some(expected))
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
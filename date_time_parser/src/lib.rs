@@ -52,9 +52,42 @@
 //! assert_eq!(time, None);
 //! ```
 
+mod business;
+mod calendar;
+mod clock;
 mod date_parse;
+mod duration_parse;
+#[cfg(feature = "hijri")]
+mod hijri;
+mod holiday;
+mod locale;
+#[cfg(feature = "lunar")]
+mod lunar;
+mod number_words;
+pub mod patterns;
+mod prefilter;
 mod recognizable;
+mod recur_parse;
+mod spans;
 mod time_parse;
-pub use date_parse::DateParser;
+mod time_zone;
+pub use business::{CompanyCalendar, Payday};
+pub use calendar::{CalendarDate, CalendarSystem};
+pub use clock::{Clock, FixedClock, LocalClock, OffsetClock, UtcClock};
+pub use date_parse::{
+    DateExpr, DateOptions, DateOrder, DateParser, Direction, MonthOfYear, MonthSpec,
+    NextWeekdayPolicy, PeriodPart, Season,
+};
+pub use duration_parse::DurationParser;
+#[cfg(feature = "hijri")]
+pub use hijri::HijriCalendar;
+pub use holiday::{HolidayDate, HolidayProvider, HolidayRegion};
+pub use locale::Locale;
+#[cfg(feature = "lunar")]
+pub use lunar::LunarCalendar;
+pub use prefilter::may_be_temporal;
 pub use recognizable::Recognizable;
-pub use time_parse::TimeParser;
+pub use recur_parse::{Frequency, RecurMatch, RecurParser, Recurrence, RecurrenceEnd};
+pub use spans::{parse_with_spans, Spans};
+pub use time_parse::{MeridiemPolicy, Rounding, TimeExpr, TimeMatch, TimeParser};
+pub use time_zone::TimeZoneMention;
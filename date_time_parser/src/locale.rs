@@ -0,0 +1,320 @@
+//! Keyword tables for reading dates and times written in languages other than English, like "mañana a las 5".
+
+use regex::Captures;
+use std::borrow::Cow;
+
+use crate::patterns::{compiled, Pattern};
+
+/// A day written before its month once the month is in English, e.g. "5 june", "5. june", "1er june" or "5 de june de
+/// 2021", with the captures `day`, `month` and `year`.
+static DAY_BEFORE_MONTH: Pattern = Pattern::new(
+    r"(?i)\b(?P<day>\d{1,2})(?:\.|º|°|er)?\s(?:de\s)?(?P<month>january|february|march|april|may|june|july|august|september|october|november|december)\b(?:,?\s(?:de\s)?(?P<year>\d{4})\b)?",
+);
+
+/// "next" or "last" written after what it qualifies once in English, e.g. "monday next" of "lunes que viene", with the
+/// captures `unit` and `which`.
+static QUALIFIER_AFTER: Pattern = Pattern::new(
+    r"(?i)\b(?P<unit>monday|tuesday|wednesday|thursday|friday|saturday|sunday|week|month|year)\s(?P<which>next|last)\b",
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The language dates and times are written in, selected on the [`DateParser`](struct.DateParser.html) and
+/// [`TimeParser`](struct.TimeParser.html) with `with_language` or `with_locale`.
+///
+/// Each language has a table of its words for weekdays, months, "tomorrow", "next", times of day like "noon" and the
+/// like, which are read as their English counterparts, so "el próximo viernes a las 8" reads as "next friday at 8".
+/// Languages other than English are enabled with the `locale-es`, `locale-fr` and `locale-de` features.
+pub enum Locale {
+    /// English. This is the default.
+    #[default]
+    English,
+    /// Spanish, e.g. "mañana a las 5 de la tarde".
+    #[cfg(feature = "locale-es")]
+    Spanish,
+    /// French, e.g. "lundi prochain à 14h".
+    #[cfg(feature = "locale-fr")]
+    French,
+    /// German, e.g. "nächsten Montag um 9 Uhr".
+    #[cfg(feature = "locale-de")]
+    German,
+}
+
+impl Locale {
+    /// Returns the language of a locale tag like "es_MX", "fr-CA" or "de", `None` if the language is unknown or its
+    /// feature is not enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - A case-insensitive locale tag or language code
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['_', '-', '.', '@']).next().unwrap_or("");
+        match language.to_lowercase().as_str() {
+            "en" => Some(Locale::English),
+            #[cfg(feature = "locale-es")]
+            "es" => Some(Locale::Spanish),
+            #[cfg(feature = "locale-fr")]
+            "fr" => Some(Locale::French),
+            #[cfg(feature = "locale-de")]
+            "de" => Some(Locale::German),
+            _ => None,
+        }
+    }
+
+    /// Returns `text` with the date and time words of this language rewritten in English, as the parsers read them.
+    /// English text is returned as it is.
+    ///
+    /// # Example
+    /// ```
+    /// use date_time_parser::Locale;
+    ///
+    /// assert_eq!(Locale::English.translate("Lunch tomorrow at noon"), "Lunch tomorrow at noon");
+    /// ```
+    pub fn translate(self, text: &str) -> Cow<'_, str> {
+        let keywords = self.keywords();
+        if keywords.is_empty() {
+            return Cow::Borrowed(text);
+        }
+        let mut text = text.to_owned();
+        for (words, english) in keywords {
+            if let Ok(re) = compiled(&format!(r"(?i)\b(?:{})\b", words)) {
+                text = re.replace_all(&text, *english).into_owned();
+            }
+        }
+        let text = DAY_BEFORE_MONTH.replace_all(&text, |caps: &Captures| match caps.name("year") {
+            Some(year) => format!("{} {}, {}", &caps["month"], &caps["day"], year.as_str()),
+            None => format!("{} {}", &caps["month"], &caps["day"]),
+        });
+        Cow::Owned(
+            QUALIFIER_AFTER
+                .replace_all(&text, "$which $unit")
+                .into_owned(),
+        )
+    }
+
+    /// Returns the words of this language and what they are in English, phrases before the words in them. Words are
+    /// case-insensitive regular expressions, and their English may refer to their captures, like "${1}pm".
+    fn keywords(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => &[],
+            #[cfg(feature = "locale-es")]
+            Locale::Spanish => SPANISH,
+            #[cfg(feature = "locale-fr")]
+            Locale::French => FRENCH,
+            #[cfg(feature = "locale-de")]
+            Locale::German => GERMAN,
+        }
+    }
+}
+
+#[cfg(feature = "locale-es")]
+const SPANISH: &[(&str, &str)] = &[
+    (r"(\d{1,2}):(\d{2})\sde\sla\s(?:tarde|noche)", "$1:${2}pm"),
+    (r"(\d{1,2})\sde\sla\s(?:tarde|noche)", "${1}pm"),
+    (
+        r"(\d{1,2}):(\d{2})\sde\sla\s(?:mañana|madrugada)",
+        "$1:${2}am",
+    ),
+    (r"(\d{1,2})\sde\sla\s(?:mañana|madrugada)", "${1}am"),
+    (r"pasado\smañana", "in 2 days"),
+    (r"(?:por|en)\sla\smañana", "in the morning"),
+    (r"(?:por|en)\sla\starde", "in the afternoon"),
+    (r"(?:por|en)\sla\snoche|esta\snoche", "tonight"),
+    (r"medio\s?d[ií]a", "noon"),
+    (r"medianoche", "midnight"),
+    (r"mañana|manana", "tomorrow"),
+    (r"hoy", "today"),
+    (r"ayer", "yesterday"),
+    (r"que\sviene|pr[oó]xim[oa]", "next"),
+    (r"pasad[oa]", "last"),
+    (r"este|esta", "this"),
+    (r"(?:dentro\sde|en)\s(\d)", "in $1"),
+    (r"a\slas?", "at"),
+    (r"d[ií]as?", "days"),
+    (r"horas?", "hours"),
+    (r"minutos?", "minutes"),
+    (r"semanas", "weeks"),
+    (r"semana", "week"),
+    (r"meses", "months"),
+    (r"mes", "month"),
+    (r"años", "years"),
+    (r"año", "year"),
+    (r"lunes", "monday"),
+    (r"martes", "tuesday"),
+    (r"mi[ée]rcoles", "wednesday"),
+    (r"jueves", "thursday"),
+    (r"viernes", "friday"),
+    (r"s[áa]bado", "saturday"),
+    (r"domingo", "sunday"),
+    (r"enero", "january"),
+    (r"febrero", "february"),
+    (r"marzo", "march"),
+    (r"abril", "april"),
+    (r"mayo", "may"),
+    (r"junio", "june"),
+    (r"julio", "july"),
+    (r"agosto", "august"),
+    (r"se?tiembre", "september"),
+    (r"octubre", "october"),
+    (r"noviembre", "november"),
+    (r"diciembre", "december"),
+];
+
+#[cfg(feature = "locale-fr")]
+const FRENCH: &[(&str, &str)] = &[
+    (
+        r"(\d{1,2})\s?h(?:eures?)?\s(?:du\ssoir|de\sl'apr[èe]s-midi)",
+        "${1}pm",
+    ),
+    (r"(\d{1,2})\s?h(?:eures?)?\sdu\smatin", "${1}am"),
+    (r"dans\s(\d{1,3})\sheures?", "in $1 hours"),
+    (r"(\d{1,2})\sheures?\s(\d{2})", "$1:$2"),
+    (r"(\d{1,2})\sheures?", "$1:00"),
+    (r"apr[èe]s-demain", "in 2 days"),
+    (r"aujourd['’]hui", "today"),
+    (r"demain", "tomorrow"),
+    (r"hier", "yesterday"),
+    (r"ce\ssoir", "tonight"),
+    (r"(?:du|le)\smatin", "in the morning"),
+    (r"(?:de\s)?l'apr[èe]s-midi", "in the afternoon"),
+    (r"(?:du|le)\ssoir", "in the evening"),
+    (r"midi", "noon"),
+    (r"minuit", "midnight"),
+    (r"prochaine?", "next"),
+    (r"derni[èe]re?", "last"),
+    (r"cette|ce", "this"),
+    (r"dans\s(\d)", "in $1"),
+    (r"à", "at"),
+    (r"jours?", "days"),
+    (r"semaines", "weeks"),
+    (r"semaine", "week"),
+    (r"mois", "month"),
+    (r"ann[ée]es?|ans?", "year"),
+    (r"lundi", "monday"),
+    (r"mardi", "tuesday"),
+    (r"mercredi", "wednesday"),
+    (r"jeudi", "thursday"),
+    (r"vendredi", "friday"),
+    (r"samedi", "saturday"),
+    (r"dimanche", "sunday"),
+    (r"janvier", "january"),
+    (r"f[ée]vrier", "february"),
+    (r"mars", "march"),
+    (r"avril", "april"),
+    (r"mai", "may"),
+    (r"juin", "june"),
+    (r"juillet", "july"),
+    (r"ao[ûu]t", "august"),
+    (r"septembre", "september"),
+    (r"octobre", "october"),
+    (r"novembre", "november"),
+    (r"d[ée]cembre", "december"),
+];
+
+#[cfg(feature = "locale-de")]
+const GERMAN: &[(&str, &str)] = &[
+    (
+        r"(\d{1,2}):(\d{2})\sUhr\s(?:abends|nachmittags|nachts)",
+        "$1:${2}pm",
+    ),
+    (r"(\d{1,2})\sUhr\s(?:abends|nachmittags|nachts)", "${1}pm"),
+    (
+        r"(\d{1,2}):(\d{2})\sUhr\s(?:morgens|früh|vormittags)",
+        "$1:${2}am",
+    ),
+    (r"(\d{1,2})\sUhr\s(?:morgens|früh|vormittags)", "${1}am"),
+    (r"(\d{1,2}):(\d{2})\sUhr", "$1:$2"),
+    (r"(\d{1,2})\sUhr", "$1:00"),
+    (r"(?:ü|ue)bermorgen", "in 2 days"),
+    (r"morgen\s(?:früh|frueh)", "tomorrow morning"),
+    (r"heute\sabend", "tonight"),
+    (r"morgens|am\smorgen|vormittags", "in the morning"),
+    (r"nachmittags|am\snachmittag", "in the afternoon"),
+    (r"abends|am\sabend", "in the evening"),
+    (r"heute", "today"),
+    (r"morgen", "tomorrow"),
+    (r"gestern", "yesterday"),
+    (r"mittags?", "noon"),
+    (r"mitternacht", "midnight"),
+    (r"(?:nächst|naechst|kommend)e[nmrs]?", "next"),
+    (r"(?:letzt|vergangen)e[nmrs]?", "last"),
+    (r"diese[nmrs]?", "this"),
+    (r"um", "at"),
+    (r"am", "on"),
+    (r"im", "in"),
+    (r"tagen?", "days"),
+    (r"stunden?", "hours"),
+    (r"minuten?", "minutes"),
+    (r"wochen", "weeks"),
+    (r"woche", "week"),
+    (r"monaten?", "months"),
+    (r"monat", "month"),
+    (r"jahren?", "years"),
+    (r"jahr", "year"),
+    (r"montag", "monday"),
+    (r"dienstag", "tuesday"),
+    (r"mittwoch", "wednesday"),
+    (r"donnerstag", "thursday"),
+    (r"freitag", "friday"),
+    (r"samstag|sonnabend", "saturday"),
+    (r"sonntag", "sunday"),
+    (r"januar|jänner", "january"),
+    (r"februar", "february"),
+    (r"märz|maerz", "march"),
+    (r"april", "april"),
+    (r"mai", "may"),
+    (r"juni", "june"),
+    (r"juli", "july"),
+    (r"august", "august"),
+    (r"september", "september"),
+    (r"oktober", "october"),
+    (r"november", "november"),
+    (r"dezember", "december"),
+];
+
+#[cfg(test)]
+mod locale_tests {
+    use super::Locale;
+
+    #[test]
+    fn from_tag_tests() {
+        assert_eq!(Locale::from_tag("en_US"), Some(Locale::English));
+        assert_eq!(Locale::from_tag("EN"), Some(Locale::English));
+        assert_eq!(Locale::from_tag("xx-YY"), None);
+        assert_eq!(Locale::English.translate("mañana"), "mañana");
+    }
+
+    #[cfg(feature = "locale-es")]
+    #[test]
+    fn spanish_tests() {
+        assert_eq!(Locale::from_tag("es_MX"), Some(Locale::Spanish));
+        let es = |text| Locale::Spanish.translate(text).into_owned();
+        assert_eq!(es("Cena mañana a las 8"), "Cena tomorrow at 8");
+        assert_eq!(es("Dentista el 5 de junio"), "Dentista el june 5");
+        assert_eq!(es("Viaje el 5 de junio de 2021"), "Viaje el june 5, 2021");
+        assert_eq!(es("Reunión el lunes que viene"), "Reunión el next monday");
+        assert_eq!(es("Café a las 5 de la tarde"), "Café at 5pm");
+        assert_eq!(es("Llamar en 2 horas"), "Llamar in 2 hours");
+    }
+
+    #[cfg(feature = "locale-fr")]
+    #[test]
+    fn french_tests() {
+        assert_eq!(Locale::from_tag("fr-CA"), Some(Locale::French));
+        let fr = |text| Locale::French.translate(text).into_owned();
+        assert_eq!(fr("Dîner demain à 20 heures"), "Dîner tomorrow at 20:00");
+        assert_eq!(fr("Réunion lundi prochain"), "Réunion next monday");
+        assert_eq!(fr("Fête le 1er juin"), "Fête le june 1");
+        assert_eq!(fr("Appel la semaine prochaine"), "Appel la next week");
+    }
+
+    #[cfg(feature = "locale-de")]
+    #[test]
+    fn german_tests() {
+        assert_eq!(Locale::from_tag("de"), Some(Locale::German));
+        let de = |text| Locale::German.translate(text).into_owned();
+        assert_eq!(de("Essen morgen um 19 Uhr"), "Essen tomorrow at 19:00");
+        assert_eq!(de("Treffen nächsten Montag"), "Treffen next monday");
+        assert_eq!(de("Urlaub am 5. Juni"), "Urlaub on june 5");
+        assert_eq!(de("Sport morgen früh"), "Sport tomorrow morning");
+    }
+}
@@ -0,0 +1,361 @@
+//! Recognize Chinese lunisolar calendar dates and convert them into the [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) format.
+//!
+//! Month boundaries are computed astronomically: months start on the day (China Standard Time) of a new moon,
+//! month 11 contains the winter solstice, and in a year with thirteen months the first month without a major
+//! solar term is the leap month. New moons and solar longitudes use the approximations from Jean Meeus,
+//! _Astronomical Algorithms_, which are accurate to a few minutes.
+
+use chrono::{Datelike, Duration, NaiveDate};
+use std::f64::consts::PI;
+
+use crate::calendar::{CalendarDate, CalendarSystem};
+use crate::patterns::{compiled, Pattern};
+
+/// Julian Day Number of 0000-12-31, i.e. `num_days_from_ce() == 0`.
+const CE_EPOCH: f64 = 1_721_425.0;
+
+/// China Standard Time (UTC+8) as a fraction of a day.
+const CST_OFFSET: f64 = 8.0 / 24.0;
+
+/// Mean length of a lunation in days.
+const SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// Named festivals and the lunar (month, day) they fall on.
+const FESTIVALS: [(&str, u32, u32); 7] = [
+    (r"(lunar|chinese)\snew\syear|spring\sfestival", 1, 1),
+    (r"lantern\sfestival", 1, 15),
+    (r"dragon\sboat\sfestival", 5, 5),
+    (r"qixi(\sfestival)?", 7, 7),
+    (r"ghost\sfestival", 7, 15),
+    (r"mid[-\s]autumn\sfestival|moon\sfestival", 8, 15),
+    (r"double\sninth(\sfestival)?", 9, 9),
+];
+
+/// A day of a lunar month, e.g. "the 15th day of the eighth lunar month", with the captures `day` and `month`.
+static LUNAR_DAY: Pattern = Pattern::new(
+    r"(?i)\b(?P<day>[\w-]+)\sday\sof\sthe\s(?P<month>[\w-]+)\s(lunar\smonth|month\sof\sthe\slunar\scalendar)\b",
+);
+
+/// An ordinal in digits, e.g. "8th", with the capture `num`.
+static NUMERIC_ORDINAL: Pattern = Pattern::new(r"^(?P<num>\d{1,2})(st|nd|rd|th)$");
+
+/// An ordinal in the twenties in words, e.g. "twenty-first", with the capture `unit`.
+static TWENTIES_ORDINAL: Pattern = Pattern::new(r"^twenty[-\s]?(?P<unit>\w+)$");
+
+#[derive(Debug, Default, Clone, Copy)]
+/// The Chinese lunisolar calendar, recognizing festival names and phrases like "the 15th day of the eighth lunar month".
+pub struct LunarCalendar;
+
+impl CalendarSystem for LunarCalendar {
+    fn name(&self) -> &'static str {
+        "lunar"
+    }
+
+    fn recognize(&self, text: &str) -> Option<CalendarDate> {
+        for (pattern, month, day) in FESTIVALS.iter() {
+            let re = compiled(&format!(r"(?i)\b({})\b", pattern)).unwrap();
+            if re.is_match(text) {
+                return Some(CalendarDate {
+                    year: None,
+                    month: *month,
+                    day: *day,
+                });
+            }
+        }
+
+        let caps = LUNAR_DAY.captures(text)?;
+        let day = ordinal_value(&caps["day"])?;
+        let month = ordinal_value(&caps["month"])?;
+        if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+            return None;
+        }
+
+        Some(CalendarDate {
+            year: None,
+            month,
+            day,
+        })
+    }
+
+    /// Resolves within the lunar year that begins in the same Gregorian year as `now`, or in `date.year` if given.
+    fn to_gregorian(&self, date: &CalendarDate, now: NaiveDate) -> Option<NaiveDate> {
+        let months = lunar_year_months(date.year.unwrap_or_else(|| now.year()));
+        let index = months
+            .iter()
+            .position(|&(month, leap, _)| month == date.month && !leap)?;
+
+        let start = months[index].2;
+        let length = match months.get(index + 1) {
+            Some(&(_, _, next)) => (next - start).num_days(),
+            None => 30,
+        };
+        if date.day < 1 || date.day as i64 > length {
+            return None;
+        }
+
+        Some(start + Duration::days(date.day as i64 - 1))
+    }
+}
+
+/// Parses "8th" or "eighth" / "twenty-first" into its numeric value.
+fn ordinal_value(text: &str) -> Option<u32> {
+    let text = text.to_lowercase();
+
+    if let Some(caps) = NUMERIC_ORDINAL.captures(&text) {
+        return caps["num"].parse().ok();
+    }
+
+    let ordinals = [
+        "first",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "eighth",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+        "twentieth",
+    ];
+    if let Some(i) = ordinals.iter().position(|o| *o == text) {
+        return Some(i as u32 + 1);
+    }
+    if text == "thirtieth" {
+        return Some(30);
+    }
+
+    let caps = TWENTIES_ORDINAL.captures(&text)?;
+    let unit = ordinals[..9].iter().position(|o| *o == &caps["unit"])?;
+    Some(21 + unit as u32)
+}
+
+/// Returns `(month, is_leap, first day)` for every month of the lunar year that begins in `year`,
+/// followed by the first month of the next lunar year.
+fn lunar_year_months(year: i32) -> Vec<(u32, bool, NaiveDate)> {
+    let mut months: Vec<_> = months_from_solstice(year - 1)
+        .into_iter()
+        .skip_while(|&(month, _, _)| month != 1)
+        .collect();
+    months.extend(months_from_solstice(year));
+    let next_new_year = months
+        .iter()
+        .skip(1)
+        .position(|&(month, leap, _)| month == 1 && !leap)
+        .map(|i| i + 2)
+        .unwrap_or_else(|| months.len());
+    months.truncate(next_new_year);
+    months
+}
+
+/// Returns `(month, is_leap, first day)` for the months from the one containing the December solstice of `year`
+/// up to, but not including, the one containing the following December solstice.
+fn months_from_solstice(year: i32) -> Vec<(u32, bool, NaiveDate)> {
+    let start = new_moon_on_or_before(solstice_date(year));
+    let end = new_moon_on_or_before(solstice_date(year + 1));
+
+    let starts: Vec<NaiveDate> = (start..=end).map(new_moon_date).collect();
+    let has_leap = starts.len() == 14;
+
+    let mut months = vec![(11, false, starts[0])];
+    let mut month = 11;
+    let mut leap_found = false;
+    for i in 1..starts.len() - 1 {
+        if has_leap && !leap_found && !has_major_term(starts[i], starts[i + 1]) {
+            leap_found = true;
+            months.push((month, true, starts[i]));
+        } else {
+            month = month % 12 + 1;
+            months.push((month, false, starts[i]));
+        }
+    }
+    months
+}
+
+/// Returns the index of the last new moon falling on or before `date` (China Standard Time).
+fn new_moon_on_or_before(date: NaiveDate) -> i64 {
+    let jd = date.num_days_from_ce() as f64 + CE_EPOCH;
+    let mut k = ((jd - 2_451_550.1) / SYNODIC_MONTH).floor() as i64 + 1;
+    while new_moon_date(k) > date {
+        k -= 1;
+    }
+    k
+}
+
+/// Returns the date (China Standard Time) of the December solstice of `year`.
+fn solstice_date(year: i32) -> NaiveDate {
+    let mut jd = (NaiveDate::from_ymd_opt(year, 12, 21)
+        .unwrap()
+        .num_days_from_ce() as f64)
+        + CE_EPOCH;
+    for _ in 0..5 {
+        let diff = normalize_degrees(270.0 - solar_longitude(jd) + 180.0) - 180.0;
+        jd += diff * 365.242_19 / 360.0;
+    }
+    jd_to_cst_date(jd)
+}
+
+/// Returns `true` if a major solar term (a multiple of 30° of solar longitude) begins within the month
+/// starting on `start` and ending the day before `next`.
+fn has_major_term(start: NaiveDate, next: NaiveDate) -> bool {
+    major_term(start) != major_term(next)
+}
+
+/// Returns the index of the major solar term in effect at the start of `date` (China Standard Time).
+fn major_term(date: NaiveDate) -> i64 {
+    let jd = date.num_days_from_ce() as f64 + CE_EPOCH - 0.5 - CST_OFFSET;
+    (solar_longitude(jd) / 30.0).floor() as i64
+}
+
+/// Returns the date (China Standard Time) of the new moon with index `k`, counted from January 2000.
+fn new_moon_date(k: i64) -> NaiveDate {
+    jd_to_cst_date(new_moon(k))
+}
+
+/// Converts a Julian Day to its calendar date in China Standard Time.
+fn jd_to_cst_date(jd: f64) -> NaiveDate {
+    let days = (jd + 0.5 + CST_OFFSET).floor() - CE_EPOCH;
+    NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap()
+}
+
+/// Returns the Julian Day of the new moon with index `k` (Meeus, chapter 49).
+fn new_moon(k: i64) -> f64 {
+    let k = k as f64;
+    let t = k / 1236.85;
+    let jde = 2_451_550.097_66 + SYNODIC_MONTH * k + 0.000_154_37 * t * t
+        - 0.000_000_150 * t * t * t
+        + 0.000_000_000_73 * t * t * t * t;
+
+    let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+    let m = radians(2.5534 + 29.105_356_70 * k - 0.000_001_4 * t * t);
+    let mp =
+        radians(201.5643 + 385.816_935_28 * k + 0.010_758_2 * t * t + 0.000_012_38 * t * t * t);
+    let f = radians(160.7108 + 390.670_502_84 * k - 0.001_611_8 * t * t - 0.000_002_27 * t * t * t);
+    let om = radians(124.7746 - 1.563_755_88 * k + 0.002_067_2 * t * t);
+
+    let correction = -0.407_20 * mp.sin()
+        + 0.172_41 * e * m.sin()
+        + 0.016_08 * (2.0 * mp).sin()
+        + 0.010_39 * (2.0 * f).sin()
+        + 0.007_39 * e * (mp - m).sin()
+        - 0.005_14 * e * (mp + m).sin()
+        + 0.002_08 * e * e * (2.0 * m).sin()
+        - 0.001_11 * (mp - 2.0 * f).sin()
+        - 0.000_57 * (mp + 2.0 * f).sin()
+        + 0.000_56 * e * (2.0 * mp + m).sin()
+        - 0.000_42 * (3.0 * mp).sin()
+        + 0.000_42 * e * (m + 2.0 * f).sin()
+        + 0.000_38 * e * (m - 2.0 * f).sin()
+        - 0.000_24 * e * (2.0 * mp - m).sin()
+        - 0.000_17 * om.sin()
+        - 0.000_07 * (mp + 2.0 * m).sin()
+        + 0.000_04 * (2.0 * mp - 2.0 * f).sin()
+        + 0.000_04 * (3.0 * m).sin()
+        + 0.000_03 * (mp + m - 2.0 * f).sin()
+        + 0.000_03 * (2.0 * mp + 2.0 * f).sin()
+        - 0.000_03 * (mp + m + 2.0 * f).sin()
+        + 0.000_03 * (mp - m + 2.0 * f).sin()
+        - 0.000_02 * (mp - m - 2.0 * f).sin()
+        - 0.000_02 * (3.0 * mp + m).sin()
+        + 0.000_02 * (4.0 * mp).sin();
+
+    jde + correction
+}
+
+/// Returns the apparent longitude of the sun in degrees at Julian Day `jd` (Meeus, chapter 25).
+fn solar_longitude(jd: f64) -> f64 {
+    let t = (jd - 2_451_545.0) / 36525.0;
+    let l0 = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let m = radians(357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t);
+    let c = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * m.sin()
+        + (0.019_993 - 0.000_101 * t) * (2.0 * m).sin()
+        + 0.000_289 * (3.0 * m).sin();
+    let om = radians(125.04 - 1934.136 * t);
+    normalize_degrees(l0 + c - 0.005_69 - 0.004_78 * om.sin())
+}
+
+fn radians(degrees: f64) -> f64 {
+    degrees * PI / 180.0
+}
+
+fn normalize_degrees(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
+
+#[cfg(test)]
+mod lunar_tests {
+    use super::{CalendarDate, CalendarSystem, LunarCalendar};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn recognize_tests() {
+        assert_recognize_lunar("Lunar New Year", 1, 1);
+        assert_recognize_lunar("dinner for chinese new year", 1, 1);
+        assert_recognize_lunar("Mid-Autumn Festival", 8, 15);
+        assert_recognize_lunar("the eighth day of the fourth lunar month", 4, 8);
+        assert_recognize_lunar("15th day of the 8th lunar month", 8, 15);
+        assert_recognize_lunar("twenty-third day of the twelfth lunar month", 12, 23);
+        assert_eq!(
+            LunarCalendar.recognize("the first day of the thirteenth lunar month"),
+            None
+        );
+        assert_eq!(LunarCalendar.recognize("June 5th"), None);
+    }
+
+    #[test]
+    fn new_year_tests() {
+        assert_lunar_to_gregorian(1, 1, 2020, ymd(2020, 1, 25));
+        assert_lunar_to_gregorian(1, 1, 2021, ymd(2021, 2, 12));
+        assert_lunar_to_gregorian(1, 1, 2022, ymd(2022, 2, 1));
+        assert_lunar_to_gregorian(1, 1, 2023, ymd(2023, 1, 22));
+        assert_lunar_to_gregorian(1, 1, 2024, ymd(2024, 2, 10));
+        assert_lunar_to_gregorian(1, 1, 2025, ymd(2025, 1, 29));
+        assert_lunar_to_gregorian(1, 1, 2026, ymd(2026, 2, 17));
+    }
+
+    #[test]
+    fn festival_tests() {
+        // 2023 has a leap second month and 2025 a leap sixth month.
+        assert_lunar_to_gregorian(5, 5, 2023, ymd(2023, 6, 22));
+        assert_lunar_to_gregorian(5, 5, 2024, ymd(2024, 6, 10));
+        assert_lunar_to_gregorian(5, 5, 2025, ymd(2025, 5, 31));
+        assert_lunar_to_gregorian(8, 15, 2023, ymd(2023, 9, 29));
+        assert_lunar_to_gregorian(8, 15, 2024, ymd(2024, 9, 17));
+        assert_lunar_to_gregorian(8, 15, 2025, ymd(2025, 10, 6));
+        assert_lunar_to_gregorian(12, 23, 2024, ymd(2025, 1, 22));
+    }
+
+    fn assert_recognize_lunar(text: &str, month: u32, day: u32) {
+        assert_eq!(
+            LunarCalendar.recognize(text),
+            Some(CalendarDate {
+                year: None,
+                month,
+                day
+            })
+        )
+    }
+
+    fn assert_lunar_to_gregorian(month: u32, day: u32, year: i32, expected: NaiveDate) {
+        let now = ymd(year, 6, 1);
+        let date = CalendarDate {
+            year: None,
+            month,
+            day,
+        };
+        assert_eq!(LunarCalendar.to_gregorian(&date, now), Some(expected))
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
@@ -0,0 +1,112 @@
+//! Recognize numbers written as English words, e.g. "twenty five" or "two and a half".
+
+/// Number words below twenty, in order.
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// Multiples of ten from twenty, in order.
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Returns a regex pattern, without capture groups, matching a number written in digits or words,
+/// e.g. "15", "1.5", "twenty-five", "an", "two and a half" or "half an".
+pub(crate) fn number_pattern() -> String {
+    // longer words first, so "seventeen" is not read as "seven"
+    let mut units: Vec<&str> = UNITS.to_vec();
+    units.sort_by_key(|word| std::cmp::Reverse(word.len()));
+    let units = units.join("|");
+    let tens = TENS.join("|");
+
+    format!(
+        r"(?:\d{{1,2}}(?:\.\d+)?|(?:(?:{tens})(?:[-\s](?:{units}))?|{units}|an?)(?:\sand\s(?:a\s)?half)?|half\san?)",
+        tens = tens,
+        units = units
+    )
+}
+
+/// Parses a number matched by [`number_pattern`](fn.number_pattern.html) into its value.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let text = text.trim().to_lowercase();
+    if let Ok(n) = text.parse::<f64>() {
+        return Some(n);
+    }
+    if text == "half a" || text == "half an" {
+        return Some(0.5);
+    }
+
+    let (whole, half) = match text.find(" and ") {
+        Some(i) => (&text[..i], 0.5),
+        None => (text.as_str(), 0.0),
+    };
+
+    let value = match whole {
+        "a" | "an" => 1,
+        _ => {
+            let mut words = whole.split(['-', ' ']);
+            let first = words.next()?;
+            match TENS.iter().position(|w| *w == first) {
+                Some(t) => {
+                    let unit = match words.next() {
+                        Some(word) => UNITS.iter().position(|w| *w == word).filter(|u| *u < 10)?,
+                        None => 0,
+                    };
+                    (t + 2) * 10 + unit
+                }
+                None => UNITS.iter().position(|w| *w == first)?,
+            }
+        }
+    };
+    Some(value as f64 + half)
+}
+
+#[cfg(test)]
+mod number_words_tests {
+    use super::{number_pattern, parse_number};
+    use regex::Regex;
+
+    #[test]
+    fn parse_number_tests() {
+        assert_eq!(parse_number("7"), Some(7.0));
+        assert_eq!(parse_number("1.5"), Some(1.5));
+        assert_eq!(parse_number("seven"), Some(7.0));
+        assert_eq!(parse_number("Seventeen"), Some(17.0));
+        assert_eq!(parse_number("twenty"), Some(20.0));
+        assert_eq!(parse_number("twenty-five"), Some(25.0));
+        assert_eq!(parse_number("forty five"), Some(45.0));
+        assert_eq!(parse_number("an"), Some(1.0));
+        assert_eq!(parse_number("half an"), Some(0.5));
+        assert_eq!(parse_number("two and a half"), Some(2.5));
+        assert_eq!(parse_number("twenty twelve"), None);
+        assert_eq!(parse_number("lots"), None);
+    }
+
+    #[test]
+    fn number_pattern_tests() {
+        let re = Regex::new(&format!(r"(?i)^{}$", number_pattern())).unwrap();
+        assert!(re.is_match("seventeen"));
+        assert!(re.is_match("ninety-nine"));
+        assert!(re.is_match("one and a half"));
+        assert!(!re.is_match("several"));
+    }
+}
@@ -0,0 +1,495 @@
+//! Temporal patterns shared by the parsers in this crate and by `event_parser`, so that each is written in one place
+//! and compiled once, the first time it is used.
+//!
+//! # Example
+//! ```
+//! use date_time_parser::patterns::MONTH_DATE;
+//!
+//! let caps = MONTH_DATE.captures("Lunch on June 5th").unwrap();
+//! assert_eq!(&caps["date"], "5");
+//! ```
+
+use chrono::Weekday;
+use regex::{Captures, Match, Regex};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock};
+
+/// A regular expression compiled the first time it is used. Dereferences to [`Regex`](https://docs.rs/regex/1.3.6/regex/struct.Regex.html).
+pub struct Pattern {
+    source: &'static str,
+    regex: OnceLock<Regex>,
+}
+
+impl Pattern {
+    /// Creates a pattern from its source, which is compiled the first time the pattern is used.
+    ///
+    /// # Panics
+    ///
+    /// Using the pattern panics if `source` is not a valid regular expression.
+    pub const fn new(source: &'static str) -> Pattern {
+        Pattern {
+            source,
+            regex: OnceLock::new(),
+        }
+    }
+
+    /// Returns the pattern as written, for building larger patterns around it.
+    pub fn as_str(&self) -> &'static str {
+        self.source
+    }
+}
+
+impl Deref for Pattern {
+    type Target = Regex;
+
+    fn deref(&self) -> &Regex {
+        self.regex.get_or_init(|| Regex::new(self.source).unwrap())
+    }
+}
+
+/// A regular expression built from other parts, like the number words, when it is first used, and compiled once.
+pub(crate) struct BuiltPattern {
+    build: fn() -> String,
+    regex: OnceLock<Regex>,
+}
+
+impl BuiltPattern {
+    /// Creates a pattern whose source is returned by `build`, which is called the first time the pattern is used.
+    pub(crate) const fn new(build: fn() -> String) -> BuiltPattern {
+        BuiltPattern {
+            build,
+            regex: OnceLock::new(),
+        }
+    }
+}
+
+impl Deref for BuiltPattern {
+    type Target = Regex;
+
+    fn deref(&self) -> &Regex {
+        self.regex
+            .get_or_init(|| Regex::new(&(self.build)()).unwrap())
+    }
+}
+
+/// Returns `source` compiled, compiling each source only the first time it is asked for. Used for patterns made at run
+/// time from tables, like holiday names, or from phrases added to a parser.
+pub(crate) fn compiled(source: &str) -> Result<Regex, regex::Error> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(re) = cache.get(source) {
+        return Ok(re.clone());
+    }
+    let re = Regex::new(source)?;
+    cache.insert(source.to_owned(), re.clone());
+    Ok(re)
+}
+
+/// A month name followed by a day, maybe with a year, e.g. "June 5", "Aug 21st", "June 5th, 2026" or "Jun 5 '26",
+/// with the captures `month`, `date` and `year`.
+pub static MONTH_DATE: Pattern = Pattern::new(
+    r"(?i)(?P<month>jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)\s(?:(?P<date>\d{1,2})(?:st|nd|rd|th)?\b(?:,?\s(?P<year>\d{4}\b|'\d{2}\b))?)?",
+);
+
+/// A day followed by a month name, maybe with a year, e.g. "4 July", "the 1st of June" or "12 Dec '23", with the
+/// captures `date`, `month` and `year`.
+pub static DAY_MONTH: Pattern = Pattern::new(
+    r"(?i)\b(?:the\s)?(?P<date>\d{1,2})(?:st|nd|rd|th)?\s(?:of\s)?(?P<month>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t|tember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\b\.?(?:,?\s(?P<year>\d{4}\b|'\d{2}\b))?",
+);
+
+/// A numeric month and day, e.g. "6/10", with the captures `month` and `date`.
+pub static NUMERIC_DATE: Pattern = Pattern::new(r"(?P<month>\d{1,2})(/)(?P<date>\d{1,2})");
+
+/// A numeric month, day and year, e.g. "6/10/2021", with the captures `month`, `date` and `year`.
+pub static NUMERIC_DATE_WITH_YEAR: Pattern =
+    Pattern::new(r"(?P<month>\d{1,2})(/)(?P<date>\d{1,2})(/)(?P<year>\d{4}|\d{2})");
+
+/// Any date written out with its day, e.g. "June 5", "Aug 21st, 2021", "4 July", "6/10/21", "2021-06-10" or "the 21st",
+/// with the capture `day`, `first_day`, `numeric_day`, `iso_day` or `nth_day`. The days of these dates are never hours.
+pub static DATE_MENTION: Pattern = Pattern::new(
+    r"(?i)\b(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\.?\s(?P<day>\d{1,2})(st|nd|rd|th)?\b(,?\s(\d{4}|'\d{2})\b)?|\b(?P<first_day>\d{1,2})(st|nd|rd|th)?\s(of\s)?(jan(uary)?|feb(ruary)?|mar(ch)?|apr(il)?|may|june?|july?|aug(ust)?|sep(t|tember)?|oct(ober)?|nov(ember)?|dec(ember)?)\b\.?(,?\s(\d{4}|'\d{2})\b)?|\b\d{1,2}/(?P<numeric_day>\d{1,2})(/\d{2,4})?\b|\b\d{4}-\d{2}-(?P<iso_day>\d{2})\b|\bthe\s(?P<nth_day>\d{1,2})(st|nd|rd|th)\b(\sof\b)?",
+);
+
+/// A season, e.g. "this summer", "early fall" or "in the winter", with the captures `rel` or `part`, `season` and
+/// `year`, or a season in a year on its own, e.g. "spring 2025", with the captures `bare_season` and `bare_year`. A
+/// season needs one of these words around it, so "Summer Camp" is not one.
+pub static SEASON: Pattern = Pattern::new(
+    r"(?i)\b(?:(?:(?P<rel>this|next|last)\s|(?P<part>early|mid|late)[\s-]|(?:in|during)\s(?:the\s)?)(?P<season>spring|summer|fall|autumn|winter)\b(?:\s(?:of\s)?(?P<year>\d{4}))?|(?P<bare_season>spring|summer|fall|autumn|winter)\s(?:of\s)?(?P<bare_year>\d{4}))\b",
+);
+
+/// A quarter of a year, e.g. "Q3", "Q1 2025" or "the first quarter of 2025", with the captures `q` or `nth`, and
+/// `year`.
+pub static QUARTER: Pattern = Pattern::new(
+    r"(?i)\b(?:q(?P<q>[1-4])|(?P<nth>first|second|third|fourth|1st|2nd|3rd|4th)\squarter)\b(?:,?\s(?:of\s)?(?P<year>\d{4})\b)?",
+);
+
+/// The start or end of a week, month or year, e.g. "the end of this month" or "beginning of next year", with the
+/// captures `edge`, `rel` and `unit`, or a week or year on its own, e.g. "next week", with the captures `bare_rel` and
+/// `bare_unit`. `rel` is "this", "next", "last" or "the".
+pub static PERIOD: Pattern = Pattern::new(
+    r"(?i)\b(?:(?:the\s)?(?P<edge>start|beginning|end)\sof\s(?:(?P<rel>this|next|last|the)\s)?(?P<unit>week|month|year)|(?P<bare_rel>next|last)\s(?P<bare_unit>week|year))\b",
+);
+
+/// A day of the month on its own, e.g. "the 21st", with the capture `day`, and `of` when a month follows, as in
+/// "the 21st of June".
+pub static DAY_OF_MONTH: Pattern =
+    Pattern::new(r"(?i)\bthe\s(?P<day>\d{1,2})(st|nd|rd|th)\b(?P<of>\sof\b)?");
+
+/// A weekday counted within a month, e.g. "the first Monday of June", "last Fri of the month" or "every 2nd Tuesday of
+/// the month", with the captures `nth`, `day`, and `month` or `rel`. `rel` is "this", "next", "last", "the", "every" or
+/// "each", and `every` is there when the weekday comes every month.
+pub static NTH_WEEKDAY_OF_MONTH: Pattern = Pattern::new(
+    r"(?i)\b(?:(?P<every>every|each)\s|the\s)?(?P<nth>first|second|third|fourth|fifth|last|1st|2nd|3rd|4th|5th)\s(?P<day>monday|mon|tuesday|tues|tue|wednesday|weds|wed|thursday|thurs|thur|thu|friday|fri|saturday|sat|sunday|sun)\s(?:of|in)\s(?:(?P<rel>this|next|last|the|every|each)\smonth|(?P<month>jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*)\b",
+);
+
+/// An ISO 8601 date with an optional time and UTC offset, e.g. "2021-06-05", "2021-06-05T14:30" or
+/// "2021-06-05 14:30:00+02:00", with the captures `year`, `month`, `day`, `hour`, `minute` and `second`, and `zone`
+/// or `zone_after_minute` for the offset or "Z". An offset like "-07:00" is only read after seconds, so "09:30-10:00" is a range.
+pub static ISO_DATE_TIME: Pattern = Pattern::new(
+    r"(?i)\b(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})(?:[T\s](?P<hour>\d{2}):(?P<minute>\d{2})(?::(?P<second>\d{2})(?:[.,]\d+)?(?P<zone>z|[+-]\d{2}(?::?\d{2})?)?|(?P<zone_after_minute>z|\+\d{2}(?::?\d{2})?))?)?\b",
+);
+
+/// An ordinal day of the year, e.g. "julian day 045", "day 45 of 2021" or "2021-045", with the captures `ddd` and
+/// `year`, `day` and `day_year`, or `iso_year` and `iso_ddd`. Without "julian", a day needs its year, so "day 2 of the
+/// conference" is not one.
+pub static ORDINAL_DATE: Pattern = Pattern::new(
+    r"(?i)\b(?:julian\sday\s(?P<ddd>\d{1,3})(?:,?\s(?:of\s)?(?P<year>\d{4}))?|day\s(?P<day>\d{1,3}),?\s(?:of\s)?(?P<day_year>\d{4})|(?P<iso_year>\d{4})-(?P<iso_ddd>\d{3}))\b",
+);
+
+/// A weekday by any of the names in [`WEEKDAY_NAMES`](constant.WEEKDAY_NAMES.html), e.g. "Fri", "tues" or
+/// "Saturday", with the capture `day`. [`weekday_named`](fn.weekday_named.html) reads the capture.
+pub static WEEKDAY: Pattern = Pattern::new(
+    r"(?i)\b(?P<day>monday|mon|tuesday|tues|tue|wednesday|weds|wed|thursday|thurs|thur|thu|friday|fri|saturday|sat|sunday|sun)\b",
+);
+
+/// The names each weekday is read by: its full name and its standard and informal abbreviations.
+pub const WEEKDAY_NAMES: [(Weekday, &[&str]); 7] = [
+    (Weekday::Mon, &["monday", "mon"]),
+    (Weekday::Tue, &["tuesday", "tues", "tue"]),
+    (Weekday::Wed, &["wednesday", "weds", "wed"]),
+    (Weekday::Thu, &["thursday", "thurs", "thur", "thu"]),
+    (Weekday::Fri, &["friday", "fri"]),
+    (Weekday::Sat, &["saturday", "sat"]),
+    (Weekday::Sun, &["sunday", "sun"]),
+];
+
+/// Returns the weekday `name` is one of the [`WEEKDAY_NAMES`](constant.WEEKDAY_NAMES.html) of, in any case.
+///
+/// # Example
+/// ```
+/// use chrono::Weekday;
+/// use date_time_parser::patterns::weekday_named;
+///
+/// assert_eq!(weekday_named("Thurs"), Some(Weekday::Thu));
+/// assert_eq!(weekday_named("month"), None);
+/// ```
+pub fn weekday_named(name: &str) -> Option<Weekday> {
+    let name = name.to_lowercase();
+    WEEKDAY_NAMES
+        .iter()
+        .find(|(_, names)| names.contains(&name.as_str()))
+        .map(|(weekday, _)| *weekday)
+}
+
+/// A weekend near the current date, e.g. "this weekend", "next weekend" or "the weekend", with the capture `rel`.
+pub static WEEKEND: Pattern = Pattern::new(r"(?i)\b(?P<rel>this|next|last|the)\sweekend\b");
+
+/// A week by its ISO 8601 number, e.g. "week 32", "ISO week 32 2025" or "2025-W32", with the captures `week` and
+/// `year`, or `iso_week` and `iso_year`.
+pub static ISO_WEEK: Pattern = Pattern::new(
+    r"(?i)\b(?:iso\s)?week\s(?P<week>\d{1,2})\b(?:,?\s(?:of\s)?(?P<year>\d{4})\b)?|\b(?P<iso_year>\d{4})-?W(?P<iso_week>\d{2})\b",
+);
+
+/// A whole week or month, e.g. "all week" or "all of next month", with the captures `rel` and `unit`.
+pub static SPAN: Pattern =
+    Pattern::new(r"(?i)\ball\s(of\s)?((?P<rel>this|next|last)\s)?(?P<unit>week|month)\b");
+
+/// A clock time, e.g. "7", "12:30", "6pm", "7 PM" or "7:30 a.m.", with the captures `hour`, `minute` and `meridiem`.
+/// The last period of a dotted meridiem is left out of the match, as it may end a sentence.
+pub static CLOCK_TIME: Pattern = Pattern::new(
+    r"(?i)(^|\b)(?P<hour>\d{1,2}):?(?P<minute>\d{2})?(?P<meridiem>\s?[ap]\.\s?m|\s?[ap]m|[ap])?($|\b)",
+);
+
+/// A time in the continental `HHhMM` style, e.g. "19h30" or "9h05", with the captures `hour` and `minute`, or a bare
+/// hour like "19h" after "at", "à" or "@", with the capture `bare`. A bare hour elsewhere, like "2h", is a duration.
+pub static CONTINENTAL_TIME: Pattern = Pattern::new(
+    r"(?i)\b(?P<hour>\d{1,2})h(?P<minute>\d{2})\b|(?:\bat\s|à\s|@\s?)(?P<bare>\d{1,2})h\b",
+);
+
+/// A time on the 24-hour clock marked as one, e.g. "1830h", "0700 hours" or "18:30 hrs", with the captures `hour` and
+/// `minute`. The hour is written with two digits.
+pub static MILITARY_TIME: Pattern =
+    Pattern::new(r"(?i)\b(?P<hour>[01]\d|2[0-3]):?(?P<minute>[0-5]\d)\s?(?:h|hrs|hours)\b");
+
+/// A British colloquial time, e.g. "half 7", "half past seven", "quarter to 8" or "20 past 6pm", with the captures
+/// `half` or `minutes` and `dir` ("past" or "to"), `hour` and `meridiem`.
+pub static COLLOQUIAL_TIME: Pattern = Pattern::new(
+    r"(?i)\b(?:(?P<half>half)\s(?:past\s)?|(?P<minutes>(?:a\s)?quarter|twenty[\s-]five|twenty|ten|five|\d{1,2})(?:\sminutes?)?\s(?P<dir>past|to)\s)(?P<hour>\d{1,2}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)(?P<meridiem>\s?[ap]\.\s?m|\s?[ap]m)?\b",
+);
+
+/// A time of day in words, e.g. "seven thirty pm", "nine oh five", "ten o'clock" or "at seven", with the captures
+/// `lead`, `hour`, `minute`, `oclock` and `meridiem`. An hour alone, like the "two" of "two friends", also matches but is
+/// only a time with one of the other captures.
+pub static WORD_TIME: Pattern = Pattern::new(
+    r"(?i)(?P<lead>\b(?:at|around|by|until|till|from)\s)?\b(?P<hour>twelve|eleven|ten|nine|eight|seven|six|five|four|three|two|one)\b(?:[\s-](?P<minute>oh[\s-](?:one|two|three|four|five|six|seven|eight|nine)|(?:twenty|thirty|forty|fifty)(?:[\s-](?:one|two|three|four|five|six|seven|eight|nine))?|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen)\b)?(?P<oclock>\so'?clock\b)?(?P<meridiem>\s?[ap]\.\s?m\b\.?|\s?[ap]m\b)?",
+);
+
+/// Returns whether a match of [`WORD_TIME`](static.WORD_TIME.html) in `text` is a time, rather than a number like the
+/// "two" of "two friends" or the "one" of "at one point".
+///
+/// # Example
+/// ```
+/// use date_time_parser::patterns::{is_word_time, WORD_TIME};
+///
+/// let text = "Lunch with two friends at seven";
+/// let times: Vec<_> = WORD_TIME
+///     .captures_iter(text)
+///     .filter(|caps| is_word_time(caps, text))
+///     .map(|caps| caps[0].to_owned())
+///     .collect();
+/// assert_eq!(times, vec!["at seven"]);
+/// ```
+pub fn is_word_time(caps: &Captures, text: &str) -> bool {
+    if caps.name("minute").is_some()
+        || caps.name("oclock").is_some()
+        || caps.name("meridiem").is_some()
+    {
+        return true;
+    }
+    let next = text[caps.get(0).map_or(0, |m| m.end())..]
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+    caps.name("lead").is_some() && !matches!(next.as_str(), "of" | "point" | "another")
+}
+
+/// A time with am or pm given, e.g. "7pm", "9 a" or "7 p.m.".
+pub static MERIDIEM: Pattern = Pattern::new(r"(?i)\d\s?[ap](\.\s?m|m)?\b");
+
+/// A duration in shorthand or words, e.g. "1h30m", "45m", "2hrs" or "90 minutes", with the captures `h`, `m` and `mins`.
+pub static DURATION: Pattern = Pattern::new(
+    r"(?i)\b(?:(?P<h>\d{1,2})(?:h(?:rs?)?|\s(?:hours?|hrs?))(?:\s?(?P<m>\d{1,2})(?:m(?:ins?)?|\s(?:minutes?|mins?)))?|(?P<mins>\d{1,3})(?:m(?:ins?)?|\s(?:minutes?|mins?)))\b",
+);
+
+/// A timezone named right after a time, e.g. the "EST" of "3pm EST", "CET" of "14:00 CET", "UTC+2" of "9am UTC+2" or
+/// "America/New_York" of "3pm America/New_York", with the captures `before`, the end of the time, `zone`, the timezone
+/// with any brackets around it, `name` and `offset`. Abbreviations are only read in capitals.
+pub static TIME_ZONE: Pattern = Pattern::new(
+    r"(?P<before>\d(?i:\s?[ap]\.?m\.?)?|(?i:\bnoon|\bmidnight))\s*(?P<zone>\(?(?P<name>(?:UTC|GMT)(?P<offset>[+-]\d{1,2}(?::?\d{2})?)?|EST|EDT|ET|CST|CDT|CT|MST|MDT|MT|PST|PDT|PT|AKST|AKDT|HST|AST|ADT|NST|NDT|BST|IST|WET|WEST|CET|CEST|EET|EEST|MSK|JST|KST|SGT|HKT|AWST|ACST|ACDT|AEST|AEDT|NZST|NZDT|(?:Africa|America|Antarctica|Asia|Atlantic|Australia|Europe|Indian|Pacific)/[A-Za-z_-]+(?:/[A-Za-z_-]+)?)\b\)?)",
+);
+
+/// A number that is not a date or time, with the capture `local` for a phone number without its area code: a phone
+/// number, e.g. "555-1230", "555.123.4567" or "(555) 123-4567", a price or range of prices, e.g. "$5", "$5-10" or
+/// "12.50 dollars", or a version or range of versions, e.g. "v2.30", "v1.2-3" or "version 1.2".
+pub static NOT_TEMPORAL: Pattern = Pattern::new(
+    r"(?i)(?:\+\d{1,3}[\s.-]?)?(?:\(\d{3}\)\s?|\b\d{3}[.-])\d{3}[.-]\d{4}\b|\b(?P<local>\d{3}-\d{4})\b|[$€£¥]\s?\d[\d,]*(?:\.\d+)?[km]?(?:\s?[-–]\s?[$€£¥]?\d[\d,]*(?:\.\d+)?[km]?)?\b|\b\d[\d,]*(?:\.\d+)?(?:[-–]\d[\d,]*(?:\.\d+)?)?\s?(?:dollars|bucks|usd|eur|euros|gbp)\b|\b(?:v|version\s)\d+(?:\.\d+)*(?:[-–]\d+(?:\.\d+)*)?\b",
+);
+
+/// Returns whether a word or phrase found in `text` is part of a name, as a capitalized one followed by another
+/// capitalized word past the start of the text is, like "Midnight" of "Tickets for Midnight Express". A title starting
+/// the text, like "Morning Run", is not.
+///
+/// # Example
+/// ```
+/// use date_time_parser::patterns::in_name;
+/// use regex::Regex;
+///
+/// let evening = Regex::new(r"(?i)\bevening\b").unwrap();
+/// let text = "Coffee at Evening Star cafe";
+/// assert!(in_name(text, &evening.find(text).unwrap()));
+/// let text = "Coffee this evening";
+/// assert!(!in_name(text, &evening.find(text).unwrap()));
+/// ```
+pub fn in_name(text: &str, found: &Match) -> bool {
+    let capitalized = |word: &str| word.chars().next().is_some_and(char::is_uppercase);
+    found.start() > 0
+        && capitalized(found.as_str())
+        && text[found.end()..]
+            .strip_prefix(' ')
+            .is_some_and(capitalized)
+}
+
+/// Returns every number in `text` that is not a date or time, as matched by [`NOT_TEMPORAL`](static.NOT_TEMPORAL.html),
+/// in the order they appear. A phone number without its area code followed by am or pm, like "930-1030am", is a range
+/// of times and is left out.
+pub fn find_non_temporal(text: &str) -> impl Iterator<Item = Match<'_>> {
+    NOT_TEMPORAL
+        .captures_iter(text)
+        .filter(move |caps| {
+            let end = caps.get(0).unwrap().end();
+            caps.name("local").is_none() || !MERIDIEM_AFTER.is_match(&text[end..])
+        })
+        .map(|caps| caps.get(0).unwrap())
+}
+
+/// Returns `text` with every number in it that is not a date or time, as found by
+/// [`find_non_temporal`](fn.find_non_temporal.html), blanked out with spaces, so that the rest keeps its byte offsets.
+///
+/// # Example
+/// ```
+/// use date_time_parser::patterns::blank_non_temporal;
+///
+/// assert_eq!(blank_non_temporal("Call 555-1230 at 5pm"), "Call          at 5pm");
+/// assert_eq!(blank_non_temporal("$5 lunch"), "   lunch");
+/// assert_eq!(blank_non_temporal("deploy v2.30"), "deploy      ");
+/// ```
+pub fn blank_non_temporal(text: &str) -> Cow<'_, str> {
+    let mut blanked = Cow::Borrowed(text);
+    for found in find_non_temporal(text) {
+        blanked
+            .to_mut()
+            .replace_range(found.range(), &" ".repeat(found.len()));
+    }
+    blanked
+}
+
+/// Am or pm right at the start of the text, e.g. the "am" of "930-1030am".
+static MERIDIEM_AFTER: Pattern = Pattern::new(r"(?i)^\s?[ap]\.?m\b");
+
+#[cfg(test)]
+mod patterns_tests {
+    use super::{
+        blank_non_temporal, compiled, weekday_named, BuiltPattern, CLOCK_TIME, CONTINENTAL_TIME,
+        DATE_MENTION, DURATION, MERIDIEM, MONTH_DATE, WEEKDAY, WEEKDAY_NAMES,
+    };
+    use chrono::Weekday;
+    use regex::Regex;
+
+    #[test]
+    fn shared_pattern_tests() {
+        assert_eq!(&MONTH_DATE.captures("June 5th").unwrap()["month"], "Jun");
+        assert_eq!(&WEEKDAY.captures("next Friday").unwrap()["day"], "Friday");
+        assert_eq!(&CLOCK_TIME.captures("at 6:30pm").unwrap()["minute"], "30");
+        assert_eq!(&DURATION.captures("1h30m").unwrap()["m"], "30");
+        assert_eq!(
+            DATE_MENTION
+                .find("Party June 5, 2021 at 7")
+                .unwrap()
+                .as_str(),
+            "June 5, 2021"
+        );
+        assert_eq!(
+            DATE_MENTION.find("Party 4th July at 7").unwrap().as_str(),
+            "4th July"
+        );
+    }
+
+    #[test]
+    fn compiled_once_tests() {
+        static BUILT: BuiltPattern = BuiltPattern::new(|| format!(r"\b{}\b", "noon"));
+        assert!(BUILT.is_match("lunch at noon"));
+        assert!(std::ptr::eq(&*MONTH_DATE, &*MONTH_DATE));
+        assert!(std::ptr::eq::<Regex>(&*BUILT, &*BUILT));
+
+        let first = compiled(r"(?i)\bstandup\b").unwrap();
+        assert!(first.is_match("Daily Standup"));
+        assert_eq!(
+            compiled(r"(?i)\bstandup\b").unwrap().as_str(),
+            first.as_str()
+        );
+        assert!(compiled(r"(unclosed").is_err());
+    }
+
+    #[test]
+    fn meridiem_tests() {
+        for (text, time, meridiem) in [
+            ("Dinner at 7pm", "7pm", "pm"),
+            ("Dinner at 7 PM", "7 PM", " PM"),
+            ("Dinner at 7 p.m. with Sam", "7 p.m", " p.m"),
+            ("Call at 7:30 A.M.", "7:30 A.M", " A.M"),
+            ("Call at 7:30a.m.", "7:30a.m", "a.m"),
+            ("Call at 9a", "9a", "a"),
+        ] {
+            let caps = CLOCK_TIME.captures(text).unwrap();
+            assert_eq!(&caps[0], time, "{}", text);
+            assert_eq!(&caps["meridiem"], meridiem, "{}", text);
+            assert!(MERIDIEM.is_match(text), "{}", text);
+        }
+
+        let caps = CLOCK_TIME.captures("Meet at 7 a block away").unwrap();
+        assert!(caps.name("meridiem").is_none());
+    }
+
+    #[test]
+    fn continental_time_tests() {
+        let caps = CONTINENTAL_TIME.captures("Dîner 19h30").unwrap();
+        assert_eq!((&caps["hour"], &caps["minute"]), ("19", "30"));
+        let caps = CONTINENTAL_TIME.captures("Réunion à 9h").unwrap();
+        assert_eq!(&caps["bare"], "9");
+        assert!(CONTINENTAL_TIME.is_match("Dinner at 19h"));
+        assert!(!CONTINENTAL_TIME.is_match("Deep work 2h"));
+        assert!(!CONTINENTAL_TIME.is_match("Flight 1h30m"));
+        assert!(!DURATION.is_match("Dîner 19h30"));
+    }
+
+    #[test]
+    fn weekday_tests() {
+        for (weekday, names) in WEEKDAY_NAMES {
+            for name in names {
+                for written in [name.to_string(), name.to_uppercase(), capitalized(name)] {
+                    let text = format!("Lunch {} at noon", written);
+                    let caps = WEEKDAY.captures(&text).unwrap();
+                    assert_eq!(&caps["day"], written, "{}", text);
+                    assert_eq!(weekday_named(&caps["day"]), Some(weekday), "{}", text);
+                }
+            }
+        }
+
+        for text in [
+            "Simon says",
+            "next month",
+            "Sundays",
+            "Thursdays",
+            "wedding",
+            "Saturn",
+            "frisbee",
+            "nesay",
+        ] {
+            assert!(WEEKDAY.find(text).is_none(), "{}", text);
+        }
+        assert_eq!(weekday_named("tu"), None);
+        assert_eq!(weekday_named("Wednesday"), Some(Weekday::Wed));
+    }
+
+    #[test]
+    fn not_temporal_tests() {
+        for text in [
+            "Call 555-1230",
+            "Call (555) 123-4567",
+            "Call 555.123.4567",
+            "Call +1 555-123-4567",
+            "$5 lunch",
+            "€12.50 lunch",
+            "lunch for 20 bucks",
+            "deploy v2.30",
+            "ship version 1.2",
+        ] {
+            assert!(
+                blank_non_temporal(text)
+                    .chars()
+                    .all(|c| !c.is_ascii_digit()),
+                "{}",
+                text
+            );
+        }
+        for text in [
+            "Call 930-1030am",
+            "Dinner at 7:30",
+            "Party 6/5",
+            "Camp 6/1-6/8",
+        ] {
+            assert_eq!(blank_non_temporal(text), text);
+        }
+    }
+
+    fn capitalized(name: &str) -> String {
+        name[..1].to_uppercase() + &name[1..]
+    }
+}
@@ -0,0 +1,208 @@
+//! A cheap check for whether text could hold a date or time at all, so the parsers can skip the rest of a line of a log
+//! or an email without running their regular expressions on it.
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
+use std::sync::OnceLock;
+
+/// Parts of every word the built-in parsers read without a digit, like "fri" of "Friday" or "night" of "tonight".
+/// Dates and times with a digit in them are caught by the digit check instead.
+const KEYWORDS: &[&str] = &[
+    // weekdays and months, abbreviated
+    "mon",
+    "tue",
+    "wed",
+    "thu",
+    "fri",
+    "sat",
+    "sun",
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "may",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "oct",
+    "nov",
+    "dec",
+    // relative dates and times of day
+    "today",
+    "tomorrow",
+    "tmrw",
+    "yesterday",
+    "night",
+    "noon",
+    "morning",
+    "afternoon",
+    "evening",
+    "soon",
+    "shortly",
+    "later",
+    "while",
+    "bit",
+    "half",
+    "past",
+    "lunch",
+    "dinner",
+    "thing",
+    "eod",
+    "cob",
+    "business",
+    // hours and minutes in words
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "twenty",
+    "thirty",
+    "forty",
+    "fifty",
+    "clock",
+    // units, schedules and workplace terms
+    "day",
+    "week",
+    "month",
+    "year",
+    "hour",
+    "min",
+    "fortnight",
+    "quarter",
+    "spring",
+    "summer",
+    "fall",
+    "autumn",
+    "winter",
+    "daily",
+    "annual",
+    "every",
+    "eoq",
+    "fye",
+    "fiscal",
+    "financial",
+    // holidays and festivals without "day" in their names
+    "christmas",
+    "xmas",
+    "easter",
+    "thanksgiving",
+    "mlk",
+    "juneteenth",
+    "hallow",
+    "valentine",
+    "patrick",
+    "paddy",
+    "fools",
+    "mardi",
+    "nye",
+    "holiday",
+    "fete",
+    "reconciliation",
+    "ascension",
+    "assumption",
+    "saints",
+    "pentecost",
+    "whit",
+    "festival",
+    "lunar",
+    "qixi",
+    "ninth",
+];
+
+/// Returns `false` if `text` cannot hold a date, time or duration the built-in parsers read, which takes a single
+/// pass over it. Returns `true` if it might, in which case it still has to be parsed to find out.
+///
+/// The parsers run this check themselves unless phrases, calendars or holidays of their own have been added, since
+/// it only knows the built-in words.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be checked
+///
+/// # Example
+/// ```
+/// use date_time_parser::may_be_temporal;
+///
+/// assert!(may_be_temporal("Lunch at noon"));
+/// assert!(may_be_temporal("Meet me at 7"));
+/// assert!(!may_be_temporal("GET /index.html from client"));
+/// ```
+pub fn may_be_temporal(text: &str) -> bool {
+    static KEYWORD_AUTOMATON: OnceLock<AhoCorasick> = OnceLock::new();
+
+    // `\d` in the parsers' patterns matches digits of any script
+    if text.bytes().any(|b| b.is_ascii_digit())
+        || (!text.is_ascii() && text.chars().any(char::is_numeric))
+    {
+        return true;
+    }
+    KEYWORD_AUTOMATON
+        .get_or_init(|| {
+            AhoCorasickBuilder::new()
+                .ascii_case_insensitive(true)
+                .build(KEYWORDS)
+                .unwrap()
+        })
+        .is_match(text)
+}
+
+#[cfg(test)]
+mod prefilter_tests {
+    use super::may_be_temporal;
+    use crate::{DateParser, TimeParser};
+    use chrono::{NaiveDate, NaiveTime};
+
+    #[test]
+    fn may_be_temporal_tests() {
+        for text in [
+            "Dinner at 7",
+            "Party on Saturday",
+            "TOMORROW",
+            "lunch at noon",
+            "standup every week",
+            "back soon",
+            "call in a bit",
+            "Christmas party",
+            "Dentist ٣ pm",
+        ] {
+            assert!(may_be_temporal(text), "{:?}", text);
+        }
+        for text in [
+            "",
+            "ERROR connection reset by peer",
+            "Please review the attached document",
+        ] {
+            assert!(!may_be_temporal(text), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn skipped_text_tests() {
+        let today = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+        let now = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+        assert_eq!(
+            DateParser::parse_relative("Please review the attached document", today),
+            None
+        );
+        assert_eq!(
+            TimeParser::parse_relative("Please review the attached document", now),
+            None
+        );
+
+        // phrases added to a parser are still found
+        let parser = TimeParser::new()
+            .with_casual_time(r"lunch", NaiveTime::from_hms_opt(12, 30, 0).unwrap());
+        assert_eq!(
+            parser.find("lunch", now).unwrap().time,
+            NaiveTime::from_hms_opt(12, 30, 0).unwrap()
+        );
+    }
+}
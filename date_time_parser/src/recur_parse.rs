@@ -0,0 +1,754 @@
+//! Parse repeating schedules like "every day" or "every other week for 3 months" into a [`Recurrence`](struct.Recurrence.html).
+
+use chrono::{Duration, Months, NaiveDate, Weekday};
+use std::ops::Range;
+
+use crate::date_parse::{weekday_count, DateParser};
+use crate::number_words::{number_pattern, parse_number};
+use crate::patterns::{weekday_named, BuiltPattern, Pattern, NTH_WEEKDAY_OF_MONTH};
+use crate::prefilter::may_be_temporal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How often something repeats, following the iCalendar `FREQ` values.
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn from_unit(unit: &str) -> Option<Frequency> {
+        match unit.to_lowercase().as_str() {
+            "day" => Some(Frequency::Daily),
+            "week" => Some(Frequency::Weekly),
+            "month" => Some(Frequency::Monthly),
+            "year" => Some(Frequency::Yearly),
+            _ => None,
+        }
+    }
+
+    /// Returns `date` moved forward by `n` of this frequency's unit, clamping to the end of shorter months.
+    fn add(self, date: NaiveDate, n: u32) -> Option<NaiveDate> {
+        match self {
+            Frequency::Daily => date.checked_add_signed(Duration::days(n as i64)),
+            Frequency::Weekly => date.checked_add_signed(Duration::weeks(n as i64)),
+            Frequency::Monthly => date.checked_add_months(Months::new(n)),
+            Frequency::Yearly => date.checked_add_months(Months::new(n * 12)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// When a recurrence stops.
+pub enum RecurrenceEnd {
+    /// After a number of occurrences, e.g. "10 times".
+    Count(u32),
+    /// On the last date an occurrence may fall on, e.g. "until June 30".
+    Until(NaiveDate),
+    /// After a length of time from the first occurrence, e.g. "for two weeks" is `For(2, Frequency::Weekly)`.
+    For(u32, Frequency),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A repeating schedule, e.g. "every other week for 3 months" or "MWF".
+pub struct Recurrence {
+    /// The unit the schedule repeats in.
+    pub frequency: Frequency,
+    /// How many units pass between occurrences, 2 for "every other week".
+    pub interval: u32,
+    /// When the schedule stops, if it does.
+    pub end: Option<RecurrenceEnd>,
+    /// The weekdays a weekly schedule falls on, like Monday, Wednesday and Friday for "MWF", in order. Empty when it
+    /// falls on the weekday of its first occurrence.
+    pub days: Vec<Weekday>,
+    /// The day of the month a monthly schedule falls on, like 1 for "monthly on the 1st". `None` when it falls on the
+    /// day of the month of its first occurrence.
+    pub month_day: Option<u32>,
+    /// The weekday of the month a monthly schedule falls on, like `(1, Mon)` for "the first Monday of every month",
+    /// counted from the start of the month, or from its end when negative, as in `(-1, Fri)` for "the last Friday".
+    pub month_weekday: Option<(i8, Weekday)>,
+}
+
+impl Recurrence {
+    /// Returns when the schedule stops if its first occurrence is on `start`. A `For` window becomes a `Count` when
+    /// it holds a whole number of occurrences, like "every day for two weeks", and the last day of the window otherwise.
+    pub fn resolve_end(&self, start: NaiveDate) -> Option<RecurrenceEnd> {
+        let (n, unit) = match self.end? {
+            RecurrenceEnd::For(n, unit) => (n, unit),
+            end => return Some(end),
+        };
+        let units = match (self.frequency, unit) {
+            (f, u) if f == u => Some(n),
+            (Frequency::Daily, Frequency::Weekly) => Some(n * 7),
+            (Frequency::Monthly, Frequency::Yearly) => Some(n * 12),
+            _ => None,
+        };
+        match units {
+            Some(units) if units % self.interval == 0 => {
+                Some(RecurrenceEnd::Count(units / self.interval))
+            }
+            _ => Some(RecurrenceEnd::Until(unit.add(start, n)?.pred_opt()?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A schedule found by [`RecurParser::parse_relative`](struct.RecurParser.html#method.parse_relative).
+pub struct RecurMatch {
+    /// The schedule found in the text.
+    pub recurrence: Recurrence,
+    /// The text with the schedule taken out, so the date and time of the first occurrence can be parsed from it.
+    pub rest: String,
+    /// Where the repeating part, like "every other week", is in the text.
+    pub span: Range<usize>,
+    /// Where the part saying when it stops, like "for two weeks", is in the text, if there is one.
+    pub end_span: Option<Range<usize>>,
+    /// `true` if the schedule could also be read another way, like "biweekly", read as every other week but sometimes
+    /// meaning twice a week.
+    pub ambiguous: bool,
+}
+
+/// A day of every month, e.g. "the 1st of every month" or "on the 15th of each month", with the capture `day`.
+static DAY_OF_EVERY_MONTH: Pattern = Pattern::new(
+    r"(?i)\b(?:on\s)?the\s(?P<day>\d{1,2})(?:st|nd|rd|th)\sof\s(?:every|each)\smonth\b",
+);
+
+/// The day a monthly schedule falls on, e.g. "on the 1st" of "monthly on the 1st", with the capture `day`.
+static MONTH_DAY: Pattern = Pattern::new(r"(?i)\b(?:on\s)?the\s(?P<day>\d{1,2})(?:st|nd|rd|th)\b");
+
+/// The weekday a monthly schedule falls on, e.g. "on the first Monday" of "monthly on the first Monday", with the
+/// captures `nth` and `day`.
+static MONTH_WEEKDAY: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\b(?:on\s)?the\s(?P<nth>first|second|third|fourth|fifth|last|1st|2nd|3rd|4th|5th)\s(?P<day>{})\b",
+        WEEKDAY_NAME
+    )
+});
+
+/// Single words for how often something repeats, e.g. "daily" or "fortnightly", with the capture `adverb`.
+static ADVERB: Pattern = Pattern::new(
+    r"(?i)\b(?P<adverb>daily|weekly|bi-?weekly|fortnightly|monthly|bi-?monthly|quarterly|yearly|annually)\b",
+);
+
+/// A weekday by name, singular or plural, e.g. "Tue" or "Tuesdays".
+const WEEKDAY_NAME: &str = r"(?:monday|mon|tuesday|tues|tue|wednesday|weds|wed|thursday|thurs|thur|thu|friday|fri|saturday|sat|sunday|sun)s?";
+
+/// A schedule of every so many days, weeks, months or years, e.g. "every other week" or "every 3 days", with the
+/// captures `other`, `n` and `unit`.
+static EVERY: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bevery\s(?:(?P<other>other)\s|(?P<n>{})\s)?(?P<unit>day|week|month|year)s?\b",
+        number_pattern()
+    )
+});
+
+/// A weekly schedule on weekdays by name, e.g. "every Tuesday" or "every other Mon and Thurs", with the captures
+/// `other`, `n` and `days`.
+static EVERY_WEEKDAY: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bevery\s(?:(?P<other>other)\s|(?P<n>{num})\s)?(?P<days>{day}(?:(?:,|\s&|,?\sand)\s{day})*)\b",
+        num = number_pattern(),
+        day = WEEKDAY_NAME
+    )
+});
+
+/// Each weekday named in the `days` of [`EVERY_WEEKDAY`].
+static WEEKDAY_NAMES: BuiltPattern = BuiltPattern::new(|| format!("(?i){}", WEEKDAY_NAME));
+
+/// How long a schedule lasts, e.g. "for two weeks" or "for the next 3 months", with the captures `n` and `unit`.
+static WINDOW: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bfor\s(?:the\snext\s)?(?P<n>{})\s(?P<unit>day|week|month|year)s?\b",
+        number_pattern()
+    )
+});
+
+/// How many times a schedule repeats, e.g. "for 10 times" or "five times", with the capture `n`.
+static COUNT: BuiltPattern =
+    BuiltPattern::new(|| format!(r"(?i)\b(?:for\s)?(?P<n>{})\stimes\b", number_pattern()));
+
+/// When a schedule ends, e.g. "until June 30", with the capture `date`.
+static UNTIL: Pattern = Pattern::new(r"(?i)\buntil\s(?P<date>.+?)(\s(?:at|from|every)\s.*)?$");
+
+/// Weekday codes as in class schedules, case-sensitive so ordinary words are not read as days; R and U are the
+/// registrar's Thursday and Sunday.
+const WEEKDAY_CODE: &str = r"(?:Th|Tu|Sa|Su|M|T|W|R|F|S|U)";
+
+/// Weekday abbreviations in a compact set, e.g. "Mon/Wed".
+const WEEKDAY_ABBREVIATION: &str = r"(?i:mon|tues?|weds?|thurs?|thu|fri|sat|sun)";
+
+/// A compact set of weekdays followed by a time, a part of the day or the end of the text, e.g. "MWF 10am", "Yoga MWF
+/// at noon", "Tu/Th mornings" or "Mon/Wed 9am", with the captures `days` and `plural`, the "s" of a part of the day
+/// said of each of the days.
+static WEEKDAY_SET: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"\b(?P<days>{code}(?:/?{code})+|{name}(?:\s?[/,&]\s?{name})+)(?:\s(?:at\s)?(?:\d|(?i:(?:morning|afternoon|evening|night)(?P<plural>s)?|first\sthing|tonight|noon|midnight|lunch\s?time|dinner\s?time)\b)|\s*$)",
+        code = WEEKDAY_CODE,
+        name = WEEKDAY_ABBREVIATION
+    )
+});
+
+/// Each weekday in the `days` of [`WEEKDAY_SET`].
+static WEEKDAY_TOKENS: BuiltPattern =
+    BuiltPattern::new(|| format!("{}|{}", WEEKDAY_ABBREVIATION, WEEKDAY_CODE));
+
+#[derive(Default)]
+/// Container for parsing repeating schedules from string slices.
+pub struct RecurParser {}
+
+impl RecurParser {
+    /// Parses a string slice of natural language text for a repeating schedule with respect to a given date, which
+    /// "until" dates are read around. Returns a [`RecurMatch`](struct.RecurMatch.html) if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveDate`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDate.html) to interpret the natural language date around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use date_time_parser::{Frequency, RecurParser, RecurrenceEnd};
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 6, 1).unwrap();
+    /// let found = RecurParser::parse_relative("Standup every day at 9am for two weeks", now).unwrap();
+    ///
+    /// assert_eq!(found.recurrence.frequency, Frequency::Daily);
+    /// assert_eq!(found.recurrence.resolve_end(now), Some(RecurrenceEnd::Count(14)));
+    /// assert_eq!(found.rest, "Standup at 9am");
+    /// ```
+    pub fn parse_relative(text: &str, now: NaiveDate) -> Option<RecurMatch> {
+        if !may_be_temporal(text) {
+            return None;
+        }
+        let mut ambiguous = false;
+        let mut month_day = None;
+        let mut month_weekday = None;
+        // the "s" of "Tu/Th mornings", taken out so the part of the day is read as a time
+        let mut plural = None;
+        // "the first Monday of every month" and "every first Monday of the month"
+        let weekday_of_every_month = NTH_WEEKDAY_OF_MONTH.captures(text).filter(|caps| {
+            caps.name("every").is_some() && caps.name("rel").is_some()
+                || caps.name("rel").is_some_and(|rel| {
+                    rel.as_str().eq_ignore_ascii_case("every")
+                        || rel.as_str().eq_ignore_ascii_case("each")
+                })
+        });
+        let (frequency, interval, days, span) = match EVERY.captures(text) {
+            // "every month" in "the 1st of every month" is read with its day
+            _ if DAY_OF_EVERY_MONTH.is_match(text) => {
+                let caps = DAY_OF_EVERY_MONTH.captures(text).unwrap();
+                month_day = Some(caps["day"].parse().ok().filter(|d| (1..=31).contains(d))?);
+                (
+                    Frequency::Monthly,
+                    1,
+                    Vec::new(),
+                    caps.get(0).unwrap().range(),
+                )
+            }
+            _ if weekday_of_every_month.is_some() => {
+                let caps = weekday_of_every_month.unwrap();
+                month_weekday = Some((weekday_count(&caps["nth"])?, weekday_named(&caps["day"])?));
+                (
+                    Frequency::Monthly,
+                    1,
+                    Vec::new(),
+                    caps.get(0).unwrap().range(),
+                )
+            }
+            Some(caps) => {
+                let interval = if caps.name("other").is_some() {
+                    2
+                } else {
+                    caps.name("n")
+                        .map_or(Some(1), |n| whole_number(n.as_str()))?
+                };
+                let frequency = Frequency::from_unit(&caps["unit"])?;
+                (
+                    frequency,
+                    interval,
+                    Vec::new(),
+                    caps.get(0).unwrap().range(),
+                )
+            }
+            None if EVERY_WEEKDAY.is_match(text) => {
+                let caps = EVERY_WEEKDAY.captures(text).unwrap();
+                let interval = if caps.name("other").is_some() {
+                    2
+                } else {
+                    caps.name("n")
+                        .map_or(Some(1), |n| whole_number(n.as_str()))?
+                };
+                let mut days = Vec::new();
+                for name in WEEKDAY_NAMES.find_iter(&caps["days"]) {
+                    let day = weekday_named(name.as_str().trim_end_matches(['s', 'S']))?;
+                    if !days.contains(&day) {
+                        days.push(day);
+                    }
+                }
+                days.sort_by_key(|d| d.num_days_from_monday());
+                (
+                    Frequency::Weekly,
+                    interval,
+                    days,
+                    caps.get(0).unwrap().range(),
+                )
+            }
+            None => match ADVERB.captures(text) {
+                Some(caps) => {
+                    let adverb = caps["adverb"].to_lowercase().replace('-', "");
+                    // "biweekly" may also mean twice a week
+                    ambiguous = adverb.starts_with("bi");
+                    let (frequency, interval) = match adverb.as_str() {
+                        "daily" => (Frequency::Daily, 1),
+                        "weekly" => (Frequency::Weekly, 1),
+                        "biweekly" | "fortnightly" => (Frequency::Weekly, 2),
+                        "monthly" => (Frequency::Monthly, 1),
+                        "bimonthly" => (Frequency::Monthly, 2),
+                        "quarterly" => (Frequency::Monthly, 3),
+                        _ => (Frequency::Yearly, 1),
+                    };
+                    (
+                        frequency,
+                        interval,
+                        Vec::new(),
+                        caps.get(0).unwrap().range(),
+                    )
+                }
+                None => {
+                    let set = weekday_set(text)?;
+                    plural = set.plural;
+                    (Frequency::Weekly, 1, set.days, set.span)
+                }
+            },
+        };
+
+        let mut end = None;
+        let mut end_span = None;
+        if let Some(caps) = COUNT.captures(text) {
+            end = whole_number(&caps["n"]).map(RecurrenceEnd::Count);
+            end_span = caps.get(0).map(|m| m.range());
+        } else if let Some(caps) = WINDOW.captures(text) {
+            end = whole_number(&caps["n"])
+                .zip(Frequency::from_unit(&caps["unit"]))
+                .map(|(n, unit)| RecurrenceEnd::For(n, unit));
+            end_span = caps.get(0).map(|m| m.range());
+        } else if let Some(caps) = UNTIL.captures(text) {
+            if let Some(date) = DateParser::parse_relative(&caps["date"], now) {
+                end = Some(RecurrenceEnd::Until(date));
+                end_span = Some(caps.get(0).unwrap().start()..caps.name("date").unwrap().end());
+            }
+        }
+        // a window inside the repeating part, like "every 2 weeks" in "every 2 weeks 3 times", is not one
+        let end_span = end_span.filter(|s| s.start >= span.end || s.end <= span.start);
+        if end_span.is_none() {
+            end = None;
+        }
+
+        // a monthly schedule may say which weekday it falls on, like "monthly on the last Friday"
+        let apart =
+            |s: &Range<usize>, other: &Range<usize>| s.start >= other.end || s.end <= other.start;
+        let weekday_span = MONTH_WEEKDAY
+            .captures_iter(text)
+            .filter(|_| {
+                frequency == Frequency::Monthly && month_day.is_none() && month_weekday.is_none()
+            })
+            .filter_map(|caps| {
+                let nth = weekday_count(&caps["nth"])?;
+                let day = weekday_named(caps["day"].trim_end_matches(['s', 'S']))?;
+                Some(((nth, day), caps.get(0).unwrap().range()))
+            })
+            .find(|(_, s)| apart(s, &span) && end_span.as_ref().is_none_or(|end| apart(s, end)))
+            .map(|(weekday, s)| {
+                month_weekday = Some(weekday);
+                s
+            });
+        // or which day, like "monthly on the 1st"
+        let day_span = MONTH_DAY
+            .captures_iter(text)
+            .filter(|_| {
+                frequency == Frequency::Monthly && month_day.is_none() && month_weekday.is_none()
+            })
+            .map(|caps| {
+                (
+                    caps["day"].parse::<u32>().unwrap(),
+                    caps.get(0).unwrap().range(),
+                )
+            })
+            .find(|(day, s)| {
+                (1..=31).contains(day)
+                    && apart(s, &span)
+                    && end_span.as_ref().is_none_or(|end| apart(s, end))
+            })
+            .map(|(day, s)| {
+                month_day = Some(day);
+                s
+            });
+
+        let mut rest = text.to_string();
+        let mut taken: Vec<&Range<usize>> = end_span
+            .iter()
+            .chain(&day_span)
+            .chain(&weekday_span)
+            .chain(&plural)
+            .chain([&span])
+            .collect();
+        taken.sort_by_key(|s| std::cmp::Reverse(s.start));
+        for s in taken {
+            rest.replace_range(s.clone(), "");
+        }
+
+        Some(RecurMatch {
+            recurrence: Recurrence {
+                frequency,
+                interval,
+                end,
+                days,
+                month_day,
+                month_weekday,
+            },
+            rest: rest.split_whitespace().collect::<Vec<_>>().join(" "),
+            span,
+            end_span,
+            ambiguous,
+        })
+    }
+}
+
+/// A compact set of weekdays found by [`weekday_set`].
+struct WeekdaySet {
+    /// The weekdays, in order
+    days: Vec<Weekday>,
+    /// Where the set is in the text
+    span: Range<usize>,
+    /// Where the "s" of a part of the day after the set is, like that of "Tu/Th mornings"
+    plural: Option<Range<usize>>,
+}
+
+/// Finds a compact set of weekdays followed by a time, like "MWF 10am", "TTh 2-3:15" or "Mon/Wed 9am", as in class and
+/// meeting schedules. A set on its own, like "MWF", is not read.
+fn weekday_set(text: &str) -> Option<WeekdaySet> {
+    let caps = WEEKDAY_SET.captures(text)?;
+    let days = caps.name("days")?;
+    if text[..days.start()].trim().is_empty() && text[days.end()..].trim().is_empty() {
+        return None;
+    }
+
+    let mut weekdays = Vec::new();
+    for token in WEEKDAY_TOKENS.find_iter(days.as_str()) {
+        let weekday = match token.as_str() {
+            "M" => Weekday::Mon,
+            "T" | "Tu" => Weekday::Tue,
+            "W" => Weekday::Wed,
+            "Th" | "R" => Weekday::Thu,
+            "F" => Weekday::Fri,
+            "S" | "Sa" => Weekday::Sat,
+            "Su" | "U" => Weekday::Sun,
+            name => weekday_named(name)?,
+        };
+        if !weekdays.contains(&weekday) {
+            weekdays.push(weekday);
+        }
+    }
+    weekdays.sort_by_key(|d| d.num_days_from_monday());
+    Some(WeekdaySet {
+        days: weekdays,
+        span: days.range(),
+        plural: caps.name("plural").map(|s| s.range()),
+    })
+}
+
+/// Parses a number of at least one with no fraction, like "two" or "12".
+fn whole_number(text: &str) -> Option<u32> {
+    parse_number(text)
+        .filter(|n| *n >= 1.0 && n.fract() == 0.0)
+        .map(|n| n as u32)
+}
+
+#[cfg(test)]
+mod recur_tests {
+    use super::{Frequency, RecurParser, Recurrence, RecurrenceEnd};
+    use chrono::{NaiveDate, Weekday};
+
+    #[test]
+    fn every_tests() {
+        assert_recurrence("every day", Frequency::Daily, 1, None);
+        assert_recurrence("Every week", Frequency::Weekly, 1, None);
+        assert_recurrence("every other month", Frequency::Monthly, 2, None);
+        assert_recurrence("every 3 days", Frequency::Daily, 3, None);
+        assert_recurrence("every two years", Frequency::Yearly, 2, None);
+    }
+
+    #[test]
+    fn adverb_tests() {
+        assert_recurrence("Daily standup at 9", Frequency::Daily, 1, None);
+        assert_recurrence("weekly review", Frequency::Weekly, 1, None);
+        assert_recurrence("Fortnightly 1:1", Frequency::Weekly, 2, None);
+        assert_recurrence("bi-weekly sync", Frequency::Weekly, 2, None);
+        assert_recurrence("Monthly report", Frequency::Monthly, 1, None);
+        assert_recurrence("quarterly planning", Frequency::Monthly, 3, None);
+        assert_recurrence("Pay taxes annually", Frequency::Yearly, 1, None);
+        assert_recurrence("yearly checkup", Frequency::Yearly, 1, None);
+        assert_recurrence(
+            "Daily standup for two weeks",
+            Frequency::Daily,
+            1,
+            Some(RecurrenceEnd::For(2, Frequency::Weekly)),
+        );
+        assert_rest("Biweekly sync at 10am", "sync at 10am");
+
+        let now = ymd(2021, 6, 1);
+        let ambiguous = |text: &str| RecurParser::parse_relative(text, now).unwrap().ambiguous;
+        assert!(ambiguous("biweekly sync"));
+        assert!(ambiguous("Bi-monthly book club"));
+        assert!(!ambiguous("fortnightly sync"));
+        assert!(!ambiguous("every other week"));
+    }
+
+    #[test]
+    fn weekday_set_tests() {
+        use Weekday::*;
+        for (text, days, set) in [
+            ("CS 101 MWF 10-10:50am", vec![Mon, Wed, Fri], "MWF"),
+            ("Lab Tu/Th 2-3:15", vec![Tue, Thu], "Tu/Th"),
+            ("Seminar TTh 9:30am", vec![Tue, Thu], "TTh"),
+            ("Seminar TR 9:30am", vec![Tue, Thu], "TR"),
+            ("Yoga Mon/Wed 6pm", vec![Mon, Wed], "Mon/Wed"),
+            (
+                "Standup mon, tues & thurs at 9am",
+                vec![Mon, Tue, Thu],
+                "mon, tues & thurs",
+            ),
+            ("Shift SaSu 8am", vec![Sat, Sun], "SaSu"),
+            ("Yoga MWF at noon", vec![Mon, Wed, Fri], "MWF"),
+            ("Run Tu/Th mornings", vec![Tue, Thu], "Tu/Th"),
+            ("Swim Mon/Wed evenings", vec![Mon, Wed], "Mon/Wed"),
+            ("Office hours MWF", vec![Mon, Wed, Fri], "MWF"),
+        ] {
+            let found = RecurParser::parse_relative(text, ymd(2021, 6, 1)).unwrap();
+            assert_eq!(found.recurrence.frequency, Frequency::Weekly, "{}", text);
+            assert_eq!(found.recurrence.days, days, "{}", text);
+            assert_eq!(&text[found.span], set, "{}", text);
+        }
+
+        let found =
+            RecurParser::parse_relative("Lecture MWF 10am until June 30", ymd(2021, 6, 1)).unwrap();
+        assert_eq!(
+            found.recurrence.end,
+            Some(RecurrenceEnd::Until(ymd(2021, 6, 30)))
+        );
+        assert_eq!(found.rest, "Lecture 10am");
+        let found = RecurParser::parse_relative("Run Tu/Th mornings", ymd(2021, 6, 1)).unwrap();
+        assert_eq!(found.rest, "Run morning");
+
+        let now = ymd(2021, 6, 1);
+        for text in [
+            "Meet at the SW corner at 5",
+            "MWF",
+            "Call TJ 555 1234",
+            "mwf 10am",
+            "Meet the SW team tonight",
+        ] {
+            assert_eq!(RecurParser::parse_relative(text, now), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn every_weekday_tests() {
+        use Weekday::*;
+        let now = ymd(2021, 6, 1);
+        for (text, interval, days, every) in [
+            (
+                "Meeting every Tuesday at 4pm",
+                1,
+                vec![Tue],
+                "every Tuesday",
+            ),
+            (
+                "Lunch every other Friday",
+                2,
+                vec![Fri],
+                "every other Friday",
+            ),
+            (
+                "Gym every Mon and Thurs",
+                1,
+                vec![Mon, Thu],
+                "every Mon and Thurs",
+            ),
+            (
+                "Piano every Wednesdays, Saturdays and Mondays at 5",
+                1,
+                vec![Mon, Wed, Sat],
+                "every Wednesdays, Saturdays and Mondays",
+            ),
+            ("Review every 3 Fridays", 3, vec![Fri], "every 3 Fridays"),
+        ] {
+            let found = RecurParser::parse_relative(text, now).unwrap();
+            assert_eq!(found.recurrence.frequency, Frequency::Weekly, "{}", text);
+            assert_eq!(found.recurrence.interval, interval, "{}", text);
+            assert_eq!(found.recurrence.days, days, "{}", text);
+            assert_eq!(&text[found.span], every, "{}", text);
+        }
+        assert_rest("Meeting every Tuesday at 4pm", "Meeting at 4pm");
+    }
+
+    #[test]
+    fn month_day_tests() {
+        let now = ymd(2021, 6, 1);
+        for (text, rest) in [
+            ("Pay rent monthly on the 1st", "Pay rent"),
+            ("Pay rent on the 1st of every month", "Pay rent"),
+            (
+                "Book club every month on the 1st at 7pm",
+                "Book club at 7pm",
+            ),
+        ] {
+            let found = RecurParser::parse_relative(text, now).unwrap();
+            assert_eq!(found.recurrence.frequency, Frequency::Monthly, "{}", text);
+            assert_eq!(found.recurrence.month_day, Some(1), "{}", text);
+            assert_eq!(found.rest, rest, "{}", text);
+        }
+        let found = RecurParser::parse_relative("Weekly sync on the 3rd", now).unwrap();
+        assert_eq!(found.recurrence.month_day, None);
+        let found = RecurParser::parse_relative("Monthly report", now).unwrap();
+        assert_eq!(found.recurrence.month_day, None);
+    }
+
+    #[test]
+    fn month_weekday_tests() {
+        let now = ymd(2021, 6, 1);
+        for (text, weekday, rest) in [
+            (
+                "Book club the first Monday of every month",
+                (1, Weekday::Mon),
+                "Book club",
+            ),
+            (
+                "Book club every first Monday of the month at 7pm",
+                (1, Weekday::Mon),
+                "Book club at 7pm",
+            ),
+            (
+                "Sync monthly on the last Friday",
+                (-1, Weekday::Fri),
+                "Sync",
+            ),
+            (
+                "Meetup every month on the 2nd Tue",
+                (2, Weekday::Tue),
+                "Meetup",
+            ),
+        ] {
+            let found = RecurParser::parse_relative(text, now).unwrap();
+            assert_eq!(found.recurrence.frequency, Frequency::Monthly, "{}", text);
+            assert_eq!(found.recurrence.month_weekday, Some(weekday), "{}", text);
+            assert_eq!(found.recurrence.month_day, None, "{}", text);
+            assert_eq!(found.rest, rest, "{}", text);
+        }
+        assert!(RecurParser::parse_relative("Club the first Monday of June", now).is_none());
+        let found = RecurParser::parse_relative("Weekly sync on the first Monday", now).unwrap();
+        assert_eq!(found.recurrence.month_weekday, None);
+    }
+
+    #[test]
+    fn window_tests() {
+        assert_recurrence(
+            "every day for two weeks",
+            Frequency::Daily,
+            1,
+            Some(RecurrenceEnd::For(2, Frequency::Weekly)),
+        );
+        assert_recurrence(
+            "every week 6 times",
+            Frequency::Weekly,
+            1,
+            Some(RecurrenceEnd::Count(6)),
+        );
+        assert_recurrence(
+            "every day until June 30",
+            Frequency::Daily,
+            1,
+            Some(RecurrenceEnd::Until(ymd(2021, 6, 30))),
+        );
+    }
+
+    #[test]
+    fn resolve_end_tests() {
+        let start = ymd(2021, 6, 1);
+        assert_resolved("every day for two weeks", start, RecurrenceEnd::Count(14));
+        assert_resolved(
+            "every other week for 6 weeks",
+            start,
+            RecurrenceEnd::Count(3),
+        );
+        assert_resolved(
+            "every week for a month",
+            start,
+            RecurrenceEnd::Until(ymd(2021, 6, 30)),
+        );
+        assert_resolved(
+            "every 3 days for 10 days",
+            start,
+            RecurrenceEnd::Until(ymd(2021, 6, 10)),
+        );
+    }
+
+    #[test]
+    fn rest_tests() {
+        assert_rest("Standup every day at 9am for two weeks", "Standup at 9am");
+        assert_rest("Gym every day until June 30 at 7am", "Gym at 7am");
+        assert_rest("Water plants every 3 days 10 times", "Water plants");
+    }
+
+    #[test]
+    fn span_tests() {
+        let text = "Standup every day at 9am for two weeks";
+        let found = RecurParser::parse_relative(text, ymd(2021, 6, 1)).unwrap();
+        assert_eq!(&text[found.span], "every day");
+        assert_eq!(&text[found.end_span.unwrap()], "for two weeks");
+    }
+
+    #[test]
+    fn not_recurrence_tests() {
+        let now = ymd(2021, 6, 1);
+        assert_eq!(RecurParser::parse_relative("Lunch at noon", now), None);
+        assert_eq!(RecurParser::parse_relative("every weekday", now), None);
+        assert_eq!(RecurParser::parse_relative("for two weeks", now), None);
+    }
+
+    fn assert_recurrence(
+        text: &str,
+        frequency: Frequency,
+        interval: u32,
+        end: Option<RecurrenceEnd>,
+    ) {
+        let found = RecurParser::parse_relative(text, ymd(2021, 6, 1)).unwrap();
+        assert_eq!(
+            found.recurrence,
+            Recurrence {
+                frequency,
+                interval,
+                end,
+                days: Vec::new(),
+                month_day: None,
+                month_weekday: None,
+            }
+        )
+    }
+
+    fn assert_resolved(text: &str, start: NaiveDate, expected: RecurrenceEnd) {
+        let found = RecurParser::parse_relative(text, start).unwrap();
+        assert_eq!(found.recurrence.resolve_end(start), Some(expected))
+    }
+
+    fn assert_rest(text: &str, expected: &str) {
+        let found = RecurParser::parse_relative(text, ymd(2021, 6, 1)).unwrap();
+        assert_eq!(found.rest, expected)
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
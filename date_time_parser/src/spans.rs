@@ -0,0 +1,155 @@
+//! Where in a text the date and time the parsers read came from, for applications that highlight what was understood.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use std::ops::Range;
+
+use crate::patterns::{
+    Pattern, CLOCK_TIME, COLLOQUIAL_TIME, CONTINENTAL_TIME, DATE_MENTION, DAY_MONTH, DAY_OF_MONTH,
+    ISO_DATE_TIME, MILITARY_TIME, MONTH_DATE, NTH_WEEKDAY_OF_MONTH, NUMERIC_DATE,
+    NUMERIC_DATE_WITH_YEAR, ORDINAL_DATE, PERIOD, QUARTER, SEASON, WEEKDAY, WORD_TIME,
+};
+use crate::{DateParser, TimeMatch, TimeParser};
+
+/// A run of characters between spaces, which a span found by narrowing the text starts and ends on.
+static WORD: Pattern = Pattern::new(r"\S+");
+
+/// Patterns a date is usually written with, tried before narrowing the text down word by word.
+pub(crate) static DATE_CANDIDATES: [&Pattern; 13] = [
+    &ISO_DATE_TIME,
+    &NUMERIC_DATE_WITH_YEAR,
+    &NUMERIC_DATE,
+    &DATE_MENTION,
+    &MONTH_DATE,
+    &DAY_MONTH,
+    &ORDINAL_DATE,
+    &DAY_OF_MONTH,
+    &NTH_WEEKDAY_OF_MONTH,
+    &PERIOD,
+    &SEASON,
+    &QUARTER,
+    &WEEKDAY,
+];
+
+/// Patterns a time is usually written with, tried before narrowing the text down word by word.
+pub(crate) static TIME_CANDIDATES: [&Pattern; 5] = [
+    &CLOCK_TIME,
+    &CONTINENTAL_TIME,
+    &MILITARY_TIME,
+    &COLLOQUIAL_TIME,
+    &WORD_TIME,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// The date and time found in a text by [`parse_with_spans`](fn.parse_with_spans.html), each with where it is.
+pub struct Spans {
+    /// The date found, and where it is in the text, in bytes.
+    pub date: Option<(NaiveDate, Range<usize>)>,
+    /// The time found, and where it is in the text, in bytes.
+    pub time: Option<(TimeMatch, Range<usize>)>,
+}
+
+/// Finds the date and time in a string slice of natural language text with respect to a given date and time, like
+/// [`DateParser::find`](struct.DateParser.html#method.find) and [`TimeParser::find`](struct.TimeParser.html#method.find),
+/// along with the byte ranges of the text each was read from.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed
+/// * `now` - A [`NaiveDateTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveDateTime.html) to interpret the natural language date and time around
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use date_time_parser::parse_with_spans;
+///
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap();
+/// let text = "Lunch on June 5th at 12:30pm";
+/// let spans = parse_with_spans(text, now);
+///
+/// let (date, span) = spans.date.unwrap();
+/// assert_eq!(date, NaiveDate::from_ymd_opt(2021, 6, 5).unwrap());
+/// assert_eq!(&text[span], "June 5th");
+/// assert_eq!(&text[spans.time.unwrap().1], "12:30pm");
+/// ```
+pub fn parse_with_spans(text: &str, now: NaiveDateTime) -> Spans {
+    Spans {
+        date: DateParser::new().find_with_span(text, now.date()),
+        time: TimeParser::new().find_with_span(text, now.time()),
+    }
+}
+
+/// Returns what `read` reads from `text` and the narrowest part of it that reads the same. The longest match of a
+/// `candidates` pattern that does is taken first, and otherwise words are dropped from either end as long as the rest
+/// still does, so "Party on Thanksgiving" narrows to "Thanksgiving".
+pub(crate) fn narrowest<T: PartialEq>(
+    text: &str,
+    candidates: &[&Pattern],
+    read: impl Fn(&str) -> Option<T>,
+) -> Option<(T, Range<usize>)> {
+    let value = read(text)?;
+    let reads_same = |span: &Range<usize>| read(&text[span.clone()]).as_ref() == Some(&value);
+
+    let candidate = candidates
+        .iter()
+        .flat_map(|pattern| pattern.find_iter(text))
+        .map(|m| {
+            let found = m.as_str().trim_end();
+            m.start()..m.start() + found.len()
+        })
+        .filter(|span| !span.is_empty() && reads_same(span))
+        .max_by_key(|span| (span.len(), std::cmp::Reverse(span.start)));
+    if let Some(span) = candidate {
+        return Some((value, span));
+    }
+
+    let words: Vec<Range<usize>> = WORD.find_iter(text).map(|m| m.range()).collect();
+    let (mut first, mut last) = (0, words.len().checked_sub(1)?);
+    while first < last && reads_same(&(words[first + 1].start..words[last].end)) {
+        first += 1;
+    }
+    while last > first && reads_same(&(words[first].start..words[last - 1].end)) {
+        last -= 1;
+    }
+    Some((value, words[first].start..words[last].end))
+}
+
+#[cfg(test)]
+mod spans_tests {
+    use super::parse_with_spans;
+    use chrono::{NaiveDate, NaiveTime};
+
+    #[test]
+    fn parse_with_spans_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let spans = |text: &'static str| {
+            let found = parse_with_spans(text, now);
+            (
+                found.date.map(|(_, span)| &text[span]),
+                found.time.map(|(_, span)| &text[span]),
+            )
+        };
+
+        assert_eq!(
+            spans("Lunch on June 5th at 12:30pm"),
+            (Some("June 5th"), Some("12:30pm"))
+        );
+        assert_eq!(spans("Dentist 6/10 at 3pm"), (Some("6/10"), Some("3pm")));
+        assert_eq!(
+            spans("Standup tomorrow at nine thirty"),
+            (Some("tomorrow"), Some("at nine thirty"))
+        );
+        assert_eq!(spans("Party on Thanksgiving"), (Some("Thanksgiving"), None));
+        assert_eq!(spans("Call 3pm EST"), (None, Some("3pm EST")));
+        assert_eq!(spans("Review next Friday"), (Some("next Friday"), None));
+        assert_eq!(spans("Dinner with friends"), (None, None));
+
+        let found = parse_with_spans("Flight at 7pm", now);
+        assert_eq!(
+            found.time.unwrap().0.time,
+            NaiveTime::from_hms_opt(19, 0, 0).unwrap()
+        );
+    }
+}
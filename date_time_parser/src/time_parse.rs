@@ -1,17 +1,728 @@
 //! Parse natural language text into the [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html) format.
 
-use chrono::{Duration, NaiveTime, Utc};
-use regex::*;
+use chrono::{Duration, NaiveTime, Timelike};
+use std::borrow::Cow;
+use std::ops::Range;
 
+use crate::clock::{Clock, UtcClock};
+use crate::duration_parse::shorthand_duration;
+use crate::locale::Locale;
+use crate::number_words::{number_pattern, parse_number};
+use crate::patterns::{
+    blank_non_temporal, compiled, in_name, is_word_time, BuiltPattern, Pattern, CLOCK_TIME,
+    COLLOQUIAL_TIME, CONTINENTAL_TIME, DATE_MENTION, DURATION, ISO_DATE_TIME, MILITARY_TIME,
+    ORDINAL_DATE, TIME_ZONE, WORD_TIME,
+};
+use crate::prefilter::may_be_temporal;
 use crate::recognizable::Recognizable;
+use crate::spans::{narrowest, TIME_CANDIDATES};
+use crate::time_zone::TimeZoneMention;
 
 extern crate regex;
 
-#[derive(Default)]
+/// Vague phrases with how far from now they are taken to be, in minutes, and the latest hour they may reach.
+const FUZZY_PHRASES: [(&str, i64, Option<u32>); 5] = [
+    (r"later\s(today|on)", 180, Some(21)),
+    (r"in\sa\swhile", 120, None),
+    (r"in\sa\s(little\s)?bit|in\sa\slittle\swhile", 60, None),
+    (r"soon", 30, None),
+    (r"shortly", 15, None),
+];
+
+/// Phrases standing for a time of day, with the hour and minute they are taken to be, checked in order. A parser's own
+/// phrases are checked before them.
+const CASUAL_TIMES: [(&str, u32, u32); 12] = [
+    (r"first\sthing", 9, 0),
+    (r"morning", 9, 0),
+    (r"lunch\s?time", 12, 0),
+    (r"afternoon", 14, 0),
+    (r"dinner\s?time", 18, 0),
+    (r"evening", 18, 0),
+    (r"tonight", 21, 0),
+    (r"noon", 12, 0),
+    (r"midnight", 0, 0),
+    (r"night", 21, 0),
+    (r"eod|end\sof\s(the\s)?day", 17, 0),
+    // "cob" in lower case is only read after "by", "at" or "before", so "corn on the cob" is not a time
+    (r"(?-i:COB)|(by|at|before)\scob|close\sof\sbusiness", 17, 0),
+];
+
+/// A number of minutes from now, e.g. "in 5 mins" or "in twenty minutes", with the capture `mins`.
+static IN_MINUTES: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bin\s(?P<mins>{})\s(mins|minutes|min|minute)\b",
+        number_pattern()
+    )
+});
+
+/// A number of hours from now, e.g. "in 2 hours", "in two and a half hours" or "in an hour and a half", with the
+/// captures `hours` and `half`.
+static IN_HOURS: BuiltPattern = BuiltPattern::new(|| {
+    format!(
+        r"(?i)\bin\s(?P<hours>{})\s(hrs|hours|hr|hour)\b(?P<half>\sand\sa\shalf\b)?",
+        number_pattern()
+    )
+});
+
+/// A shorthand duration from now, e.g. "in 1h30m", with the captures of [`DURATION`].
+static IN_DURATION: BuiltPattern =
+    BuiltPattern::new(|| format!(r"(?i)\bin\s{}", DURATION.as_str()));
+
+/// A count of days or longer, e.g. "3 days" of "3 days ago" or "in 2 weeks", whose number is not an hour.
+static COUNTED_DAYS: Pattern = Pattern::new(r"(?i)\b\d{1,3}\s(day|week|month|year)s?\b");
+
+/// A part of the day that says which half of it an hour without am or pm is in, e.g. "night" of "tomorrow night at
+/// 10", with the captures `am` and `pm`.
+static DAY_HALF: Pattern =
+    Pattern::new(r"(?i)\b((?P<am>morning|first\sthing)|(?P<pm>afternoon|evening|tonight|night))\b");
+
+/// A word that says the number after it is a time, e.g. "at" of "at 1830", at the end of the text before it.
+static STATED_BEFORE: Pattern = Pattern::new(r"(?i)(\b(at|from|until|till|til|by)\s|@\s?)$");
+
+/// A time named outright rather than a part of the day, e.g. "noon" or "12 midnight", with the capture `midnight`.
+static EXACT_TIME: Pattern = Pattern::new(r"(?i)\b(12\s?)?(noon|(?P<midnight>midnight))\b");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A time found by [`TimeParser::find`](#method.find).
+pub struct TimeMatch {
+    /// The time found in the text.
+    pub time: NaiveTime,
+    /// `true` if the time was estimated from a vague phrase like "soon" rather than stated.
+    pub approximate: bool,
+    /// `true` if the time was given without am or pm, like "at 7", and one was guessed from the hour.
+    pub guessed_meridiem: bool,
+    /// The timezone named with the time, like the "EST" of "3pm EST", if any. The time is a wall-clock time in it.
+    pub zone: Option<TimeZoneMention>,
+}
+
+#[derive(Debug, Clone)]
+/// A vague phrase resolved as an offset from now, never past `latest`.
+struct FuzzyPhrase {
+    pattern: String,
+    offset: Duration,
+    latest: Option<NaiveTime>,
+}
+
+impl FuzzyPhrase {
+    fn is_match(&self, text: &str) -> bool {
+        phrase_matches(&self.pattern, text)
+    }
+
+    /// Returns the time the phrase stands for with respect to `now`.
+    fn resolve(&self, now: NaiveTime) -> NaiveTime {
+        let (time, overflow) = now.overflowing_add_signed(self.offset);
+        match self.latest {
+            Some(latest) if overflow != 0 || time > latest => latest.max(now),
+            _ => time,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A phrase standing for a time of day, like "lunch" for 12:30pm.
+struct CasualPhrase {
+    pattern: String,
+    time: NaiveTime,
+}
+
+/// Returns whether the case-insensitive regular expression `pattern` matches whole words of `text`.
+fn phrase_matches(pattern: &str, text: &str) -> bool {
+    compiled(&format!(r"(?i)\b({})\b", pattern))
+        .map(|re| re.is_match(text))
+        .unwrap_or(false)
+}
+
+/// Returns whether the case-insensitive regular expression `pattern` matches whole words of `text` that are not part
+/// of a name, like the "Evening" of "Coffee at Evening Star".
+fn casual_matches(pattern: &str, text: &str) -> bool {
+    compiled(&format!(r"(?i)\b({})\b", pattern))
+        .map(|re| re.find_iter(text).any(|m| !in_name(text, &m)))
+        .unwrap_or(false)
+}
+
+/// Finds a time named outright in `text`, like the "noon" of "noon to 2pm", that is not part of a name, and returns
+/// it with where it starts in the text, in bytes.
+fn exact_time(text: &str) -> Option<(NaiveTime, usize)> {
+    let caps = EXACT_TIME
+        .captures_iter(text)
+        .find(|caps| !in_name(text, &caps.get(0).unwrap()))?;
+    let hour = if caps.name("midnight").is_some() {
+        0
+    } else {
+        12
+    };
+    Some((NaiveTime::from_hms_opt(hour, 0, 0)?, caps.get(0)?.start()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// How an hour given without am or pm, like "at 7" or "10:30", is read. Hours past 12 are always read as written.
+pub enum MeridiemPolicy {
+    /// 1 through 8 are pm and 9 through 12 are am or noon, e.g. "at 7" is 7pm and "at 10" is 10am.
+    #[default]
+    Guess,
+    /// Always am, e.g. "at 7" is 7am and "at 12" is midnight.
+    Am,
+    /// Always pm, e.g. "at 10" is 10pm and "at 12" is noon.
+    Pm,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// How times worked out from now, like "in 2 hours" or "soon", are rounded. Times given outright, like "2:47pm", are
+/// never rounded.
+pub enum Rounding {
+    /// Keep the minute worked out, e.g. 2:47pm.
+    #[default]
+    Exact,
+    /// Round to the nearest 5 minutes, e.g. 2:45pm.
+    Nearest5,
+    /// Round to the nearest quarter hour, e.g. 2:45pm.
+    Nearest15,
+    /// Round to the nearest half hour, e.g. 3pm.
+    Nearest30,
+}
+
+impl Rounding {
+    /// Returns `time` rounded to the nearest step, halves rounding up, wrapping past midnight. Seconds are dropped
+    /// unless the rounding is exact.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::Rounding;
+    ///
+    /// let time = NaiveTime::from_hms_opt(14, 47, 0).unwrap();
+    /// assert_eq!(Rounding::Nearest30.round(time), NaiveTime::from_hms_opt(15, 0, 0).unwrap());
+    /// ```
+    pub fn round(self, time: NaiveTime) -> NaiveTime {
+        let step = match self {
+            Rounding::Exact => return time,
+            Rounding::Nearest5 => 5,
+            Rounding::Nearest15 => 15,
+            Rounding::Nearest30 => 30,
+        };
+        let minutes = (time.num_seconds_from_midnight() as i64 + step * 30) / (step * 60) * step;
+        NaiveTime::MIN
+            .overflowing_add_signed(Duration::minutes(minutes))
+            .0
+    }
+}
+
+#[derive(Debug, Clone)]
 /// Container for parsing times from string slices.  
-pub struct TimeParser {}
+///
+/// The associated `parse` functions use the default configuration. Build a `TimeParser` with
+/// [`TimeParser::new`](#method.new) to change how vague phrases like "soon" or "later today" are read.
+pub struct TimeParser {
+    fuzzy: Vec<FuzzyPhrase>,
+    casual: Vec<CasualPhrase>,
+    meridiem: MeridiemPolicy,
+    rounding: Rounding,
+    british: bool,
+    language: Locale,
+    /// Whether numbers that are not times, like phone numbers, prices and versions, are left out
+    context_filters: bool,
+    /// Whether text can be skipped when [`may_be_temporal`](../fn.may_be_temporal.html) rules it out, which stops
+    /// being safe once phrases of its own or British times are added
+    prefilter: bool,
+}
+
+impl Default for TimeParser {
+    fn default() -> TimeParser {
+        TimeParser::new()
+    }
+}
 
 impl TimeParser {
+    /// Creates a parser that reads "soon" as in 30 minutes, "in a bit" as in an hour and "later today" as in 3 hours
+    /// but no later than 9pm, among other vague phrases, and "morning" as 9am, "lunchtime" as noon and "EOD" as 5pm,
+    /// among other parts of the day.
+    pub fn new() -> TimeParser {
+        let fuzzy = FUZZY_PHRASES
+            .iter()
+            .map(|(pattern, mins, latest)| FuzzyPhrase {
+                pattern: pattern.to_string(),
+                offset: Duration::minutes(*mins),
+                latest: latest.and_then(|h| NaiveTime::from_hms_opt(h, 0, 0)),
+            })
+            .collect();
+        let casual = CASUAL_TIMES
+            .iter()
+            .map(|(pattern, hour, minute)| CasualPhrase {
+                pattern: pattern.to_string(),
+                time: NaiveTime::from_hms_opt(*hour, *minute, 0).unwrap(),
+            })
+            .collect();
+        TimeParser {
+            fuzzy,
+            casual,
+            meridiem: MeridiemPolicy::Guess,
+            rounding: Rounding::Exact,
+            british: false,
+            language: Locale::English,
+            context_filters: true,
+            prefilter: true,
+        }
+    }
+
+    /// Adds a vague phrase, taking precedence over the ones already known.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A case-insensitive regular expression matching the phrase, e.g. `r"after\slunch"`
+    /// * `offset` - How far from now the phrase is taken to be
+    /// * `latest` - The latest time the phrase may stand for, if any
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{Duration, NaiveTime};
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let parser = TimeParser::new().with_fuzzy_phrase(r"soon", Duration::minutes(10), None);
+    /// let now = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    /// let found = parser.find("back soon", now).unwrap();
+    ///
+    /// assert_eq!(found.time, NaiveTime::from_hms_opt(12, 10, 0).unwrap());
+    /// assert!(found.approximate);
+    /// ```
+    pub fn with_fuzzy_phrase(
+        mut self,
+        pattern: &str,
+        offset: Duration,
+        latest: Option<NaiveTime>,
+    ) -> TimeParser {
+        self.fuzzy.insert(
+            0,
+            FuzzyPhrase {
+                pattern: pattern.to_string(),
+                offset,
+                latest,
+            },
+        );
+        self.prefilter = false;
+        self
+    }
+
+    /// Adds a phrase standing for a time of day, taking precedence over the ones already known, like "morning" for 9am,
+    /// so a built-in phrase can be given a time of its own. Like those, it is only used when the text gives no clock
+    /// time.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A case-insensitive regular expression matching the phrase, e.g. `r"lunch(time)?"`
+    /// * `time` - The time of day the phrase stands for
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let parser = TimeParser::new()
+    ///     .with_casual_time(r"morning", NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+    ///     .with_casual_time(r"lunch(time)?", NaiveTime::from_hms_opt(12, 30, 0).unwrap());
+    /// let now = NaiveTime::from_hms_opt(7, 0, 0).unwrap();
+    ///
+    /// assert_eq!(parser.find("run in the morning", now).unwrap().time, NaiveTime::from_hms_opt(8, 0, 0).unwrap());
+    /// assert_eq!(parser.find("call at lunchtime", now).unwrap().time, NaiveTime::from_hms_opt(12, 30, 0).unwrap());
+    /// assert_eq!(parser.find("lunch at 1pm", now).unwrap().time, NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+    /// ```
+    pub fn with_casual_time(mut self, pattern: &str, time: NaiveTime) -> TimeParser {
+        self.casual.insert(
+            0,
+            CasualPhrase {
+                pattern: pattern.to_string(),
+                time,
+            },
+        );
+        self.prefilter = false;
+        self
+    }
+
+    /// Sets how an hour given without am or pm, like "at 7", is read. Defaults to
+    /// [`MeridiemPolicy::Guess`](enum.MeridiemPolicy.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `meridiem` - The [`MeridiemPolicy`](enum.MeridiemPolicy.html) to apply
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::{MeridiemPolicy, TimeParser};
+    ///
+    /// let parser = TimeParser::new().with_meridiem_policy(MeridiemPolicy::Am);
+    /// let now = NaiveTime::from_hms_opt(6, 0, 0).unwrap();
+    ///
+    /// assert_eq!(parser.find("run at 7", now).unwrap().time, NaiveTime::from_hms_opt(7, 0, 0).unwrap());
+    /// assert_eq!(parser.find("run at 7pm", now).unwrap().time, NaiveTime::from_hms_opt(19, 0, 0).unwrap());
+    /// ```
+    pub fn with_meridiem_policy(mut self, meridiem: MeridiemPolicy) -> TimeParser {
+        self.meridiem = meridiem;
+        self
+    }
+
+    /// Sets how times worked out from now, like "in 2 hours" or "soon", are rounded. Defaults to
+    /// [`Rounding::Exact`](enum.Rounding.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `rounding` - The [`Rounding`](enum.Rounding.html) to apply
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::{Rounding, TimeParser};
+    ///
+    /// let parser = TimeParser::new().with_rounding(Rounding::Nearest15);
+    /// let now = NaiveTime::from_hms_opt(12, 47, 0).unwrap();
+    ///
+    /// assert_eq!(parser.find("in 2 hours", now).unwrap().time, NaiveTime::from_hms_opt(14, 45, 0).unwrap());
+    /// assert_eq!(parser.find("at 2:47pm", now).unwrap().time, NaiveTime::from_hms_opt(14, 47, 0).unwrap());
+    /// ```
+    pub fn with_rounding(mut self, rounding: Rounding) -> TimeParser {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets whether British colloquial times are read: "half 7" as 7:30, and "20 to 8" as 7:40. Off by default, as
+    /// "10 to 12" otherwise reads as a range of hours. Times like "half past 7", "10 past 7" or "quarter to 9" are read
+    /// either way. The hour named is read like one given without minutes, so "quarter to 9" is 8:45am.
+    ///
+    /// # Arguments
+    ///
+    /// * `british` - Whether to read the times
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let parser = TimeParser::new().with_british_times(true);
+    /// let now = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(parser.find("drinks at half 7", now).unwrap().time, NaiveTime::from_hms_opt(19, 30, 0).unwrap());
+    /// assert_eq!(parser.find("train at 20 to 8am", now).unwrap().time, NaiveTime::from_hms_opt(7, 40, 0).unwrap());
+    /// ```
+    pub fn with_british_times(mut self, british: bool) -> TimeParser {
+        self.british = british;
+        // "half seven" has no digit
+        self.prefilter &= !british;
+        self
+    }
+
+    /// Reads times written in `language`, like "a las 5 de la tarde" in Spanish, as well as in English. Defaults to
+    /// [`Locale::English`](enum.Locale.html#variant.English).
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The [`Locale`](enum.Locale.html) whose words for times of day and the like are read
+    pub fn with_language(mut self, language: Locale) -> TimeParser {
+        self.language = language;
+        self
+    }
+
+    /// Reads times written in the language of `locale`, like "de-DE", if its feature is enabled, as with
+    /// [`with_language`](#method.with_language). Times in other languages are read in English.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - A locale like "en_US" or "es_MX"
+    pub fn with_locale(self, locale: &str) -> TimeParser {
+        self.with_language(Locale::from_tag(locale).unwrap_or_default())
+    }
+
+    /// Sets whether numbers that are not times are left out, as they are by default: phone numbers like "555-1230",
+    /// prices like "$5" and versions like "v2.30", matched by [`NOT_TEMPORAL`](patterns/static.NOT_TEMPORAL.html).
+    ///
+    /// # Arguments
+    ///
+    /// * `context_filters` - Whether to leave the numbers out
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let now = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    ///
+    /// assert_eq!(TimeParser::new().find("$5 lunch", now), None);
+    /// assert_eq!(
+    ///     TimeParser::new().with_context_filters(false).find("$5 lunch", now).unwrap().time,
+    ///     NaiveTime::from_hms_opt(17, 0, 0).unwrap()
+    /// );
+    /// ```
+    pub fn with_context_filters(mut self, context_filters: bool) -> TimeParser {
+        self.context_filters = context_filters;
+        self
+    }
+
+    /// Returns whether numbers that are not times are left out, as set with
+    /// [`with_context_filters`](#method.with_context_filters).
+    pub fn context_filters(&self) -> bool {
+        self.context_filters
+    }
+
+    /// Returns whether British colloquial times like "half 7" are read.
+    pub fn british_times(&self) -> bool {
+        self.british
+    }
+
+    /// Finds a time in a string slice of natural language text with respect to a given time.
+    /// Returns a [`TimeMatch`](struct.TimeMatch.html) if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html) to interpret the natural language time around
+    pub fn find(&self, text: &str, now: NaiveTime) -> Option<TimeMatch> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.prefilter && !may_be_temporal(text) {
+            return None;
+        }
+        let blanked = if self.context_filters {
+            blank_non_temporal(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        let text = blanked.as_ref();
+        if let Some(phrase) = self.fuzzy.iter().find(|p| p.is_match(text)) {
+            return Some(TimeMatch {
+                time: self.rounding.round(phrase.resolve(now)),
+                approximate: true,
+                guessed_meridiem: false,
+                zone: None,
+            });
+        }
+
+        let (expr, guessed_meridiem, zone) = self.read(text)?;
+        let time = match expr {
+            TimeExpr::Absolute(time) => time,
+            relative => self.rounding.round(relative.resolve(now)),
+        };
+        Some(TimeMatch {
+            time,
+            approximate: false,
+            guessed_meridiem,
+            zone,
+        })
+    }
+
+    /// Finds a time in a string slice of natural language text like [`find`](#method.find), but returns what it is
+    /// written as rather than when it is, e.g. "in 2 hours" is `TimeExpr::InNHours(2)`. Vague phrases like "soon"
+    /// have no [`TimeExpr`](enum.TimeExpr.html), so `None` is returned for those as for text without a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::{TimeExpr, TimeParser};
+    ///
+    /// let parser = TimeParser::new();
+    /// assert_eq!(parser.parse_expr("Call in 2 hours"), Some(TimeExpr::InNHours(2)));
+    /// assert_eq!(parser.parse_expr("Dinner at 7:30pm"), Some(TimeExpr::Absolute(NaiveTime::from_hms_opt(19, 30, 0).unwrap())));
+    /// assert_eq!(parser.parse_expr("back soon"), None);
+    /// ```
+    pub fn parse_expr(&self, text: &str) -> Option<TimeExpr> {
+        let translated = self.language.translate(text);
+        let text = translated.as_ref();
+        if self.prefilter && !may_be_temporal(text) {
+            return None;
+        }
+        let blanked = if self.context_filters {
+            blank_non_temporal(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        let text = blanked.as_ref();
+        if self.fuzzy.iter().any(|p| p.is_match(text)) {
+            return None;
+        }
+        self.read(text).map(|(expr, _, _)| expr)
+    }
+
+    /// Reads the time stated in `text` with this parser's settings, along with whether its meridiem was guessed and
+    /// the timezone named with it.
+    fn read(&self, text: &str) -> Option<(TimeExpr, bool, Option<TimeZoneMention>)> {
+        // a timezone after the time, like "3pm UTC+2", is not read as part of it
+        let zone = TimeParser::find_zone(text);
+        let text = match &zone {
+            Some((_, span)) => {
+                let mut rest = text.to_owned();
+                rest.replace_range(span.clone(), &" ".repeat(span.len()));
+                Cow::Owned(rest)
+            }
+            None => Cow::Borrowed(text),
+        };
+        let text = text.as_ref();
+        let zone = zone.map(|(zone, _)| zone);
+
+        if let Some(time) = iso_time(text) {
+            return Some((TimeExpr::Absolute(time), false, zone));
+        }
+
+        let colloquial = colloquial_time(text, self.british);
+        let clock_time = match colloquial {
+            Some((hour, meridiem, _)) => Some((hour, 0, meridiem)),
+            None => absolute_time(text),
+        };
+        // a time named outright is read before a clock time written after it, as in "noon to 2pm" or "12 midnight",
+        // while parts of the day like "tonight" only stand for a time when none is written
+        if let Some((time, start)) = exact_time(text) {
+            let before = &text[..start];
+            if absolute_time(before).is_none() && colloquial_time(before, self.british).is_none() {
+                return Some((TimeExpr::Absolute(time), false, zone));
+            }
+        }
+        if clock_time.is_none() && parse_relative_time(text).is_none() {
+            if let Some(phrase) = self
+                .casual
+                .iter()
+                .find(|p| casual_matches(&p.pattern, text))
+            {
+                return Some((TimeExpr::Absolute(phrase.time), false, zone));
+            }
+        }
+
+        let expr = match colloquial {
+            Some((hour, meridiem, _)) => TimeExpr::Absolute(time_of_day(hour, 0, meridiem)?),
+            None => TimeExpr::recognize(text)?,
+        };
+        let guessed_hour = clock_time
+            .filter(|(hour, _, meridiem)| meridiem.is_none() && (1..=12).contains(hour))
+            .filter(|_| matches!(expr, TimeExpr::Absolute(_)));
+        // a part of the day said with the hour, like "tonight at 10", settles its meridiem, except for 12
+        let day_half = DAY_HALF
+            .captures_iter(text)
+            .find(|caps| !in_name(text, &caps.get(0).unwrap()))
+            .filter(|_| guessed_hour.is_some_and(|(hour, _, _)| hour != 12))
+            .map(|caps| match caps.name("pm") {
+                Some(_) => MeridiemPolicy::Pm,
+                None => MeridiemPolicy::Am,
+            });
+        let expr = match (expr, guessed_hour, day_half.unwrap_or(self.meridiem)) {
+            (TimeExpr::Absolute(_), Some((hour, minute, _)), MeridiemPolicy::Am) => {
+                TimeExpr::Absolute(NaiveTime::from_hms_opt(hour % 12, minute, 0)?)
+            }
+            (TimeExpr::Absolute(_), Some((hour, minute, _)), MeridiemPolicy::Pm) => {
+                TimeExpr::Absolute(NaiveTime::from_hms_opt(hour % 12 + 12, minute, 0)?)
+            }
+            (expr, _, _) => expr,
+        };
+        // "20 to 8" is 20 minutes before the hour named
+        let expr = match (expr, colloquial) {
+            (TimeExpr::Absolute(time), Some((_, _, minutes))) => {
+                TimeExpr::Absolute(time.overflowing_add_signed(Duration::minutes(minutes)).0)
+            }
+            (expr, _) => expr,
+        };
+        Some((expr, guessed_hour.is_some() && day_half.is_none(), zone))
+    }
+
+    /// Returns every reading of a time in a string slice of natural language text with respect to a given time, each
+    /// with a score from 0 to 1 of how likely it is meant, most likely first. The reading [`find`](#method.find) gives
+    /// comes first; an hour without am or pm, like "at 7", also reads in the other half of the day, and a time
+    /// estimated from a vague phrase like "soon" scores lower. Returns an empty `Vec` if no time is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html) to interpret the natural language time around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let now = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+    /// let candidates = TimeParser::new().candidates("Dinner at 7", now);
+    ///
+    /// assert_eq!(candidates[0], (NaiveTime::from_hms_opt(19, 0, 0).unwrap(), 0.6));
+    /// assert_eq!(candidates[1], (NaiveTime::from_hms_opt(7, 0, 0).unwrap(), 0.4));
+    /// ```
+    pub fn candidates(&self, text: &str, now: NaiveTime) -> Vec<(NaiveTime, f32)> {
+        match self.find(text, now) {
+            Some(found) if found.approximate => vec![(found.time, 0.5)],
+            Some(found) if found.guessed_meridiem => {
+                let other_half = found.time.overflowing_add_signed(Duration::hours(12)).0;
+                vec![(found.time, 0.6), (other_half, 0.4)]
+            }
+            Some(found) => vec![(found.time, 1.0)],
+            None => Vec::new(),
+        }
+    }
+
+    /// Finds a time in a string slice of natural language text with respect to a given time like
+    /// [`find`](#method.find), along with where in the text it was read from, in bytes, like "7:30pm" of "Dinner at
+    /// 7:30pm" or "3pm EST" of "Call at 3pm EST". Returns `None` if no time is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `now` - A [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html) to interpret the natural language time around
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let now = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+    /// let (found, span) = TimeParser::new().find_with_span("Dinner at 7:30pm", now).unwrap();
+    ///
+    /// assert_eq!(found.time, NaiveTime::from_hms_opt(19, 30, 0).unwrap());
+    /// assert_eq!(span, 10..16);
+    /// ```
+    pub fn find_with_span(&self, text: &str, now: NaiveTime) -> Option<(TimeMatch, Range<usize>)> {
+        narrowest(text, &TIME_CANDIDATES, |part| self.find(part, now))
+    }
+
+    /// Finds a timezone named right after a time, like the "EST" of "3pm EST", "CET" of "14:00 CET", "UTC+2" of
+    /// "9am UTC+2" or "America/New_York" of "3pm America/New_York", or the offset of an ISO 8601 date and time, like
+    /// the "Z" of "2021-06-05T14:30:00Z". Returns the timezone and where it is in the text,
+    /// with any brackets around it, if a match is found, `None` otherwise.
+    ///
+    /// Abbreviations like "EST" stand for a fixed offset all year round, while "ET", "CT", "MT" and "PT" stand for the
+    /// US timezones, switching between standard and daylight time. Abbreviations are only read in capitals.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use date_time_parser::{TimeParser, TimeZoneMention};
+    ///
+    /// let est = TimeZoneMention::Offset(FixedOffset::west_opt(5 * 3600).unwrap());
+    /// assert_eq!(TimeParser::find_zone("Call at 3pm EST"), Some((est, 12..15)));
+    /// assert_eq!(TimeParser::find_zone("Call at 3pm"), None);
+    /// ```
+    pub fn find_zone(text: &str) -> Option<(TimeZoneMention, Range<usize>)> {
+        let iso = ISO_DATE_TIME.captures_iter(text).find_map(|caps| {
+            let offset = caps
+                .name("zone")
+                .or_else(|| caps.name("zone_after_minute"))?;
+            let zone = match offset.as_str() {
+                "Z" | "z" => TimeZoneMention::named("UTC")?,
+                offset => TimeZoneMention::named(&format!("UTC{}", offset))?,
+            };
+            Some((zone, offset.range()))
+        });
+        if iso.is_some() {
+            return iso;
+        }
+        TIME_ZONE.captures_iter(text).find_map(|caps| {
+            let zone = TimeZoneMention::named(&caps["name"])?;
+            Some((zone, caps.name("zone")?.range()))
+        })
+    }
+
     /// Parses a string slice of natural language text with respect to the current time. Returns a [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html) if a match is found, `None` otherwise.
     ///
     /// # Arguments
@@ -27,7 +738,7 @@ impl TimeParser {
     /// assert_eq!(time, Some(NaiveTime::from_hms(18, 30, 0)));
     /// ```
     pub fn parse(text: &str) -> Option<NaiveTime> {
-        TimeParser::parse_relative(text, Utc::now().time())
+        TimeParser::parse_relative(text, UtcClock.now().time())
     }
 
     /// Parses a string slice of natural language text with respect to a given time. Returns a [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html) if a match is found, `None` otherwise.
@@ -46,33 +757,67 @@ impl TimeParser {
     /// assert_eq!(time, Some(NaiveTime::from_hms(18, 30, 0)));
     /// ```
     pub fn parse_relative(text: &str, now: NaiveTime) -> Option<NaiveTime> {
-        if let Some(time_expr) = TimeExpr::recognize(text) {
-            match time_expr {
-                TimeExpr::Absolute(nt) => {
-                    return Some(nt);
-                }
-                TimeExpr::InNHours(h) => {
-                    let d = Duration::hours(h as i64);
-                    return Some(now.overflowing_add_signed(d).0);
-                }
-                TimeExpr::InNMins(m) => {
-                    let d = Duration::minutes(m as i64);
-                    return Some(now.overflowing_add_signed(d).0);
-                }
-            }
-        }
-        None
+        TimeParser::new().find(text, now).map(|found| found.time)
+    }
+
+    /// Parses a string slice of natural language text written in the language of `locale` with respect to the current
+    /// time, as [`with_locale`](#method.with_locale) reads it. Returns a [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html)
+    /// if a match is found, `None` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed
+    /// * `locale` - A locale like "en_US" or "fr_FR"; languages other than English need their feature enabled
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeParser;
+    ///
+    /// assert_eq!(TimeParser::parse_with_locale("Lunch at 12:30pm", "en_US"), NaiveTime::from_hms_opt(12, 30, 0));
+    /// ```
+    pub fn parse_with_locale(text: &str, locale: &str) -> Option<NaiveTime> {
+        TimeParser::new()
+            .with_locale(locale)
+            .find(text, UtcClock.now().time())
+            .map(|found| found.time)
     }
 }
 
-#[derive(Debug, PartialEq)]
-// An intermediate expression for parsing unstructured text into [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html).
-enum TimeExpr {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// An intermediate expression for parsing unstructured text into [`NaiveTime`](https://docs.rs/chrono/0.4.0/chrono/naive/struct.NaiveTime.html),
+/// saying what a time is written as rather than when it is. Found in text by
+/// [`TimeParser::parse_expr`](struct.TimeParser.html#method.parse_expr) and read around a time with
+/// [`resolve`](#method.resolve).
+pub enum TimeExpr {
+    /// A time of day, e.g. 7:30pm => Absolute(19:30)
     Absolute(NaiveTime),
+    /// A number of hours from now, e.g. in 2 hours => InNHours(2)
     InNHours(u32),
+    /// A number of minutes from now, e.g. in 45 minutes => InNMins(45)
     InNMins(u32),
 }
 
+impl TimeExpr {
+    /// Returns the time this expression names with respect to `now`, wrapping past midnight.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use date_time_parser::TimeExpr;
+    ///
+    /// let now = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+    /// assert_eq!(TimeExpr::InNHours(2).resolve(now), NaiveTime::from_hms_opt(1, 0, 0).unwrap());
+    /// ```
+    pub fn resolve(&self, now: NaiveTime) -> NaiveTime {
+        match *self {
+            TimeExpr::Absolute(time) => time,
+            TimeExpr::InNHours(h) => now.overflowing_add_signed(Duration::hours(h as i64)).0,
+            TimeExpr::InNMins(m) => now.overflowing_add_signed(Duration::minutes(m as i64)).0,
+        }
+    }
+}
+
 /// Parsing a `str` into a TimeExpr uses both structured formats and common phrases.
 impl Recognizable for TimeExpr {
     fn recognize(text: &str) -> Option<TimeExpr> {
@@ -94,87 +839,229 @@ impl Recognizable for TimeExpr {
 }
 
 fn parse_absolute_time(text: &str) -> Option<TimeExpr> {
-    let re =
-        Regex::new(r"(?i)(^|\b)(?P<hour>\d{1,2}):?(?P<minute>\d{2})?(?P<meridiem>[ap]m?)?($|\b)")
-            .unwrap();
-
-    let date_pattern = Regex::new(r"\d{1,2}/\d{1,2}").unwrap();
-    if let Some(caps) = re.captures(&date_pattern.replace_all(text, "")) {
-        let mut hour: u32 = 0;
-        let mut minute = 0;
-
-        if let Some(hour_match) = caps.name("hour") {
-            hour = hour_match.as_str().parse().unwrap();
-        }
-
-        // contains a minute value
-        if let Some(minute_match) = caps.name("minute") {
-            minute = minute_match.as_str().parse().unwrap();
-        }
+    let (hour, minute, meridiem) = absolute_time(text)?;
+    Some(TimeExpr::Absolute(time_of_day(hour, minute, meridiem)?))
+}
 
+/// Returns the time of an hour and minute as written, read as pm if `meridiem` says so and guessed if it is not given.
+fn time_of_day(mut hour: u32, minute: u32, meridiem: Option<bool>) -> Option<NaiveTime> {
+    match meridiem {
         // contains am or pm
-        if let Some(meridiem_match) = caps.name("meridiem") {
-            if meridiem_match.as_str().to_lowercase().contains('p') && hour != 12 {
+        Some(pm) => {
+            if pm && hour != 12 {
                 hour += 12;
-            } else {
             }
-        } else {
-            // doesn't contain am or pm, default is pm for 1-8 and am for 9-12
+        }
+        // doesn't contain am or pm, default is pm for 1-8 and am for 9-12
+        None => {
             if hour < 9 {
                 hour += 12;
             }
         }
+    }
 
-        return Some(TimeExpr::Absolute(NaiveTime::from_hms_opt(
-            hour, minute, 0,
-        )?));
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// Finds the time of an ISO 8601 date and time like "2021-06-05T14:30:00", which is on the 24-hour clock.
+fn iso_time(text: &str) -> Option<NaiveTime> {
+    let caps = ISO_DATE_TIME.captures(text)?;
+    let hour = caps.name("hour")?.as_str().parse().ok()?;
+    let minute = caps["minute"].parse().ok()?;
+    let second = caps
+        .name("second")
+        .map_or(Some(0), |s| s.as_str().parse().ok())?;
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Finds a clock time in `text` and returns its hour and minute as written, and whether it is pm if am or pm is given.
+fn absolute_time(text: &str) -> Option<(u32, u32, Option<bool>)> {
+    // days of dates like "6/10", "June 5" or "julian day 045" are not hours, nor are counts like "3 days"
+    let text = DATE_MENTION.replace_all(text, "");
+    let text = ORDINAL_DATE.replace_all(&text, "");
+    let text = COUNTED_DAYS.replace_all(&text, "");
+
+    // "19h30" is on the 24-hour clock, so read as written
+    if let Some(caps) = CONTINENTAL_TIME.captures(&text) {
+        let hour = caps.name("hour").or_else(|| caps.name("bare"))?;
+        let minute = caps
+            .name("minute")
+            .map_or(0, |m| m.as_str().parse().unwrap());
+        return Some((hour.as_str().parse().unwrap(), minute, Some(false)));
     }
 
-    None
+    // so are "1830h" and "0700 hours"
+    if let Some(caps) = MILITARY_TIME.captures(&text) {
+        let hour = caps["hour"].parse().unwrap();
+        let minute = caps["minute"].parse().unwrap();
+        return Some((hour, minute, Some(false)));
+    }
+
+    // a number like the "2025" of "Budget 2025 review" is only a time when it is said to be one, and a time with am,
+    // pm or minutes is read before a bare number like the "5" of "Room 5 meeting at 3pm"
+    let times: Vec<regex::Captures> = CLOCK_TIME
+        .captures_iter(&text)
+        .filter(|caps| !compact(caps) || stated(&text, caps))
+        .collect();
+    let written =
+        |caps: &&regex::Captures| caps.name("meridiem").is_some() || caps[0].contains(':');
+    // "seven thirty pm" has no digits
+    let caps = match times.iter().find(written).or_else(|| times.first()) {
+        Some(caps) => caps,
+        None => return word_time(&text),
+    };
+
+    let hour = caps["hour"].parse().unwrap();
+    // contains a minute value
+    let minute = caps
+        .name("minute")
+        .map_or(0, |m| m.as_str().parse().unwrap());
+    let meridiem = caps
+        .name("meridiem")
+        .map(|m| m.as_str().to_lowercase().contains('p'))
+        .or_else(|| twenty_four_hour(caps).then_some(false));
+    Some((hour, minute, meridiem))
 }
 
-/// Parses a `str` into an `Option` containing a `TimeExpr::Absolute(NaiveTime)`.
-fn parse_casual_time(text: &str) -> Option<TimeExpr> {
-    // "morning", "evening", "midnight", "mid{-}?day", ...?
-
-    let casual_phrases = vec![
-        r"morning",
-        r"afternoon",
-        r"evening",
-        r"tonight",
-        r"noon",
-        r"midnight",
-    ];
-    let hours = vec![9, 14, 18, 21, 12, 0];
-
-    for (i, phrase) in casual_phrases.iter().enumerate() {
-        let re = Regex::new(phrase).unwrap();
-        // println!("match: {:?}", re.find(&text));
-        if re.find(&text).is_some() {
-            // println!("hour: {}", hours[i]);
-            return Some(TimeExpr::Absolute(NaiveTime::from_hms_opt(hours[i], 0, 0)?));
+/// Returns whether a clock time is four digits without a colon or am or pm, like "0700" or "2025".
+fn compact(caps: &regex::Captures) -> bool {
+    caps["hour"].len() == 2
+        && caps.name("minute").is_some()
+        && caps.name("meridiem").is_none()
+        && !caps[0].contains(':')
+}
+
+/// Returns whether a compact clock time is said to be a time, by a leading zero as in "0700" or a word before it as in
+/// "at 1830", rather than being a year or a room number.
+fn stated(text: &str, caps: &regex::Captures) -> bool {
+    caps["hour"].starts_with('0') || STATED_BEFORE.is_match(&text[..caps.get(0).unwrap().start()])
+}
+
+/// Returns whether a clock time without am or pm can only be on the 24-hour clock, like "18:30", "07:15" or "0700",
+/// so its meridiem is not guessed.
+fn twenty_four_hour(caps: &regex::Captures) -> bool {
+    let hour = &caps["hour"];
+    hour.parse::<u32>().is_ok_and(|h| h >= 13)
+        || (hour.len() == 2 && (hour.starts_with('0') || compact(caps)))
+}
+
+/// Finds a time written in words like "seven thirty pm" or "at seven" in `text` and returns its hour and minute, and
+/// whether it is pm if am or pm is given.
+fn word_time(text: &str) -> Option<(u32, u32, Option<bool>)> {
+    let caps = WORD_TIME
+        .captures_iter(text)
+        .find(|caps| is_word_time(caps, text))?;
+    let hour = parse_number(&caps["hour"])? as u32;
+    let minute = match caps.name("minute") {
+        // "nine oh five"
+        Some(m) if m.as_str().to_lowercase().starts_with("oh") => parse_number(&m.as_str()[3..])?,
+        Some(m) => parse_number(m.as_str())?,
+        None => 0.0,
+    };
+    let meridiem = caps
+        .name("meridiem")
+        .map(|m| m.as_str().to_lowercase().contains('p'));
+    Some((hour, minute as u32, meridiem))
+}
+
+/// Finds a colloquial time like "half past 7" or "quarter to 8" in `text` and returns the hour named, whether it is
+/// pm if am or pm is given, and the minutes from that hour. Unless `british`, "half 7" is not read, and neither is
+/// "10 to 12", which could be a range of hours, though "10 minutes to 12" is.
+fn colloquial_time(text: &str, british: bool) -> Option<(u32, Option<bool>, i64)> {
+    let caps = COLLOQUIAL_TIME.captures(text)?;
+    let past = caps[0].to_lowercase().contains("past");
+    let numeric = caps
+        .name("minutes")
+        .is_some_and(|m| m.as_str().chars().all(|c| c.is_ascii_digit()))
+        && !caps[0].to_lowercase().contains("minute");
+    if !british && !past && (caps.name("half").is_some() || numeric) {
+        return None;
+    }
+    let hour = parse_number(&caps["hour"])? as u32;
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    let meridiem = caps
+        .name("meridiem")
+        .map(|m| m.as_str().to_lowercase().contains('p'));
+    let minutes = match caps.name("minutes") {
+        None => 30,
+        Some(m) => {
+            let m = m.as_str().to_lowercase();
+            let m = if m.ends_with("quarter") {
+                15
+            } else {
+                parse_number(&m)? as i64
+            };
+            if !(1..60).contains(&m) {
+                return None;
+            }
+            if caps["dir"].eq_ignore_ascii_case("to") {
+                -m
+            } else {
+                m
+            }
         }
+    };
+    Some((hour, meridiem, minutes))
+}
+
+/// Parses a `str` into an `Option` containing a `TimeExpr::Absolute(NaiveTime)` for a built-in phrase like "morning".
+fn parse_casual_time(text: &str) -> Option<TimeExpr> {
+    let (_, hour, minute) = CASUAL_TIMES
+        .iter()
+        .find(|(pattern, _, _)| casual_matches(pattern, text))?;
+    Some(TimeExpr::Absolute(NaiveTime::from_hms_opt(
+        *hour, *minute, 0,
+    )?))
+}
+
+/// Returns how far from now a relative time like "in 2 hours" or "soon" is, `None` if `text` has no relative time.
+pub(crate) fn relative_offset(text: &str) -> Option<Duration> {
+    let parser = TimeParser::new();
+    if let Some(phrase) = parser
+        .fuzzy
+        .iter()
+        .find(|p| p.latest.is_none() && p.is_match(text))
+    {
+        return Some(phrase.offset);
     }
 
-    None
+    match parse_relative_time(text)? {
+        TimeExpr::InNHours(h) => Some(Duration::hours(h as i64)),
+        TimeExpr::InNMins(m) => Some(Duration::minutes(m as i64)),
+        TimeExpr::Absolute(_) => None,
+    }
 }
 
-/// Parses a `str` into an `Option` containing a `TimeExpr::InNHours(u32)`.
+/// Parses a `str` into an `Option` containing a `TimeExpr::InNHours(u32)` or `TimeExpr::InNMins(u32)`.
 fn parse_relative_time(text: &str) -> Option<TimeExpr> {
-    // "in_hours/minutes"
+    // "in_hours/minutes", in 5 mins, in twenty minutes, in two and a half hours, in 1h30m
 
-    let re = Regex::new(r"in (?P<mins>\d{1,2}) (mins|minutes|min|minute)").unwrap();
-
-    if let Some(caps) = re.captures_iter(text).next() {
-        let mins: u32 = caps["mins"].parse().unwrap();
-        return Some(TimeExpr::InNMins(mins));
+    if let Some(caps) = IN_MINUTES.captures(text) {
+        let mins = parse_number(&caps["mins"])?;
+        return Some(TimeExpr::InNMins(mins.round() as u32));
     }
 
-    let re = Regex::new(r"in (?P<hours>\d{1,2}) (hrs|hours|hr|hour)").unwrap();
+    if let Some(caps) = IN_HOURS.captures(text) {
+        let half = if caps.name("half").is_some() {
+            0.5
+        } else {
+            0.0
+        };
+        let hours = parse_number(&caps["hours"])? + half;
+        if hours.fract() != 0.0 {
+            return Some(TimeExpr::InNMins((hours * 60.0).round() as u32));
+        }
+        return Some(TimeExpr::InNHours(hours as u32));
+    }
 
-    if let Some(caps) = re.captures_iter(text).next() {
-        let hours: u32 = caps["hours"].parse().unwrap();
-        return Some(TimeExpr::InNHours(hours));
+    if let Some(caps) = IN_DURATION.captures(text) {
+        let duration = shorthand_duration(&caps);
+        if duration.num_minutes() % 60 == 0 {
+            return Some(TimeExpr::InNHours(duration.num_hours() as u32));
+        }
+        return Some(TimeExpr::InNMins(duration.num_minutes() as u32));
     }
 
     None
@@ -183,8 +1070,11 @@ fn parse_relative_time(text: &str) -> Option<TimeExpr> {
 // Tests
 #[cfg(test)]
 mod time_expr_tests {
-    use super::{Recognizable, TimeExpr};
-    use chrono::NaiveTime;
+    use super::{
+        MeridiemPolicy, Recognizable, Rounding, TimeExpr, TimeMatch, TimeParser, TimeZoneMention,
+    };
+    use chrono::{FixedOffset, NaiveTime};
+    use chrono_tz::Tz;
 
     #[test]
     fn simple_hour_tests() {
@@ -203,6 +1093,13 @@ mod time_expr_tests {
         assert_recognize_time("2p", 14, 0);
     }
 
+    #[test]
+    fn time_after_date_tests() {
+        assert_recognize_time("June 5 7pm", 19, 0);
+        assert_recognize_time("Aug 21st at 9", 9, 0);
+        assert_recognize_time("6/10 11am", 11, 0);
+    }
+
     #[test]
     fn simple_minute_tests() {
         assert_recognize_time("12:30", 12, 30);
@@ -219,14 +1116,86 @@ mod time_expr_tests {
         assert_recognize_time("2:30p", 14, 30);
     }
 
+    #[test]
+    fn spaced_and_dotted_meridiem_tests() {
+        assert_recognize_time("7 PM", 19, 0);
+        assert_recognize_time("9 am", 9, 0);
+        assert_recognize_time("7 p.m.", 19, 0);
+        assert_recognize_time("7p.m.", 19, 0);
+        assert_recognize_time("7:30 A.M.", 7, 30);
+        assert_recognize_time("11:15 a. m.", 11, 15);
+        assert_recognize_time("Dinner at 8 p.m. on Friday", 20, 0);
+    }
+
     #[test]
     fn casual_time_tests() {
         assert_recognize_time("in the morning", 9, 0);
         assert_recognize_time("this afternoon", 14, 0);
         assert_recognize_time("in the evening", 18, 0);
         assert_recognize_time("tonight", 21, 0);
+        assert_recognize_time("last night", 21, 0);
+        assert_recognize_time("Friday night", 21, 0);
         assert_recognize_time("noon", 12, 0);
         assert_recognize_time("midnight", 0, 0);
+        assert_recognize_time("call first thing", 9, 0);
+        assert_recognize_time("walk at lunchtime", 12, 0);
+        assert_recognize_time("lunch time", 12, 0);
+        assert_recognize_time("dinnertime", 18, 0);
+        assert_recognize_time("send it EOD", 17, 0);
+        assert_recognize_time("by end of the day", 17, 0);
+        assert_recognize_time("report by COB", 17, 0);
+        assert_recognize_time("close of business", 17, 0);
+        assert_recognize_time("done before cob", 17, 0);
+        assert_eq!(TimeExpr::recognize("corn on the cob"), None);
+    }
+
+    #[test]
+    fn casual_word_boundary_tests() {
+        let parser = TimeParser::new();
+        for text in [
+            "moonlight walk",
+            "nightclub",
+            "stay overnight",
+            "knights game",
+            "afternoons off",
+            "Noonan's party",
+            "Coffee at Evening Star cafe",
+            "Tickets for Midnight Express",
+        ] {
+            assert_eq!(parser.find(text, hm(8, 0)), None, "{}", text);
+        }
+        // a title starting the text is still a part of the day
+        assert_eq!(parser.find("Morning Run", hm(6, 0)).unwrap().time, hm(9, 0));
+        assert_eq!(
+            parser
+                .find("Drinks at Evening Star at 6", hm(8, 0))
+                .unwrap()
+                .time,
+            hm(18, 0)
+        );
+        assert!(
+            parser
+                .find("Breakfast at Morning Glory at 7", hm(6, 0))
+                .unwrap()
+                .guessed_meridiem
+        );
+    }
+
+    #[test]
+    fn casual_precedence_tests() {
+        let parser = TimeParser::new();
+        let find = |text: &str| parser.find(text, hm(8, 0)).unwrap().time;
+        // a written time beats a part of the day, wherever it is
+        assert_eq!(find("tonight 7pm"), hm(19, 0));
+        assert_eq!(find("7pm tonight"), hm(19, 0));
+        assert_eq!(find("EOD 4pm"), hm(16, 0));
+        assert_eq!(find("8:15 first thing"), hm(8, 15));
+        // while a time named outright is read before one written after it
+        assert_eq!(find("noon or 1pm"), hm(12, 0));
+        assert_eq!(find("noon to 2pm"), hm(12, 0));
+        assert_eq!(find("midnight till 2am"), hm(0, 0));
+        assert_eq!(find("12 midnight"), hm(0, 0));
+        assert_eq!(find("1pm, or noon if late"), hm(13, 0));
     }
 
     #[test]
@@ -234,6 +1203,14 @@ mod time_expr_tests {
         assert_in_mins_time("in 5 mins", 5);
         assert_in_mins_time("in 10 minutes", 10);
         assert_in_mins_time("in 1 min", 1);
+        assert_in_mins_time("in twenty minutes", 20);
+        assert_in_mins_time("In forty-five mins", 45);
+        assert_in_mins_time("in half an hour", 30);
+        assert_in_mins_time("in two and a half hours", 150);
+        assert_in_mins_time("in an hour and a half", 90);
+        assert_in_mins_time("Call in 2 hours and a half", 150);
+        assert_in_mins_time("in 45m", 45);
+        assert_in_mins_time("in 1h30m", 90);
     }
 
     #[test]
@@ -242,6 +1219,366 @@ mod time_expr_tests {
         assert_in_hours_time("in 3 hrs", 3);
         assert_in_hours_time("in 1 hr", 1);
         assert_in_hours_time("in 1 hour", 1);
+        assert_in_hours_time("in two hours", 2);
+        assert_in_hours_time("in an hour", 1);
+        assert_in_hours_time("in 2h", 2);
+    }
+
+    #[test]
+    fn guessed_meridiem_tests() {
+        let parser = TimeParser::new();
+        let guessed = |text: &str| parser.find(text, hm(12, 0)).unwrap().guessed_meridiem;
+        assert!(guessed("Dinner at 7"));
+        assert!(guessed("call 10:30"));
+        assert!(!guessed("Dinner at 7pm"));
+        assert!(!guessed("Dinner at 7 p.m."));
+        assert!(!guessed("standup 14:00"));
+        assert!(!guessed("in 2 hours"));
+        assert!(!guessed("lunch at noon"));
+    }
+
+    #[test]
+    fn candidates_tests() {
+        let parser = TimeParser::new();
+        assert_eq!(
+            parser.candidates("Dinner at 7", hm(12, 0)),
+            vec![(hm(19, 0), 0.6), (hm(7, 0), 0.4)]
+        );
+        assert_eq!(
+            parser.candidates("call 10:30", hm(12, 0)),
+            vec![(hm(10, 30), 0.6), (hm(22, 30), 0.4)]
+        );
+        assert_eq!(
+            parser.candidates("Dinner at 7pm", hm(12, 0)),
+            vec![(hm(19, 0), 1.0)]
+        );
+        assert_eq!(
+            parser.candidates("Coffee soon", hm(12, 0)),
+            vec![(hm(12, 30), 0.5)]
+        );
+        assert_eq!(parser.candidates("Coffee", hm(12, 0)), vec![]);
+    }
+
+    #[test]
+    fn context_filter_tests() {
+        let parser = TimeParser::new();
+        for text in [
+            "Call 555-1230",
+            "$5 lunch",
+            "deploy v2.30",
+            "Call (555) 123-4567",
+            "Buy milk for $5-10 tomorrow",
+            "Release v1.2-3 Friday",
+            "Tickets 20–30 dollars",
+        ] {
+            assert_eq!(parser.find(text, hm(12, 0)), None, "{}", text);
+        }
+        assert_eq!(
+            parser.find("$5 lunch at 1pm", hm(12, 0)).unwrap().time,
+            hm(13, 0)
+        );
+        assert_eq!(
+            parser
+                .with_context_filters(false)
+                .find("$5 lunch", hm(12, 0))
+                .unwrap()
+                .time,
+            hm(17, 0)
+        );
+    }
+
+    #[test]
+    fn continental_time_tests() {
+        assert_recognize_time("Dîner 19h30", 19, 30);
+        assert_recognize_time("rdv 9h05", 9, 5);
+        assert_recognize_time("Dinner at 19h", 19, 0);
+        assert_recognize_time("Réunion à 8h", 8, 0);
+        assert_recognize_time("Brunch @ 11h", 11, 0);
+        assert_recognize_time("0h15", 0, 15);
+        assert_recognize_time("room 5 at 17h45", 17, 45);
+        assert_eq!(TimeParser::parse_relative("25h10", hm(12, 0)), None);
+
+        let parser = TimeParser::new();
+        assert!(
+            !parser
+                .find("Dinner at 19h", hm(12, 0))
+                .unwrap()
+                .guessed_meridiem
+        );
+        assert!(!parser.find("rdv 8h30", hm(12, 0)).unwrap().guessed_meridiem);
+    }
+
+    #[test]
+    fn twenty_four_hour_time_tests() {
+        assert_recognize_time("Dinner 18:30", 18, 30);
+        assert_recognize_time("Standup 0700", 7, 0);
+        assert_recognize_time("Run at 07:15", 7, 15);
+        assert_recognize_time("Muster 1830h", 18, 30);
+        assert_recognize_time("Muster 0600 hours", 6, 0);
+        assert_recognize_time("Briefing at 1145", 11, 45);
+        assert_recognize_time("Watch 0000", 0, 0);
+        assert_eq!(TimeParser::parse_relative("2460h", hm(12, 0)), None);
+
+        // an hour of the 24-hour clock is read as written, whatever the meridiem policy
+        let parser = TimeParser::new().with_meridiem_policy(MeridiemPolicy::Pm);
+        for (text, time) in [
+            ("at 0700", hm(7, 0)),
+            ("at 18", hm(18, 0)),
+            ("at 7", hm(19, 0)),
+        ] {
+            assert_eq!(parser.find(text, hm(6, 0)).unwrap().time, time, "{}", text);
+        }
+        assert!(!parser.find("at 0700", hm(6, 0)).unwrap().guessed_meridiem);
+        assert!(!parser.find("at 1830h", hm(6, 0)).unwrap().guessed_meridiem);
+        assert!(parser.find("at 7:30", hm(6, 0)).unwrap().guessed_meridiem);
+
+        // years and room numbers are not times, and a time with am, pm or minutes is read before a bare number
+        let parser = TimeParser::new();
+        let find = |text: &str| parser.find(text, hm(8, 0)).map(|found| found.time);
+        assert_eq!(find("Budget 2025 review at 3pm"), Some(hm(15, 0)));
+        assert_eq!(find("Plan for 2026 kickoff at 9am"), Some(hm(9, 0)));
+        assert_eq!(find("Room 1203 meeting at 3pm"), Some(hm(15, 0)));
+        assert_eq!(find("Room 5 meeting at 3:30"), Some(hm(15, 30)));
+        assert_eq!(find("Budget 2025 review"), None);
+        assert_eq!(find("Room 1203"), None);
+    }
+
+    #[test]
+    fn british_time_tests() {
+        let parser = TimeParser::new().with_british_times(true);
+        let find = |text: &str| parser.find(text, hm(12, 0)).map(|found| found.time);
+        assert_eq!(find("drinks at half 7"), Some(hm(19, 30)));
+        assert_eq!(find("Half past seven"), Some(hm(19, 30)));
+        assert_eq!(find("call at half 10"), Some(hm(10, 30)));
+        assert_eq!(find("meet 10 past 7"), Some(hm(19, 10)));
+        assert_eq!(find("meet ten minutes past 7"), Some(hm(19, 10)));
+        assert_eq!(find("train at 20 to 8am"), Some(hm(7, 40)));
+        assert_eq!(find("twenty-five to six"), Some(hm(17, 35)));
+        assert_eq!(find("a quarter past 3"), Some(hm(15, 15)));
+        assert_eq!(find("quarter to 9"), Some(hm(8, 45)));
+        assert_eq!(find("20 to 1"), Some(hm(12, 40)));
+        assert_eq!(find("quarter to 12 p.m."), Some(hm(11, 45)));
+        assert!(parser.find("half 7", hm(12, 0)).unwrap().guessed_meridiem);
+        assert!(!parser.find("half 7pm", hm(12, 0)).unwrap().guessed_meridiem);
+
+        // hours are 1 to 12 and minutes under 60
+        assert_eq!(find("half 13"), Some(hm(13, 0)));
+        assert_eq!(find("75 past 7"), None);
+
+        // off by default
+        let find = |text: &str| {
+            TimeParser::new()
+                .find(text, hm(12, 0))
+                .map(|found| found.time)
+        };
+        assert_eq!(find("drinks at half 7"), Some(hm(19, 0)));
+    }
+
+    #[test]
+    fn word_time_tests() {
+        let find = |text: &str| {
+            TimeParser::new()
+                .find(text, hm(8, 0))
+                .map(|found| found.time)
+        };
+        assert_eq!(find("seven thirty pm"), Some(hm(19, 30)));
+        assert_eq!(find("Dinner at seven"), Some(hm(19, 0)));
+        assert_eq!(find("call at nine fifteen am"), Some(hm(9, 15)));
+        assert_eq!(find("train at nine oh five"), Some(hm(9, 5)));
+        assert_eq!(find("Meet at Twelve"), Some(hm(12, 0)));
+        assert_eq!(find("ten o'clock standup"), Some(hm(10, 0)));
+        assert_eq!(find("quarter past nine"), Some(hm(9, 15)));
+        assert_eq!(find("half past three"), Some(hm(15, 30)));
+        assert_eq!(find("twenty to eight"), Some(hm(19, 40)));
+        assert!(
+            TimeParser::new()
+                .find("Dinner at seven", hm(8, 0))
+                .unwrap()
+                .guessed_meridiem
+        );
+
+        // numbers that are not times
+        assert_eq!(find("Lunch with two friends"), None);
+        assert_eq!(find("at one point"), None);
+        assert_eq!(find("seven wonders"), None);
+    }
+
+    #[test]
+    fn meridiem_policy_tests() {
+        let find = |policy: MeridiemPolicy, text: &str| {
+            let found = TimeParser::new()
+                .with_meridiem_policy(policy)
+                .find(text, hm(6, 0))
+                .unwrap();
+            (found.time, found.guessed_meridiem)
+        };
+        assert_eq!(find(MeridiemPolicy::Guess, "at 7"), (hm(19, 0), true));
+        assert_eq!(find(MeridiemPolicy::Am, "at 7"), (hm(7, 0), true));
+        assert_eq!(find(MeridiemPolicy::Am, "at 12"), (hm(0, 0), true));
+        assert_eq!(find(MeridiemPolicy::Pm, "at 10:30"), (hm(22, 30), true));
+        assert_eq!(find(MeridiemPolicy::Pm, "at 12"), (hm(12, 0), true));
+        assert_eq!(find(MeridiemPolicy::Am, "at 7pm"), (hm(19, 0), false));
+        assert_eq!(find(MeridiemPolicy::Pm, "at 9am"), (hm(9, 0), false));
+        assert_eq!(find(MeridiemPolicy::Am, "at 17:00"), (hm(17, 0), false));
+        assert_eq!(find(MeridiemPolicy::Am, "in 2 hours"), (hm(8, 0), false));
+
+        // the part of the day said with the hour settles it
+        assert_eq!(
+            find(MeridiemPolicy::Guess, "tonight at 10"),
+            (hm(22, 0), false)
+        );
+        assert_eq!(
+            find(MeridiemPolicy::Guess, "tomorrow night at 9:30"),
+            (hm(21, 30), false)
+        );
+        assert_eq!(
+            find(MeridiemPolicy::Am, "at 4 in the afternoon"),
+            (hm(16, 0), false)
+        );
+        assert_eq!(
+            find(MeridiemPolicy::Guess, "at 7 in the morning"),
+            (hm(7, 0), false)
+        );
+        assert_eq!(
+            find(MeridiemPolicy::Guess, "tonight at 8pm"),
+            (hm(20, 0), false)
+        );
+        assert_eq!(
+            find(MeridiemPolicy::Guess, "last night"),
+            (hm(21, 0), false)
+        );
+    }
+
+    #[test]
+    fn casual_phrase_tests() {
+        let parser = TimeParser::new()
+            .with_casual_time(r"morning", hm(7, 30))
+            .with_casual_time(r"lunch", hm(12, 30));
+        let find = |text: &str| parser.find(text, hm(6, 0)).unwrap().time;
+        assert_eq!(find("gym in the morning"), hm(7, 30));
+        assert_eq!(find("Lunch with Sam"), hm(12, 30));
+        assert_eq!(find("lunch at 1pm"), hm(13, 0));
+        assert_eq!(find("lunch in 2 hours"), hm(8, 0));
+        assert_eq!(find("this afternoon"), hm(14, 0));
+        assert_eq!(parser.find("luncheon", hm(6, 0)), None);
+
+        // built-in phrases can be given times of their own
+        let parser = TimeParser::new()
+            .with_casual_time(r"eod", hm(18, 0))
+            .with_casual_time(r"lunch\s?time", hm(13, 0));
+        let find = |text: &str| parser.find(text, hm(6, 0)).unwrap().time;
+        assert_eq!(find("send it EOD"), hm(18, 0));
+        assert_eq!(find("walk at lunchtime"), hm(13, 0));
+        assert_eq!(find("report by COB"), hm(17, 0));
+    }
+
+    #[test]
+    fn fuzzy_time_tests() {
+        assert_fuzzy_time("be there soon", (12, 0), (12, 30));
+        assert_fuzzy_time("call you in a bit", (12, 0), (13, 0));
+        assert_fuzzy_time("In a while", (12, 0), (14, 0));
+        assert_fuzzy_time("later today", (12, 0), (15, 0));
+        assert_fuzzy_time("later today", (19, 30), (21, 0));
+        assert_fuzzy_time("later today", (22, 0), (22, 0));
+        assert_fuzzy_time("soon", (23, 45), (0, 15));
+        assert_eq!(
+            TimeParser::new().find("6pm", hm(12, 0)),
+            Some(TimeMatch {
+                time: hm(18, 0),
+                approximate: false,
+                guessed_meridiem: false,
+                zone: None
+            })
+        );
+    }
+
+    #[test]
+    fn rounding_tests() {
+        assert_eq!(Rounding::Nearest5.round(hm(14, 47)), hm(14, 45));
+        assert_eq!(Rounding::Nearest5.round(hm(14, 48)), hm(14, 50));
+        assert_eq!(Rounding::Nearest15.round(hm(14, 52)), hm(14, 45));
+        assert_eq!(Rounding::Nearest15.round(hm(14, 53)), hm(15, 0));
+        assert_eq!(Rounding::Nearest30.round(hm(14, 44)), hm(14, 30));
+        assert_eq!(Rounding::Nearest30.round(hm(23, 50)), hm(0, 0));
+        assert_eq!(Rounding::Exact.round(hm(14, 47)), hm(14, 47));
+        assert_eq!(
+            Rounding::Nearest5.round(NaiveTime::from_hms_opt(14, 42, 31).unwrap()),
+            hm(14, 45)
+        );
+
+        let parser = TimeParser::new().with_rounding(Rounding::Nearest15);
+        let find = |text: &str| parser.find(text, hm(12, 47)).unwrap().time;
+        assert_eq!(find("in 2 hours"), hm(14, 45));
+        assert_eq!(find("in 20 minutes"), hm(13, 0));
+        assert_eq!(find("soon"), hm(13, 15));
+        assert_eq!(find("at 2:47pm"), hm(14, 47));
+    }
+
+    #[test]
+    fn time_zone_tests() {
+        let hours = |h: i32| {
+            Some(TimeZoneMention::Offset(
+                FixedOffset::east_opt(h * 3600).unwrap(),
+            ))
+        };
+        let find = |text: &str| TimeParser::new().find(text, hm(8, 0)).unwrap();
+
+        let found = find("Call at 3pm EST");
+        assert_eq!((found.time, found.zone), (hm(15, 0), hours(-5)));
+        let found = find("Standup 14:00 CET");
+        assert_eq!((found.time, found.zone), (hm(14, 0), hours(1)));
+        let found = find("Sync 9am UTC+2");
+        assert_eq!((found.time, found.zone), (hm(9, 0), hours(2)));
+        let found = find("Call at 3pm (America/New_York)");
+        assert_eq!(
+            (found.time, found.zone),
+            (
+                hm(15, 0),
+                Some(TimeZoneMention::Named(Tz::America__New_York))
+            )
+        );
+        assert_eq!(find("Call at 3pm").zone, None);
+
+        assert_eq!(
+            TimeParser::find_zone("Webinar 11 a.m. PT"),
+            Some((TimeZoneMention::Named(Tz::America__Los_Angeles), 16..18))
+        );
+        assert_eq!(TimeParser::find_zone("Call at 3pm Europe/Atlantis"), None);
+        // abbreviations are read in capitals, and only after a time
+        assert_eq!(TimeParser::find_zone("3 pm est"), None);
+        assert_eq!(TimeParser::find_zone("EST meeting at 3pm"), None);
+    }
+
+    #[test]
+    fn iso_time_tests() {
+        let hours = |h: i32| {
+            Some(TimeZoneMention::Offset(
+                FixedOffset::east_opt(h * 3600).unwrap(),
+            ))
+        };
+        let find = |text: &str| TimeParser::new().find(text, hm(8, 0));
+
+        let found = find("Deploy 2021-06-05T14:30:00Z").unwrap();
+        assert_eq!((found.time, found.zone), (hm(14, 30), hours(0)));
+        assert!(!found.guessed_meridiem);
+        let found = find("Review 2021-06-05T09:15:30.250+02:00").unwrap();
+        assert_eq!(
+            (found.time, found.zone),
+            (NaiveTime::from_hms_opt(9, 15, 30).unwrap(), hours(2))
+        );
+        assert_eq!(find("Call 2021-06-05 07:00").unwrap().time, hm(7, 0));
+        // the days and years of dates alone are not hours
+        assert_eq!(find("Launch 2021-06-05"), None);
+
+        assert_eq!(
+            TimeParser::find_zone("Sync 2021-06-05T09:30:00-05:00"),
+            Some((hours(-5).unwrap(), 24..30))
+        );
+        // an offset needs seconds before it, unless it is "Z" or ahead of UTC, so this is a range
+        assert_eq!(
+            TimeParser::find_zone("Standup 2021-06-05 09:30-10:00"),
+            None
+        );
     }
 
     #[test]
@@ -251,12 +1588,93 @@ mod time_expr_tests {
         assert_eq!(TimeExpr::recognize("99:99"), None);
     }
 
+    #[test]
+    fn parse_expr_tests() {
+        let parser = TimeParser::new();
+        assert_eq!(
+            parser.parse_expr("Call in 45 minutes"),
+            Some(TimeExpr::InNMins(45))
+        );
+        assert_eq!(
+            TimeParser::new()
+                .with_british_times(true)
+                .parse_expr("train at 20 to 8am"),
+            Some(TimeExpr::Absolute(hm(7, 40)))
+        );
+        assert_eq!(
+            parser.parse_expr("Standup 9:30 EST"),
+            Some(TimeExpr::Absolute(hm(9, 30)))
+        );
+        assert_eq!(parser.parse_expr("back soon"), None);
+        assert_eq!(parser.parse_expr("$5 lunch"), None);
+        let parser = TimeParser::new().with_meridiem_policy(MeridiemPolicy::Pm);
+        assert_eq!(
+            parser.parse_expr("run at 7"),
+            Some(TimeExpr::Absolute(hm(19, 0)))
+        );
+
+        let now = hm(22, 30);
+        assert_eq!(TimeExpr::InNMins(45).resolve(now), hm(23, 15));
+        assert_eq!(TimeExpr::InNHours(3).resolve(now), hm(1, 30));
+        assert_eq!(TimeExpr::Absolute(hm(7, 0)).resolve(now), hm(7, 0));
+    }
+
+    #[cfg(feature = "locale-es")]
+    #[test]
+    fn spanish_time_tests() {
+        let es = TimeParser::new().with_locale("es_ES");
+        let now = hm(8, 0);
+        let time = |text| es.find(text, now).map(|found| found.time);
+        assert_eq!(time("Cena a las 5 de la tarde"), Some(hm(17, 0)));
+        assert_eq!(time("Clase a las 9:30 de la mañana"), Some(hm(9, 30)));
+        assert_eq!(time("Almuerzo al mediodía"), Some(hm(12, 0)));
+        assert_eq!(time("Llamar en 2 horas"), Some(hm(10, 0)));
+        assert_eq!(
+            TimeParser::parse_with_locale("Cena a las 8 de la noche", "es"),
+            Some(hm(20, 0))
+        );
+    }
+
+    #[cfg(feature = "locale-fr")]
+    #[test]
+    fn french_time_tests() {
+        let fr = TimeParser::new().with_locale("fr_FR");
+        let time = |text| fr.find(text, hm(8, 0)).map(|found| found.time);
+        assert_eq!(time("Dîner à 19h30"), Some(hm(19, 30)));
+        assert_eq!(time("Déjeuner à midi"), Some(hm(12, 0)));
+    }
+
+    #[cfg(feature = "locale-de")]
+    #[test]
+    fn german_time_tests() {
+        let de = TimeParser::new().with_locale("de_DE");
+        let time = |text| de.find(text, hm(8, 0)).map(|found| found.time);
+        assert_eq!(time("Essen um 19:30 Uhr"), Some(hm(19, 30)));
+        assert_eq!(time("Anruf in 2 Stunden"), Some(hm(10, 0)));
+    }
+
+    fn assert_fuzzy_time(text: &str, now: (u32, u32), expected: (u32, u32)) {
+        assert_eq!(
+            TimeParser::new().find(text, hm(now.0, now.1)),
+            Some(TimeMatch {
+                time: hm(expected.0, expected.1),
+                approximate: true,
+                guessed_meridiem: false,
+                zone: None
+            })
+        )
+    }
+
+    fn hm(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
     fn assert_recognize_time(text: &str, expected_h: u32, expected_m: u32) {
         assert_eq!(
             TimeExpr::recognize(text),
-            Some(TimeExpr::Absolute(NaiveTime::from_hms(
-                expected_h, expected_m, 0
-            )))
+            Some(TimeExpr::Absolute(
+                NaiveTime::from_hms_opt(expected_h, expected_m, 0).unwrap()
+            ))
         )
     }
 
@@ -0,0 +1,187 @@
+//! Timezones named with a time, like the "EST" of "3pm EST" or "Europe/Paris" of "14:00 Europe/Paris".
+
+use chrono::{FixedOffset, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+/// Abbreviations that stand for a fixed UTC offset, in minutes east of UTC, e.g. "EST" for UTC-5 all year round.
+const ABBREVIATIONS: [(&str, i32); 37] = [
+    ("UTC", 0),
+    ("GMT", 0),
+    ("EST", -300),
+    ("EDT", -240),
+    ("CST", -360),
+    ("CDT", -300),
+    ("MST", -420),
+    ("MDT", -360),
+    ("PST", -480),
+    ("PDT", -420),
+    ("AKST", -540),
+    ("AKDT", -480),
+    ("HST", -600),
+    ("AST", -240),
+    ("ADT", -180),
+    ("NST", -210),
+    ("NDT", -150),
+    ("BST", 60),
+    ("IST", 330),
+    ("WET", 0),
+    ("WEST", 60),
+    ("CET", 60),
+    ("CEST", 120),
+    ("EET", 120),
+    ("EEST", 180),
+    ("MSK", 180),
+    ("JST", 540),
+    ("KST", 540),
+    ("SGT", 480),
+    ("HKT", 480),
+    ("AWST", 480),
+    ("ACST", 570),
+    ("ACDT", 630),
+    ("AEST", 600),
+    ("AEDT", 660),
+    ("NZST", 720),
+    ("NZDT", 780),
+];
+
+/// Abbreviations that stand for whichever of standard and daylight time is in effect, e.g. "ET" for Eastern Time.
+const GENERIC_ABBREVIATIONS: [(&str, Tz); 4] = [
+    ("ET", Tz::America__New_York),
+    ("CT", Tz::America__Chicago),
+    ("MT", Tz::America__Denver),
+    ("PT", Tz::America__Los_Angeles),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A timezone named with a time, found by [`TimeParser::find_zone`](struct.TimeParser.html#method.find_zone).
+pub enum TimeZoneMention {
+    /// An abbreviation or offset standing for a fixed offset from UTC, like "EST" or "UTC+2"
+    Offset(FixedOffset),
+
+    /// A timezone from the IANA database, like "America/New_York", or an abbreviation like "ET" standing for one,
+    /// whose offset depends on the date
+    Named(Tz),
+}
+
+impl TimeZoneMention {
+    /// Returns the timezone `name` stands for, e.g. "EST", "UTC+5:30" or "Europe/Paris", without any brackets.
+    pub(crate) fn named(name: &str) -> Option<TimeZoneMention> {
+        if let Some(offset) = name
+            .strip_prefix("UTC")
+            .or_else(|| name.strip_prefix("GMT"))
+            .filter(|offset| !offset.is_empty())
+        {
+            return utc_offset(offset).map(TimeZoneMention::Offset);
+        }
+        if let Some((_, minutes)) = ABBREVIATIONS.iter().find(|(abbr, _)| *abbr == name) {
+            return FixedOffset::east_opt(minutes * 60).map(TimeZoneMention::Offset);
+        }
+        if let Some((_, tz)) = GENERIC_ABBREVIATIONS.iter().find(|(abbr, _)| *abbr == name) {
+            return Some(TimeZoneMention::Named(*tz));
+        }
+        name.parse().ok().map(TimeZoneMention::Named)
+    }
+
+    /// Returns the offset from UTC of the timezone at `local`, a wall-clock time in it. A time skipped when clocks go
+    /// forward has the offset of an hour later, and one repeated when they go back the offset it has the first time
+    /// round.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate};
+    /// use date_time_parser::TimeParser;
+    ///
+    /// let (zone, _) = TimeParser::find_zone("Call at 3pm America/New_York").unwrap();
+    /// let july = NaiveDate::from_ymd_opt(2021, 7, 1).unwrap().and_hms_opt(15, 0, 0).unwrap();
+    /// assert_eq!(zone.offset_at(july), FixedOffset::west_opt(4 * 3600).unwrap());
+    /// ```
+    pub fn offset_at(&self, local: NaiveDateTime) -> FixedOffset {
+        match self {
+            TimeZoneMention::Offset(offset) => *offset,
+            TimeZoneMention::Named(tz) => tz
+                .offset_from_local_datetime(&local)
+                .earliest()
+                .or_else(|| {
+                    tz.offset_from_local_datetime(&(local + chrono::Duration::hours(1)))
+                        .earliest()
+                })
+                .unwrap_or_else(|| tz.offset_from_utc_datetime(&local))
+                .fix(),
+        }
+    }
+}
+
+/// Reads an offset from UTC like "+2", "-05:00" or "+0530".
+fn utc_offset(text: &str) -> Option<FixedOffset> {
+    let (sign, digits) = text.split_at(1);
+    let digits = digits.replace(':', "");
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i32>().ok()?, 0),
+        3 | 4 => {
+            let (hours, minutes) = digits.split_at(digits.len() - 2);
+            (hours.parse::<i32>().ok()?, minutes.parse::<i32>().ok()?)
+        }
+        _ => return None,
+    };
+    let seconds = hours * 3600 + minutes * 60;
+    FixedOffset::east_opt(if sign == "-" { -seconds } else { seconds })
+}
+
+#[cfg(test)]
+mod time_zone_tests {
+    use super::TimeZoneMention;
+    use chrono::{FixedOffset, NaiveDate};
+    use chrono_tz::Tz;
+
+    #[test]
+    fn named_tests() {
+        let hours = |h: i32| {
+            Some(TimeZoneMention::Offset(
+                FixedOffset::east_opt(h * 3600).unwrap(),
+            ))
+        };
+        assert_eq!(TimeZoneMention::named("EST"), hours(-5));
+        assert_eq!(TimeZoneMention::named("CEST"), hours(2));
+        assert_eq!(TimeZoneMention::named("UTC"), hours(0));
+        assert_eq!(TimeZoneMention::named("GMT-3"), hours(-3));
+        assert_eq!(
+            TimeZoneMention::named("UTC+05:30"),
+            Some(TimeZoneMention::Offset(
+                FixedOffset::east_opt(5 * 3600 + 1800).unwrap()
+            ))
+        );
+        assert_eq!(
+            TimeZoneMention::named("PT"),
+            Some(TimeZoneMention::Named(Tz::America__Los_Angeles))
+        );
+        assert_eq!(
+            TimeZoneMention::named("Europe/Paris"),
+            Some(TimeZoneMention::Named(Tz::Europe__Paris))
+        );
+        assert_eq!(TimeZoneMention::named("Europe/Atlantis"), None);
+    }
+
+    #[test]
+    fn offset_at_tests() {
+        let zone = TimeZoneMention::Named(Tz::Europe__Paris);
+        let at = |m: u32, d: u32, h: u32| {
+            NaiveDate::from_ymd_opt(2021, m, d)
+                .unwrap()
+                .and_hms_opt(h, 30, 0)
+                .unwrap()
+        };
+        assert_eq!(
+            zone.offset_at(at(1, 5, 9)),
+            FixedOffset::east_opt(3600).unwrap()
+        );
+        assert_eq!(
+            zone.offset_at(at(7, 5, 9)),
+            FixedOffset::east_opt(7200).unwrap()
+        );
+        // 2:30am on March 28 was skipped in Paris
+        assert_eq!(
+            zone.offset_at(at(3, 28, 2)),
+            FixedOffset::east_opt(7200).unwrap()
+        );
+    }
+}
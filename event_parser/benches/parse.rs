@@ -0,0 +1,74 @@
+//! Parses a batch of event texts, run with `cargo bench -p event_parser`. Every pattern is compiled the first time it
+//! is used, so after the warm-up each text costs only the matching.
+
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use date_time_parser::{DateParser, TimeParser};
+use event_parser::{to_event_with_clock, FixedClock};
+
+/// Texts like the ones the parser is given, with dates, times, ranges, schedules and places.
+const EVENTS: [&str; 10] = [
+    "Dinner at 7pm",
+    "Lunch with Sam at Cafe Rio tomorrow at noon",
+    "Summer Camp 6/1-6/8",
+    "Standup every weekday at 9:30am",
+    "Flight to Boston June 5 7pm to June 6 2am",
+    "Dentist next Tuesday 3-4pm",
+    "Team offsite the weekend of June 12",
+    "Call mom in 2 hours",
+    "Pottery every other Thursday for 6 weeks",
+    "Workshop June 5, 6, and 7 from 10am",
+];
+
+/// Lines with no date or time in them, like most of a log or an email, which the prefilter skips.
+const LINES: [&str; 4] = [
+    "ERROR connection reset by peer",
+    "Please review the attached document",
+    "Thanks for the update, looks good",
+    "GET /index.html from client",
+];
+
+fn parse_events(c: &mut Criterion) {
+    let clock = FixedClock(
+        NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap(),
+    );
+    c.bench_function("to_event", |b| {
+        b.iter(|| {
+            for text in EVENTS {
+                black_box(to_event_with_clock(black_box(text), &clock));
+            }
+        })
+    });
+    c.bench_function("to_event without dates", |b| {
+        b.iter(|| {
+            for text in LINES {
+                black_box(to_event_with_clock(black_box(text), &clock));
+            }
+        })
+    });
+}
+
+fn parse_dates_and_times(c: &mut Criterion) {
+    let today = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap();
+    let now = today.and_hms_opt(8, 0, 0).unwrap().time();
+    c.bench_function("DateParser::parse_relative", |b| {
+        b.iter(|| {
+            for text in EVENTS {
+                black_box(DateParser::parse_relative(black_box(text), today));
+            }
+        })
+    });
+    c.bench_function("TimeParser::parse_relative", |b| {
+        b.iter(|| {
+            for text in EVENTS {
+                black_box(TimeParser::parse_relative(black_box(text), now));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, parse_events, parse_dates_and_times);
+criterion_main!(benches);
@@ -0,0 +1,288 @@
+//! Read when someone says they are free, like "I'm free 2-4pm Tuesday and Thursday morning", into time ranges and a
+//! `VFREEBUSY` component for scheduling assistants.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use date_time_parser::patterns::Pattern;
+use date_time_parser::{Clock, LocalClock};
+use icalendar::{Component, Property};
+use std::collections::BTreeMap;
+
+use crate::normalize::normalize;
+use crate::{part_of_day, to_start_end_expr, EventStartAndEndExpr, Parsers, PART_OF_DAY};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// A stretch of time from `start` up to `end`.
+pub struct TimeRange {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+/// What separates the times someone is free, e.g. the "and" of "Tuesday afternoon and Thursday morning".
+static SEPARATOR: Pattern = Pattern::new(r"(?i)\s*(,|;|\band\b|\bor\b)\s*");
+
+/// Parses `text` for the times someone says they are free, with respect to the local time. Returns them in order,
+/// with any that overlap joined.
+///
+/// Each part of the text, split at "and" and commas, is a time, a date or both. A date without a time is free all
+/// day, unless a later part gives a time, as in "Tuesday and Thursday 2-4pm". A time without a date is on the date
+/// before it, as in "Tuesday 9-10am and 2-4pm", or else the one after it or today.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::{free_times_with_clock, FixedClock, TimeRange};
+/// use chrono::NaiveDate;
+///
+/// // a Wednesday
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap();
+/// let free = free_times_with_clock("I'm free 2-4pm Friday and Saturday morning", &FixedClock(now));
+///
+/// let friday = NaiveDate::from_ymd_opt(2021, 3, 5).unwrap();
+/// let saturday = NaiveDate::from_ymd_opt(2021, 3, 6).unwrap();
+/// assert_eq!(
+///     free,
+///     vec![
+///         TimeRange { start: friday.and_hms_opt(14, 0, 0).unwrap(), end: friday.and_hms_opt(16, 0, 0).unwrap() },
+///         TimeRange { start: saturday.and_hms_opt(9, 0, 0).unwrap(), end: saturday.and_hms_opt(12, 0, 0).unwrap() },
+///     ]
+/// );
+/// ```
+pub fn free_times(text: &str) -> Vec<TimeRange> {
+    free_times_with_clock(text, &LocalClock)
+}
+
+/// Parses `text` for the times someone says they are free like [`free_times`](fn.free_times.html), with respect to
+/// the time given by `clock`.
+pub fn free_times_with_clock(text: &str, clock: &dyn Clock) -> Vec<TimeRange> {
+    free_times_at(text, clock.now(), &Parsers::default())
+}
+
+/// The dates and times found in one part of the text, either of which may be missing.
+struct Slot {
+    dates: Option<Vec<NaiveDate>>,
+    times: Option<(NaiveTime, NaiveTime)>,
+}
+
+fn free_times_at(text: &str, now: NaiveDateTime, parsers: &Parsers) -> Vec<TimeRange> {
+    let text = normalize(text);
+    let today = now.date();
+
+    let mut free = Vec::new();
+    let mut slots = Vec::new();
+    for part in SEPARATOR.split(&text) {
+        let expr = to_start_end_expr(part, now, parsers);
+        let hour = |t: NaiveTime| (t, t + parsers.duration);
+        let (dates, times) = match expr {
+            EventStartAndEndExpr::Unknown => (None, None),
+            EventStartAndEndExpr::Starts(t) => (None, Some(hour(t))),
+            EventStartAndEndExpr::StartsAndEnds(start, end) => (None, Some((start, end))),
+            EventStartAndEndExpr::StartsWithDate(t, d) => (Some(vec![d]), Some(hour(t))),
+            EventStartAndEndExpr::StartsAndEndsWithDate(start, end, d) => {
+                (Some(vec![d]), Some((start, end)))
+            }
+            EventStartAndEndExpr::StartsAndEndsOn(start, end, dates) => {
+                (Some(dates), Some((start, end)))
+            }
+            EventStartAndEndExpr::AllDay(_)
+            | EventStartAndEndExpr::AllDayStartsAndEnds(_, _)
+            | EventStartAndEndExpr::AllDayOn(_) => (Some(expr.dates(today)), None),
+            EventStartAndEndExpr::StartsAndEndsAt(start, end) => {
+                free.push(TimeRange { start, end });
+                continue;
+            }
+        };
+        // a part of the day stands for the hours it covers rather than the time it usually means
+        let times = match PART_OF_DAY.captures(part) {
+            Some(caps) => Some(part_of_day(&caps["part"])),
+            None => times,
+        };
+        if dates.is_some() || times.is_some() {
+            slots.push(Slot { dates, times });
+        }
+    }
+
+    for (i, slot) in slots.iter().enumerate() {
+        let dates = slot
+            .dates
+            .clone()
+            .or_else(|| slots[..i].iter().rev().find_map(|s| s.dates.clone()))
+            .or_else(|| slots[i + 1..].iter().find_map(|s| s.dates.clone()))
+            .unwrap_or_else(|| vec![today]);
+        let times = slot
+            .times
+            .or_else(|| slots[i + 1..].iter().find_map(|s| s.times));
+        for d in dates {
+            free.push(match times {
+                Some((start, end)) => {
+                    let start = d.and_time(start);
+                    let mut end = d.and_time(end);
+                    // a range past midnight, like "10pm-1am", ends the next day
+                    if end <= start {
+                        end += Duration::days(1);
+                    }
+                    TimeRange { start, end }
+                }
+                None => TimeRange {
+                    start: d.and_hms_opt(0, 0, 0).unwrap(),
+                    end: d.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap(),
+                },
+            });
+        }
+    }
+
+    free.sort();
+    let mut joined: Vec<TimeRange> = Vec::with_capacity(free.len());
+    for range in free {
+        match joined.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => joined.push(range),
+        }
+    }
+    joined
+}
+
+#[derive(Debug, Default)]
+/// A `VFREEBUSY` component [(RFC 5545, Section 3.6.4)](https://tools.ietf.org/html/rfc5545#section-3.6.4) listing
+/// times someone is free. Like an `Event`, it is written out with `to_string`.
+pub struct FreeBusy {
+    properties: BTreeMap<String, Property>,
+    multi_properties: Vec<Property>,
+}
+
+impl FreeBusy {
+    /// Creates a `VFREEBUSY` component with a `FREEBUSY;FBTYPE=FREE` property for each of `free`, spanning from the
+    /// first of them to the last.
+    ///
+    /// # Example
+    /// ```
+    /// use event_parser::{free_times_with_clock, FixedClock, FreeBusy};
+    /// use chrono::NaiveDate;
+    /// use icalendar::Component;
+    ///
+    /// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap();
+    /// let free = free_times_with_clock("free 2-4pm tomorrow", &FixedClock(now));
+    ///
+    /// assert!(FreeBusy::new(&free)
+    ///     .to_string()
+    ///     .contains("FREEBUSY;FBTYPE=FREE:20210304T140000Z/20210304T160000Z"));
+    /// ```
+    pub fn new(free: &[TimeRange]) -> FreeBusy {
+        let mut fb = FreeBusy::default();
+        let format = |dt: NaiveDateTime| dt.format("%Y%m%dT%H%M%SZ").to_string();
+        if let (Some(first), Some(end)) = (
+            free.iter().map(|r| r.start).min(),
+            free.iter().map(|r| r.end).max(),
+        ) {
+            fb.add_property("DTSTART", &format(first));
+            fb.add_property("DTEND", &format(end));
+        }
+        for range in free {
+            let period = format!("{}/{}", format(range.start), format(range.end));
+            fb.append_multi_property(
+                Property::new("FREEBUSY", &period)
+                    .add_parameter("FBTYPE", "FREE")
+                    .done(),
+            );
+        }
+        fb
+    }
+}
+
+impl Component for FreeBusy {
+    fn component_kind() -> &'static str {
+        "VFREEBUSY"
+    }
+
+    fn properties(&self) -> &BTreeMap<String, Property> {
+        &self.properties
+    }
+
+    fn multi_properties(&self) -> &Vec<Property> {
+        &self.multi_properties
+    }
+
+    fn append_property(&mut self, property: Property) -> &mut Self {
+        self.properties.insert(property.key().to_owned(), property);
+        self
+    }
+
+    fn append_multi_property(&mut self, property: Property) -> &mut Self {
+        self.multi_properties.push(property);
+        self
+    }
+}
+
+#[cfg(test)]
+mod availability_tests {
+    use super::{free_times_at, FreeBusy, TimeRange};
+    use crate::Parsers;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use icalendar::Component;
+
+    /// Wednesday March 3 2021 at 8am
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+    }
+
+    fn range(day: u32, start: (u32, u32), end_day: u32, end: (u32, u32)) -> TimeRange {
+        TimeRange {
+            start: NaiveDate::from_ymd_opt(2021, 3, day)
+                .unwrap()
+                .and_hms_opt(start.0, start.1, 0)
+                .unwrap(),
+            end: NaiveDate::from_ymd_opt(2021, 3, end_day)
+                .unwrap()
+                .and_hms_opt(end.0, end.1, 0)
+                .unwrap(),
+        }
+    }
+
+    fn free(text: &str) -> Vec<TimeRange> {
+        free_times_at(text, now(), &Parsers::default())
+    }
+
+    #[test]
+    fn free_times_tests() {
+        assert_eq!(
+            free("I'm free 2-4pm Friday and Saturday morning"),
+            vec![range(5, (14, 0), 5, (16, 0)), range(6, (9, 0), 6, (12, 0))]
+        );
+        assert_eq!(
+            free("Thursday and Friday 10-11:30am work for me"),
+            vec![
+                range(4, (10, 0), 4, (11, 30)),
+                range(5, (10, 0), 5, (11, 30))
+            ]
+        );
+        assert_eq!(
+            free("Friday 9-10am and 2-3pm"),
+            vec![range(5, (9, 0), 5, (10, 0)), range(5, (14, 0), 5, (15, 0))]
+        );
+        assert_eq!(
+            free("free all day tomorrow"),
+            vec![range(4, (0, 0), 5, (0, 0))]
+        );
+        assert_eq!(
+            free("1-3pm or 2-5pm tomorrow"),
+            vec![range(4, (13, 0), 4, (17, 0))]
+        );
+        assert_eq!(free("Sorry, I'm busy"), vec![]);
+    }
+
+    #[test]
+    fn free_busy_tests() {
+        let fb = FreeBusy::new(&free("2-4pm Friday and Saturday morning")).to_string();
+        assert!(fb.starts_with("BEGIN:VFREEBUSY\r\n"));
+        assert!(fb.contains("DTSTART:20210305T140000Z\r\n"));
+        assert!(fb.contains("DTEND:20210306T120000Z\r\n"));
+        assert!(fb.contains("FREEBUSY;FBTYPE=FREE:20210305T140000Z/20210305T160000Z\r\n"));
+        assert!(fb.contains("FREEBUSY;FBTYPE=FREE:20210306T090000Z/20210306T120000Z\r\n"));
+        assert!(fb.ends_with("END:VFREEBUSY\r\n"));
+    }
+}
@@ -0,0 +1,82 @@
+//! Tell events apart from deadlines and reminders by how they are phrased, so apps can send each to a calendar, a task
+//! list or a notification.
+
+use date_time_parser::patterns::Pattern;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What kind of thing a text describes.
+pub enum EventKind {
+    /// Something that happens at a time, like a meeting or a dinner
+    Event,
+
+    /// Something to be done before a time, e.g. "Report due Friday" or "Send invoices by 5pm"
+    Deadline,
+
+    /// Something to be told about at a time, e.g. "Remind me to call Mom at 6"
+    Reminder,
+}
+
+static REMINDER: Pattern = Pattern::new(r"(?i)\b(remind(er|ers)?|don'?t\sforget|remember\sto)\b");
+static DEADLINE: Pattern = Pattern::new(r"(?i)\b(due|deadline|submit|turn\sin|hand\sin)\b");
+// "by" only sets a deadline when a date or time follows it, unlike "Walk by the river"
+static BY: Pattern = Pattern::new(
+    r"(?i)\bby\s(the\s)?(end\sof|eod|eow|noon|midnight|tonight|today|tomorrow|tmrw|next|this|\d|(mon|tue|wed|thu|fri|sat|sun|jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\b)",
+);
+
+/// Returns what kind of thing `text` describes from its phrasing: a [`Reminder`](enum.EventKind.html#variant.Reminder)
+/// if it asks to be reminded, a [`Deadline`](enum.EventKind.html#variant.Deadline) if something is due or to be done
+/// by a date or time, and an [`Event`](enum.EventKind.html#variant.Event) otherwise.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be classified.
+///
+/// # Example
+/// ```
+/// use event_parser::{classify, EventKind};
+///
+/// assert_eq!(classify("Meet Sam for lunch at noon"), EventKind::Event);
+/// assert_eq!(classify("Essay due Friday"), EventKind::Deadline);
+/// assert_eq!(classify("Remind me to call Mom at 6"), EventKind::Reminder);
+/// ```
+pub fn classify(text: &str) -> EventKind {
+    if REMINDER.is_match(text) {
+        EventKind::Reminder
+    } else if DEADLINE.is_match(text) || BY.is_match(text) {
+        EventKind::Deadline
+    } else {
+        EventKind::Event
+    }
+}
+
+#[cfg(test)]
+mod classify_tests {
+    use super::{classify, EventKind};
+
+    #[test]
+    fn classify_tests() {
+        assert_eq!(classify("Meet with Alex tomorrow at 3"), EventKind::Event);
+        assert_eq!(classify("Dinner at 7pm"), EventKind::Event);
+        assert_eq!(classify("Walk by the river Saturday"), EventKind::Event);
+        assert_eq!(classify("Tax return due April 15"), EventKind::Deadline);
+        assert_eq!(classify("Send invoices by 5pm"), EventKind::Deadline);
+        assert_eq!(classify("Finish slides by Friday"), EventKind::Deadline);
+        assert_eq!(classify("Pay rent by the 1st"), EventKind::Deadline);
+        assert_eq!(
+            classify("Project deadline next Monday"),
+            EventKind::Deadline
+        );
+        assert_eq!(
+            classify("Remind me to take out the trash at 8"),
+            EventKind::Reminder
+        );
+        assert_eq!(
+            classify("Don't forget Mom's birthday June 5"),
+            EventKind::Reminder
+        );
+        assert_eq!(
+            classify("Reminder: homework due Friday"),
+            EventKind::Reminder
+        );
+    }
+}
@@ -0,0 +1,493 @@
+//! Load how text is parsed into events from a TOML or JSON file, so services can ship parser behavior as configuration
+//! rather than code. Enabled with the `config` feature.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use date_time_parser::{
+    Clock, DateParser, Direction, HolidayProvider, LocalClock, MeridiemPolicy, Rounding, TimeParser,
+};
+use icalendar::Event;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::highlight::highlight_with_parsers;
+use crate::{parse_at, Confidence, Parsers, Segment};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+/// Settings for parsing text into events. Every field is optional in a file, and missing ones take the defaults
+/// [`to_event`](fn.to_event.html) uses.
+///
+/// # Example
+/// ```
+/// use event_parser::ParserConfig;
+///
+/// let config = ParserConfig::from_toml(r#"
+///     locale = "en_GB"
+///     bias = "past"
+///     meridiem = "guess"
+///     rounding = "nearest15"
+///     default_duration = 30
+///
+///     [casual_times]
+///     morning = "08:00"
+///     lunch = "12:30"
+///
+///     [durations]
+///     standup = 15
+///
+///     [rules]
+///     location = false
+/// "#).unwrap();
+///
+/// assert_eq!(config.default_duration, 30);
+/// assert!(config.rules.recurrence);
+/// assert!(!config.rules.location);
+/// ```
+pub struct ParserConfig {
+    /// The locale slash dates like "5/6" are read in, e.g. "en_GB" for day first. Defaults to "en_US".
+    pub locale: String,
+    /// Which way dates without a year or week are read from today: "future" (the default) or "past".
+    pub bias: Direction,
+    /// Whether a weekday or time that has gone by, like "Monday" on a Wednesday or "at 7am" at noon, is read as its next
+    /// occurrence. On by default; when off, it is this week's or today's.
+    pub prefer_future: bool,
+    /// How an hour without am or pm, like "at 7", is read: "guess" (the default), "am" or "pm".
+    pub meridiem: MeridiemPolicy,
+    /// How times worked out from now, like "in 2 hours", are rounded: "exact" (the default), "nearest5",
+    /// "nearest15" or "nearest30".
+    pub rounding: Rounding,
+    /// Phrases standing for a time of day, like "lunch" for "12:30", used when the text gives no clock time. These
+    /// take precedence over built-in phrases like "morning".
+    pub casual_times: BTreeMap<String, NaiveTime>,
+    /// Whether British colloquial times are read, like "half 7" for 7:30 and "20 to 8" for 7:40. Off by default, as
+    /// "10 to 12" otherwise reads as a range of hours.
+    pub british_times: bool,
+    /// Whether dated birthdays and anniversaries, like "Mom's birthday April 5", become all-day events repeating every
+    /// year. Off by default.
+    pub yearly_occasions: bool,
+    /// Whether the names of people an event is with, like "Alice" of "Lunch with Alice", stay in the summary. On by
+    /// default. Email addresses are always left out.
+    pub names_in_summary: bool,
+    /// How long events without an end or a duration last, in minutes. Defaults to 60.
+    pub default_duration: u32,
+    /// How long events mentioning a word last, in minutes, e.g. 15 for "standup", when they give no end or duration.
+    pub durations: BTreeMap<String, u32>,
+    /// How sure the parser has to be of a time to keep it: "low" (the default) keeps every time, "medium" drops bare
+    /// numbers like the "5" of "Meet in room 5", and "high" also drops hours without am or pm, like "at 7".
+    pub min_confidence: Confidence,
+    /// Which parts of the text are read.
+    pub rules: Rules,
+}
+
+impl Default for ParserConfig {
+    fn default() -> ParserConfig {
+        ParserConfig {
+            locale: "en_US".to_owned(),
+            bias: Direction::default(),
+            prefer_future: true,
+            meridiem: MeridiemPolicy::default(),
+            rounding: Rounding::default(),
+            casual_times: BTreeMap::new(),
+            british_times: false,
+            yearly_occasions: false,
+            names_in_summary: true,
+            default_duration: 60,
+            durations: BTreeMap::new(),
+            min_confidence: Confidence::default(),
+            rules: Rules::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+/// Which parts of the text a [`ParserConfig`](struct.ParserConfig.html) reads. All are on by default.
+pub struct Rules {
+    /// Repeating schedules like "every Monday", which add an `RRULE`.
+    pub recurrence: bool,
+    /// Places like "at Cafe Rio", which set the `LOCATION` and are left out of the summary.
+    pub location: bool,
+    /// Holiday names like "Thanksgiving".
+    pub holidays: bool,
+    /// People named after "with" and email addresses, like "Lunch with Alice and bob@example.com", which add an
+    /// `ATTENDEE` each.
+    pub attendees: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            recurrence: true,
+            location: true,
+            holidays: true,
+            attendees: true,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Why a [`ParserConfig`](struct.ParserConfig.html) could not be loaded.
+pub enum ConfigError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The TOML is malformed or does not match the configuration.
+    Toml(toml::de::Error),
+    /// The JSON is malformed or does not match the configuration.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "could not read config: {}", e),
+            ConfigError::Toml(e) => write!(f, "invalid TOML config: {}", e),
+            ConfigError::Json(e) => write!(f, "invalid JSON config: {}", e),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(e) => Some(e),
+            ConfigError::Toml(e) => Some(e),
+            ConfigError::Json(e) => Some(e),
+        }
+    }
+}
+
+impl ParserConfig {
+    /// Reads a configuration from TOML.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The TOML, with any of the fields of [`ParserConfig`](struct.ParserConfig.html) at the top level
+    pub fn from_toml(text: &str) -> Result<ParserConfig, ConfigError> {
+        toml::from_str(text).map_err(ConfigError::Toml)
+    }
+
+    /// Reads a configuration from JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A JSON object with any of the fields of [`ParserConfig`](struct.ParserConfig.html)
+    ///
+    /// # Example
+    /// ```
+    /// use event_parser::{Direction, ParserConfig};
+    ///
+    /// let config = ParserConfig::from_json(r#"{ "bias": "past", "durations": { "coffee": 30 } }"#).unwrap();
+    ///
+    /// assert_eq!(config.bias, Direction::Past);
+    /// assert_eq!(config.durations["coffee"], 30);
+    /// ```
+    pub fn from_json(text: &str) -> Result<ParserConfig, ConfigError> {
+        serde_json::from_str(text).map_err(ConfigError::Json)
+    }
+
+    /// Reads a configuration from a file, as JSON if its extension is `.json` and as TOML otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Where the file is
+    pub fn load(path: impl AsRef<Path>) -> Result<ParserConfig, ConfigError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        match path.extension() {
+            Some(extension) if extension == "json" => ParserConfig::from_json(&text),
+            _ => ParserConfig::from_toml(&text),
+        }
+    }
+
+    /// Returns the parsers these settings describe.
+    pub(crate) fn parsers(&self) -> Parsers {
+        let mut date = DateParser::new()
+            .with_locale(&self.locale)
+            .with_direction(self.bias)
+            .with_prefer_future(self.prefer_future);
+        if !self.rules.holidays {
+            date = date.with_holidays(NoHolidays);
+        }
+
+        let mut time = TimeParser::new()
+            .with_meridiem_policy(self.meridiem)
+            .with_rounding(self.rounding)
+            .with_british_times(self.british_times);
+        for (phrase, at) in &self.casual_times {
+            time = time.with_casual_time(&words_pattern(phrase), *at);
+        }
+
+        Parsers {
+            date,
+            time,
+            duration: Duration::minutes(self.default_duration as i64),
+            durations: self
+                .durations
+                .iter()
+                .map(|(word, mins)| {
+                    let word = Regex::new(&format!(r"(?i)\b{}\b", regex::escape(word))).unwrap();
+                    (word, Duration::minutes(*mins as i64))
+                })
+                .collect(),
+            recurrence: self.rules.recurrence,
+            location: self.rules.location,
+            attendees: self.rules.attendees,
+            names_in_summary: self.names_in_summary,
+            occasions: self.yearly_occasions,
+            all_day: true,
+            min_confidence: self.min_confidence,
+            zone: None,
+        }
+    }
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), with the settings in `config`.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `config` - How to parse it.
+///
+/// # Example
+/// ```
+/// use event_parser::{to_event_with_config, ParserConfig};
+/// use icalendar::Component;
+///
+/// let config = ParserConfig::from_toml("[rules]\nlocation = false").unwrap();
+/// let event = to_event_with_config("Lunch at Cafe Rio at noon", &config);
+///
+/// assert!(event.properties().get("LOCATION").is_none());
+/// ```
+pub fn to_event_with_config(text: &str, config: &ParserConfig) -> Event {
+    parse_at(text, LocalClock.now(), &config.parsers()).event
+}
+
+/// Splits `text` into labeled segments like [`highlight`](fn.highlight.html), with respect to `now` and the settings
+/// in `config`, so the pieces match the event [`to_event_with_config`](fn.to_event_with_config.html) reads.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `now` - When the text is read, which relative dates like "tomorrow" are taken from.
+/// * `config` - How to parse it.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use event_parser::{highlight_with, Label, ParserConfig};
+///
+/// let config = ParserConfig::from_toml("[rules]\nlocation = false").unwrap();
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap();
+/// let segments = highlight_with("Lunch at Cafe Rio at noon", now, &config);
+///
+/// assert!(segments.iter().all(|s| s.label != Label::Location));
+/// ```
+pub fn highlight_with<'a>(
+    text: &'a str,
+    now: NaiveDateTime,
+    config: &ParserConfig,
+) -> Vec<Segment<'a>> {
+    highlight_with_parsers(text, now, &config.parsers())
+}
+
+/// Returns a case-insensitive pattern matching `phrase` word for word, however its words are spaced.
+fn words_pattern(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(r"\s+")
+}
+
+/// Holidays for when none should be recognized.
+struct NoHolidays;
+
+impl HolidayProvider for NoHolidays {
+    fn holiday(&self, _: &str, _: i32) -> Option<NaiveDate> {
+        None
+    }
+
+    fn is_day_off(&self, _: NaiveDate) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod config_tests {
+    use super::{highlight_with, ConfigError, ParserConfig, Rules};
+    use crate::{parse_at, Confidence, Label};
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    use date_time_parser::{Direction, MeridiemPolicy, Rounding};
+    use icalendar::{Component, Event};
+
+    #[test]
+    fn from_toml_tests() {
+        let config = ParserConfig::from_toml(
+            r#"
+            locale = "en_GB"
+            bias = "past"
+            prefer_future = false
+            meridiem = "am"
+            rounding = "nearest30"
+            default_duration = 30
+
+            [casual_times]
+            "first thing" = "08:30"
+
+            [durations]
+            standup = 15
+
+            [rules]
+            recurrence = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.locale, "en_GB");
+        assert_eq!(config.bias, Direction::Past);
+        assert!(!config.prefer_future);
+        assert_eq!(config.meridiem, MeridiemPolicy::Am);
+        assert_eq!(config.rounding, Rounding::Nearest30);
+        assert_eq!(config.casual_times["first thing"], hm(8, 30));
+        assert_eq!(config.durations["standup"], 15);
+        assert_eq!(
+            config.rules,
+            Rules {
+                recurrence: false,
+                location: true,
+                holidays: true,
+                attendees: true
+            }
+        );
+
+        assert_eq!(
+            ParserConfig::from_toml("").unwrap(),
+            ParserConfig::default()
+        );
+        assert!(matches!(
+            ParserConfig::from_toml("bias = \"sideways\""),
+            Err(ConfigError::Toml(_))
+        ));
+    }
+
+    #[test]
+    fn from_json_tests() {
+        let config =
+            ParserConfig::from_json(r#"{ "meridiem": "pm", "rules": { "holidays": false } }"#)
+                .unwrap();
+        assert_eq!(config.meridiem, MeridiemPolicy::Pm);
+        assert!(!config.rules.holidays);
+        assert!(matches!(
+            ParserConfig::from_json("{ \"default_duration\": -5 }"),
+            Err(ConfigError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn parse_with_config_tests() {
+        let config = ParserConfig::from_toml(
+            r#"
+            locale = "en_GB"
+            meridiem = "am"
+            british_times = true
+            yearly_occasions = true
+            default_duration = 30
+            min_confidence = "medium"
+
+            [casual_times]
+            "first thing" = "08:30"
+
+            [durations]
+            standup = 15
+
+            [rules]
+            recurrence = false
+            location = false
+            holidays = false
+            "#,
+        )
+        .unwrap();
+        let parse = |text: &str| parse_at(text, now(), &config.parsers()).event;
+
+        let e = parse("Run at 7 5/6");
+        assert_eq!(value(&e, "DTSTART"), "20210605T070000Z");
+        assert_eq!(value(&e, "DTEND"), "20210605T073000Z");
+        let e = parse("Standup first thing tomorrow");
+        assert_eq!(value(&e, "DTSTART"), "20210304T083000Z");
+        assert_eq!(value(&e, "DTEND"), "20210304T084500Z");
+        let e = parse("Lunch at Cafe Rio every Monday at noon");
+        assert!(e.properties().get("RRULE").is_none());
+        assert!(e.properties().get("LOCATION").is_none());
+        assert!(value(&e, "SUMMARY").contains("Cafe Rio"));
+        let e = parse("Drinks at half 7 tomorrow");
+        assert_eq!(value(&e, "SUMMARY"), "Drinks");
+        assert_eq!(value(&e, "DTSTART"), "20210304T073000Z");
+        let e = parse("Train 20 to 8");
+        assert_eq!(value(&e, "DTSTART"), "20210303T074000Z");
+        assert_eq!(value(&e, "DTEND"), "20210303T081000Z");
+        let e = parse("Dad's birthday 5/6");
+        assert_eq!(value(&e, "DTSTART"), "20210605");
+        assert_eq!(value(&e, "RRULE"), "FREQ=YEARLY");
+        let e = parse("Party on Thanksgiving");
+        assert_eq!(value(&e, "DTSTART"), "20210303");
+        let e = parse("Meet in room 5 on 5/6");
+        assert_eq!(value(&e, "DTSTART"), "20210605");
+        assert_eq!(config.min_confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn highlight_with_tests() {
+        let config =
+            ParserConfig::from_toml("[rules]\nrecurrence = false\nlocation = false").unwrap();
+        let labeled = |text, config: &ParserConfig| {
+            highlight_with(text, now(), config)
+                .into_iter()
+                .map(|s| (s.label, s.text))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            labeled("Lunch at Cafe Rio every Monday", &ParserConfig::default()),
+            vec![
+                (Label::Summary, "Lunch"),
+                (Label::Plain, " at "),
+                (Label::Location, "Cafe Rio"),
+                (Label::Plain, " "),
+                (Label::Date, "every Monday"),
+            ]
+        );
+        // the place stays in the summary and "every" is not a schedule, as in the event these settings read
+        let summary = parse_at("Lunch at Cafe Rio every Monday", now(), &config.parsers()).event;
+        assert_eq!(value(&summary, "SUMMARY"), "Lunch Cafe Rio every");
+        assert_eq!(
+            labeled("Lunch at Cafe Rio every Monday", &config),
+            vec![
+                (Label::Summary, "Lunch"),
+                (Label::Plain, " at "),
+                (Label::Summary, "Cafe Rio every"),
+                (Label::Plain, " "),
+                (Label::Date, "Monday"),
+            ]
+        );
+    }
+
+    fn value<'a>(e: &'a Event, key: &str) -> &'a str {
+        e.properties()[key].value()
+    }
+
+    fn hm(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(6, 0, 0)
+            .unwrap()
+    }
+}
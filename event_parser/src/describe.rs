@@ -0,0 +1,116 @@
+//! Describe an `Event` in words, the reverse of parsing one.
+
+use chrono::NaiveDateTime;
+use icalendar::{Component, Event};
+
+use crate::ical_datetime;
+
+/// Describes `e` in English the parser reads back into the same event, e.g. "Dinner at Cafe Rio on 6/5/2022 from
+/// 7:00pm to 8:30pm". Dates are written month first, with their year.
+///
+/// # Arguments
+///
+/// * `e` - An [iCalendar Event](../icalendar/struct.Event.html) to be described.
+///
+/// # Example
+/// ```
+/// use event_parser::describe_event;
+/// use chrono::{NaiveDate, TimeZone, Utc};
+/// use icalendar::{Component, Event};
+///
+/// let day = NaiveDate::from_ymd_opt(2022, 6, 5).unwrap();
+/// let event = Event::new()
+///     .summary("Dinner")
+///     .starts(Utc.from_utc_datetime(&day.and_hms_opt(19, 0, 0).unwrap()))
+///     .ends(Utc.from_utc_datetime(&day.and_hms_opt(20, 30, 0).unwrap()))
+///     .done();
+///
+/// assert_eq!(describe_event(&event), "Dinner on 6/5/2022 from 7:00pm to 8:30pm");
+/// ```
+pub fn describe_event(e: &Event) -> String {
+    let mut words = Vec::new();
+    if let Some(summary) = e.properties().get("SUMMARY") {
+        words.push(summary.value().to_owned());
+    }
+    if let Some(location) = e.properties().get("LOCATION") {
+        words.push(format!("at {}", location.value()));
+    }
+
+    let all_day = e
+        .properties()
+        .get("DTSTART")
+        .is_some_and(|p| p.value().len() == 8);
+    let date = |dt: NaiveDateTime| dt.format("%-m/%-d/%Y").to_string();
+    let time = |dt: NaiveDateTime| dt.format("%-I:%M%P").to_string();
+    match (ical_datetime(e, "DTSTART"), ical_datetime(e, "DTEND")) {
+        (Some(start), Some(end)) if all_day && start.date() < end.date() => {
+            words.push(format!("from {} to {}", date(start), date(end)))
+        }
+        (Some(start), _) if all_day => words.push(format!("on {}", date(start))),
+        (Some(start), Some(end)) if start.date() == end.date() => words.push(format!(
+            "on {} from {} to {}",
+            date(start),
+            time(start),
+            time(end)
+        )),
+        (Some(start), Some(end)) => words.push(format!(
+            "from {} {} to {} {}",
+            date(start),
+            time(start),
+            date(end),
+            time(end)
+        )),
+        (Some(start), None) => words.push(format!("on {} at {}", date(start), time(start))),
+        (None, _) => {}
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod describe_tests {
+    use super::describe_event;
+    use crate::{parse_at, Parsers};
+    use chrono::NaiveDate;
+    use icalendar::Component;
+
+    #[test]
+    fn describe_event_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let describe = |text: &str| describe_event(&parse_at(text, now, &Parsers::default()).event);
+
+        assert_eq!(
+            describe("Lunch at Cafe Rio tomorrow at noon"),
+            "Lunch at Cafe Rio on 3/4/2021 from 12:00pm to 1:00pm"
+        );
+        assert_eq!(
+            describe("Vacation 6/1-6/8"),
+            "Vacation from 6/1/2021 to 6/8/2021"
+        );
+        assert_eq!(describe("Dentist June 5"), "Dentist on 6/5/2021");
+        assert_eq!(
+            describe("Flight June 5 10pm to June 6 6am"),
+            "Flight from 6/5/2021 10:00pm to 6/6/2021 6:00am"
+        );
+
+        for text in [
+            "Lunch at Cafe Rio tomorrow at noon",
+            "Vacation 6/1-6/8",
+            "Flight June 5 10pm to June 6 6am",
+        ] {
+            let event = parse_at(text, now, &Parsers::default()).event;
+            let again = parse_at(&describe_event(&event), now, &Parsers::default()).event;
+            for key in ["SUMMARY", "LOCATION", "DTSTART", "DTEND"] {
+                assert_eq!(
+                    event.properties().get(key).map(|p| p.value()),
+                    again.properties().get(key).map(|p| p.value()),
+                    "{} of {:?}",
+                    key,
+                    text
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Parse events strictly, failing on text the parser would otherwise read as best it can, so callers can ask the user
+//! instead of saving a wrong event.
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use date_time_parser::{Clock, LocalClock};
+use icalendar::Event;
+use std::error::Error;
+use std::fmt;
+
+use crate::{ical_datetime, parse_checked, MissReason, ParseWarning, Parsers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Why [`try_to_event`](fn.try_to_event.html) could not parse an event.
+pub enum EventParseError {
+    /// No date or time was found, so [`to_event`](fn.to_event.html) would make the event all day today.
+    NoDateOrTime,
+
+    /// The text gives dates the event cannot fall on together, e.g. "June 5 or June 12", given as the date the event
+    /// would be on and the date left out.
+    ConflictingDates(NaiveDate, NaiveDate),
+
+    /// The only time found is a bare number, e.g. the "5" of "Meet in room 5", so the time the event would start at
+    /// is a guess.
+    AmbiguousTime(NaiveTime),
+}
+
+impl fmt::Display for EventParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventParseError::NoDateOrTime => write!(f, "no date or time was found"),
+            EventParseError::ConflictingDates(used, other) => write!(
+                f,
+                "the event could be on {} or {}",
+                used.format("%B %-d %Y"),
+                other.format("%B %-d %Y")
+            ),
+            EventParseError::AmbiguousTime(time) => write!(
+                f,
+                "{} was guessed from a number that may not be a time",
+                time.format("%-I:%M%P")
+            ),
+        }
+    }
+}
+
+impl Error for EventParseError {}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), failing instead of guessing when the text has
+/// no date or time, gives dates that conflict or has no time but a bare number.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::{try_to_event, EventParseError};
+///
+/// assert!(try_to_event("Dinner at 7pm").is_ok());
+/// assert_eq!(try_to_event("Write the quarterly report").unwrap_err(), EventParseError::NoDateOrTime);
+/// ```
+pub fn try_to_event(text: &str) -> Result<Event, EventParseError> {
+    try_parse_at(text, LocalClock.now(), &Parsers::default())
+}
+
+/// Parses `text` like [`try_to_event`] with respect to `now`, finding dates and times with `parsers`.
+fn try_parse_at(
+    text: &str,
+    now: NaiveDateTime,
+    parsers: &Parsers,
+) -> Result<Event, EventParseError> {
+    let (outcome, miss) = parse_checked(text, now, parsers);
+    if miss == Some(MissReason::NoDateOrTime) {
+        return Err(EventParseError::NoDateOrTime);
+    }
+    let ignored = outcome.warnings.iter().find_map(|w| match w {
+        ParseWarning::IgnoredDate(d) => Some(*d),
+        _ => None,
+    });
+    if let Some(other) = ignored {
+        let used = ical_datetime(&outcome.event, "DTSTART").map_or(other, |dt| dt.date());
+        return Err(EventParseError::ConflictingDates(used, other));
+    }
+    let guessed = outcome.warnings.iter().find_map(|w| match w {
+        ParseWarning::AmbiguousMeridiem(t) => Some(*t),
+        _ => None,
+    });
+    match (miss, guessed) {
+        (Some(MissReason::LowConfidence), Some(time)) => Err(EventParseError::AmbiguousTime(time)),
+        _ => Ok(outcome.event),
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::{try_parse_at, EventParseError};
+    use crate::Parsers;
+    use chrono::{NaiveDate, NaiveTime};
+    use icalendar::Component;
+
+    #[test]
+    fn try_to_event_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let parse = |text: &str| try_parse_at(text, now, &Parsers::default());
+        let date = |m: u32, d: u32| NaiveDate::from_ymd_opt(2021, m, d).unwrap();
+
+        assert_eq!(
+            parse("Dinner at 7pm").unwrap().properties()["SUMMARY"].value(),
+            "Dinner"
+        );
+        assert_eq!(
+            parse("Dentist June 5").unwrap().properties()["DTSTART"].value(),
+            "20210605"
+        );
+        assert!(parse("Dinner at 7").is_ok());
+
+        assert_eq!(
+            parse("Write the quarterly report").unwrap_err(),
+            EventParseError::NoDateOrTime
+        );
+        assert_eq!(
+            parse("Pottery June 5 or June 12").unwrap_err(),
+            EventParseError::ConflictingDates(date(6, 5), date(6, 12))
+        );
+        assert_eq!(
+            parse("Review in room 5").unwrap_err(),
+            EventParseError::AmbiguousTime(NaiveTime::from_hms_opt(17, 0, 0).unwrap())
+        );
+        assert_eq!(
+            EventParseError::ConflictingDates(date(6, 5), date(6, 12)).to_string(),
+            "the event could be on June 5 2021 or June 12 2021"
+        );
+    }
+}
@@ -0,0 +1,336 @@
+//! Find every date, time, range, duration, recurrence and location in a text in one call.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use date_time_parser::patterns::{
+    Pattern, CLOCK_TIME, DATE_MENTION, DURATION, ISO_WEEK, MILITARY_TIME, NTH_WEEKDAY_OF_MONTH,
+    ORDINAL_DATE, PERIOD, SPAN,
+};
+use date_time_parser::{
+    Clock, DateParser, DurationParser, LocalClock, RecurParser, Recurrence, RecurrenceEnd, TimeParser,
+};
+use std::ops::Range;
+
+use crate::range::ranges;
+use crate::{propagate_meridiem, Parsers};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// What an [`Entity`](struct.Entity.html) is, with its value.
+pub enum EntityKind {
+    /// A date, e.g. "June 5" or "next Friday"
+    Date(NaiveDate),
+
+    /// A time of day, e.g. "7pm" or "noon"
+    Time(NaiveTime),
+
+    /// A range of dates, e.g. "6/1-6/8" or "all week"
+    DateRange(NaiveDate, NaiveDate),
+
+    /// A range of times, e.g. "9am-5pm"
+    TimeRange(NaiveTime, NaiveTime),
+
+    /// How long something lasts, e.g. "1h30m"
+    Duration(Duration),
+
+    /// A repeating schedule, e.g. "every other week"
+    Recurrence(Recurrence),
+
+    /// When a repeating schedule stops, e.g. "for two weeks"
+    RecurrenceEnd(RecurrenceEnd),
+
+    /// A place, e.g. "Blue Bottle" in "Coffee at Blue Bottle"
+    Location(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Something recognized in a text, and where it is.
+pub struct Entity {
+    /// What was recognized.
+    pub kind: EntityKind,
+    /// Where it is in the text, in bytes.
+    pub span: Range<usize>,
+}
+
+static WEEKEND_OF: Pattern =
+    Pattern::new(r"(?i)\b(the\s)?weekend\sof\s(\w+\.?\s\d{1,2}(st|nd|rd|th)?|\d{1,2}/\d{1,2})\b");
+static WEEK_OF: Pattern =
+    Pattern::new(r"(?i)\b(the\s)?week\sof\s(\w+\.?\s\d{1,2}(st|nd|rd|th)?|\d{1,2}/\d{1,2})\b");
+static RELATIVE_DAY: Pattern = Pattern::new(
+    r"(?i)\b((the\s)?(day\safter\stomorrow|day\sbefore\syesterday|night\sbefore\slast)|today|tomorrow|yesterday|last\snight|in\s\d{1,3}\s(days?|months?)|(next|last|this)\smonth)\b",
+);
+static WEEKDAY_PHRASE: Pattern = Pattern::new(
+    r"(?i)\b((next|last|this\spast|this)\s)?(monday|mon|tuesday|tues|tue|wednesday|weds|wed|thursday|thurs|thur|thu|friday|fri|saturday|sat|sunday|sun)\b",
+);
+static RELATIVE_TIME: Pattern = Pattern::new(
+    r"(?i)\bin\s(an?|\w+|\d+(\.\d+)?)\s(hours?|hrs?|minutes?|mins?)\b|\bin\s\d{1,2}h(\d{1,2}m)?\b",
+);
+static CASUAL_TIME: Pattern = Pattern::new(
+    r"(?i)\b(noon|midnight|morning|afternoon|evening|tonight|soon|shortly|later\s(today|on)|in\sa\s(little\s)?(bit|while))\b",
+);
+static LOCATION: Pattern = Pattern::new(r"(?:\b[Aa]t|@)\s?(?P<place>[A-Z][\w'&.-]*(?:\s[A-Z0-9][\w'&.-]*)*)");
+static MONTH_WORD: Pattern = Pattern::new(r"(?i)^(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\.?$");
+static RELATIVE_WORD: Pattern = Pattern::new(r"(?i)^(next|last|this|today|tonight|tomorrow)$");
+
+/// Finds every date, time, range, duration, recurrence and location in `text`, in the order they appear. Where two
+/// overlap, the longer reading wins, so "6/1-6/8" is one date range rather than two dates.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::{extract, EntityKind};
+///
+/// let text = "Coffee at Blue Bottle tomorrow 9-10am";
+/// let entities = extract(text);
+///
+/// assert_eq!(entities.len(), 3);
+/// assert_eq!(entities[0].kind, EntityKind::Location("Blue Bottle".to_owned()));
+/// assert_eq!(&text[entities[2].span.clone()], "9-10am");
+/// ```
+pub fn extract(text: &str) -> Vec<Entity> {
+    extract_at(text, LocalClock.now())
+}
+
+/// Finds the entities in `text` with respect to `now`.
+pub(crate) fn extract_at(text: &str, now: NaiveDateTime) -> Vec<Entity> {
+    extract_with(text, now, &Parsers::default())
+}
+
+/// Finds the entities in `text` with respect to `now`, reading dates and times with `parsers` and leaving out the
+/// schedules and places they turn off.
+pub(crate) fn extract_with(text: &str, now: NaiveDateTime, parsers: &Parsers) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    let today = now.date();
+    let parser = &parsers.date;
+
+    let recurrence = RecurParser::parse_relative(text, today).filter(|_| parsers.recurrence);
+    if let Some(found) = recurrence {
+        claim(&mut entities, EntityKind::Recurrence(found.recurrence.clone()), found.span);
+        if let (Some(end), Some(span)) = (found.recurrence.end, found.end_span) {
+            claim(&mut entities, EntityKind::RecurrenceEnd(end), span);
+        }
+    }
+
+    for range in ranges(text) {
+        let (start, end) = (range.start, range.end);
+        let times = (parsers.time(start, now.time()), parsers.time(end, now.time()));
+        let dates = (parser.find(start, today), parser.find(end, today));
+        let kind = match (times, dates) {
+            ((Some(s), Some(e)), _) => {
+                let (s, e) = propagate_meridiem(start, s, end, e);
+                EntityKind::TimeRange(s, e)
+            }
+            (_, (Some(s), Some(e))) if !range.times_only => EntityKind::DateRange(s, e),
+            _ => continue,
+        };
+        claim(&mut entities, kind, range.span);
+    }
+
+    for pattern in [&WEEKEND_OF, &WEEK_OF, &ISO_WEEK, &SPAN] {
+        for m in pattern.find_iter(text) {
+            if let Some((start, end)) = parser.find_span(m.as_str(), today) {
+                claim(&mut entities, EntityKind::DateRange(start, end), m.range());
+            }
+        }
+    }
+
+    for pattern in [
+        &NTH_WEEKDAY_OF_MONTH,
+        &DATE_MENTION,
+        &ORDINAL_DATE,
+        &PERIOD,
+        &RELATIVE_DAY,
+        &WEEKDAY_PHRASE,
+    ] {
+        for m in pattern.find_iter(text) {
+            if let Some(date) = parser.find(m.as_str(), today) {
+                claim(&mut entities, EntityKind::Date(date), m.range());
+            }
+        }
+    }
+
+    for pattern in [&RELATIVE_TIME, &CASUAL_TIME] {
+        for m in pattern.find_iter(text) {
+            if let Some(time) = parsers.time.find(m.as_str(), now.time()) {
+                claim(&mut entities, EntityKind::Time(time.time), m.range());
+            }
+        }
+    }
+
+    for caps in DURATION.captures_iter(text) {
+        let m = caps.get(0).unwrap();
+        if let Some(duration) = DurationParser::parse(m.as_str()) {
+            claim(&mut entities, EntityKind::Duration(duration), m.range());
+        }
+    }
+
+    for m in MILITARY_TIME
+        .find_iter(text)
+        .chain(CLOCK_TIME.find_iter(text))
+        .filter(|m| !m.is_empty())
+    {
+        if let Some(time) = parsers.time(m.as_str(), now.time()) {
+            claim(&mut entities, EntityKind::Time(time), m.range());
+        }
+    }
+
+    if let Some(span) = find_location(text).filter(|_| parsers.location) {
+        claim(&mut entities, EntityKind::Location(text[span.clone()].to_owned()), span);
+    }
+
+    entities.sort_by_key(|entity| entity.span.start);
+    entities
+}
+
+/// Adds an entity unless it overlaps one found before it.
+fn claim(entities: &mut Vec<Entity>, kind: EntityKind, span: Range<usize>) {
+    if entities.iter().all(|e| e.span.end <= span.start || span.end <= e.span.start) {
+        entities.push(Entity { kind, span });
+    }
+}
+
+/// Returns where the place an event is at is in `text`, e.g. "Cafe Rio" in "Lunch at Cafe Rio at noon". A place is a run
+/// of capitalized words after "at" or "@" and ends before any word that is a date or time, like "Friday" or "Noon".
+pub(crate) fn find_location(text: &str) -> Option<Range<usize>> {
+    for caps in LOCATION.captures_iter(text) {
+        let place = caps.name("place").unwrap();
+        let words: Vec<&str> = place.as_str().split(' ').collect();
+        let dated = |word: &str| {
+            MONTH_WORD.is_match(word)
+                || RELATIVE_WORD.is_match(word)
+                || DateParser::parse(&word.to_lowercase()).is_some()
+        };
+        let timed = |word: &str| TimeParser::parse(&word.to_lowercase()).is_some();
+        let mut end = place.start();
+        for (i, word) in words.iter().enumerate() {
+            // a time in words named along with the place, like the "Noon" of "Noon Bistro", is part of its name
+            let named = words
+                .get(i + 1)
+                .is_some_and(|next| !dated(next) && !timed(next));
+            if dated(word) || (timed(word) && !named) {
+                break;
+            }
+            end = place.start() + (word.as_ptr() as usize - place.as_str().as_ptr() as usize) + word.len();
+        }
+        if end > place.start() {
+            return Some(place.start()..end);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod extract_tests {
+    use super::{extract_at, find_location, EntityKind};
+    use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+    use date_time_parser::{Frequency, Recurrence, RecurrenceEnd};
+
+    #[test]
+    fn extract_tests() {
+        assert_entities(
+            "Standup every day at 9am for two weeks",
+            vec![
+                (
+                    "every day",
+                    EntityKind::Recurrence(Recurrence {
+                        frequency: Frequency::Daily,
+                        interval: 1,
+                        end: Some(RecurrenceEnd::For(2, Frequency::Weekly)),
+                        days: Vec::new(),
+                        month_day: None,
+                        month_weekday: None,
+                    }),
+                ),
+                ("9am", EntityKind::Time(hm(9, 0))),
+                ("for two weeks", EntityKind::RecurrenceEnd(RecurrenceEnd::For(2, Frequency::Weekly))),
+            ],
+        );
+        assert_entities(
+            "Lunch at Cafe Rio June 5 12-1pm",
+            vec![
+                ("Cafe Rio", EntityKind::Location("Cafe Rio".to_owned())),
+                ("June 5", EntityKind::Date(ymd(2021, 6, 5))),
+                ("12-1pm", EntityKind::TimeRange(hm(12, 0), hm(13, 0))),
+            ],
+        );
+        assert_entities(
+            "Deep work 2h next Friday",
+            vec![
+                ("2h", EntityKind::Duration(Duration::hours(2))),
+                ("next Friday", EntityKind::Date(ymd(2021, 3, 12))),
+            ],
+        );
+        assert_entities(
+            "Summer Camp 6/1-6/8",
+            vec![("6/1-6/8", EntityKind::DateRange(ymd(2021, 6, 1), ymd(2021, 6, 8)))],
+        );
+        assert_entities(
+            "Out of office all week, back in 3 hours",
+            vec![
+                ("all week", EntityKind::DateRange(ymd(2021, 3, 1), ymd(2021, 3, 7))),
+                ("in 3 hours", EntityKind::Time(hm(11, 0))),
+            ],
+        );
+        assert_entities(
+            "Party the day after tomorrow at 9pm",
+            vec![
+                ("the day after tomorrow", EntityKind::Date(ymd(2021, 3, 5))),
+                ("9pm", EntityKind::Time(hm(21, 0))),
+            ],
+        );
+        assert_entities(
+            "Club the first Monday of June",
+            vec![(
+                "the first Monday of June",
+                EntityKind::Date(ymd(2021, 6, 7)),
+            )],
+        );
+        assert_entities(
+            "Vacation the week of June 9",
+            vec![(
+                "the week of June 9",
+                EntityKind::DateRange(ymd(2021, 6, 7), ymd(2021, 6, 13)),
+            )],
+        );
+        assert_entities("Write the report", vec![]);
+    }
+
+    #[test]
+    fn location_tests() {
+        assert_location("Lunch at Cafe Rio at noon", Some("Cafe Rio"));
+        assert_location("Coffee @ Blue Bottle tomorrow", Some("Blue Bottle"));
+        assert_location("Drinks at The Rusty Nail Friday", Some("The Rusty Nail"));
+        assert_location("Flight on Saturday at Noon", None);
+        assert_location("Dinner at 7", None);
+        assert_location("Meet at Friday", None);
+        assert_location("Coffee at Evening Star cafe at 7", Some("Evening Star"));
+        assert_location("Dinner at Noon Bistro 7pm", Some("Noon Bistro"));
+        assert_location("Lunch at Noon Friday", None);
+        assert_location("Lunch at Noon", None);
+    }
+
+    fn assert_entities(text: &str, expected: Vec<(&str, EntityKind)>) {
+        let found: Vec<_> = extract_at(text, now())
+            .into_iter()
+            .map(|e| (&text[e.span], e.kind))
+            .collect();
+        assert_eq!(found, expected)
+    }
+
+    fn assert_location(text: &str, expected: Option<&str>) {
+        assert_eq!(find_location(text).map(|span| &text[span]), expected)
+    }
+
+    fn now() -> NaiveDateTime {
+        ymd(2021, 3, 3).and_hms_opt(8, 0, 0).unwrap()
+    }
+
+    fn hm(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+}
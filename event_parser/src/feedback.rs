@@ -0,0 +1,137 @@
+//! Hear about the texts the parser could not read, or read with little confidence, so applications can collect them to
+//! improve the rules without wrapping every call site.
+
+use std::sync::{Arc, RwLock};
+
+use crate::ParseOutcome;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Why a text was reported to the miss hook.
+pub enum MissReason {
+    /// No date or time was found, so the event is all day today
+    NoDateOrTime,
+
+    /// The start time is a guess, e.g. the "5" of "Meet in room 5", with [`Confidence::Low`](enum.Confidence.html)
+    LowConfidence,
+}
+
+#[derive(Debug)]
+/// A text the parser could not read well, with what it did read.
+pub struct Miss<'a> {
+    /// The text as it was given to the parser.
+    pub text: &'a str,
+    /// Why it was reported.
+    pub reason: MissReason,
+    /// The event and warnings parsed from it, with any summary or location found.
+    pub outcome: &'a ParseOutcome,
+}
+
+type MissHook = Arc<dyn Fn(&Miss) + Send + Sync>;
+
+static HOOK: RwLock<Option<MissHook>> = RwLock::new(None);
+
+/// Sets `hook` to be called with every text [`to_event`](fn.to_event.html) and the functions like it find no date or
+/// time in, or only a time they are unsure of, replacing any hook set before. The hook is called on the thread that
+/// parsed the text, after it was parsed, so it should be quick, e.g. sending the text down a channel.
+///
+/// # Arguments
+///
+/// * `hook` - What to call with each text missed.
+///
+/// # Example
+/// ```
+/// use event_parser::{clear_miss_hook, set_miss_hook, to_event, MissReason};
+/// use std::sync::{mpsc, Mutex};
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let sender = Mutex::new(sender);
+/// set_miss_hook(move |miss| {
+///     sender.lock().unwrap().send((miss.text.to_owned(), miss.reason)).unwrap();
+/// });
+///
+/// to_event("Dinner at 7pm");
+/// to_event("Write the quarterly report");
+/// clear_miss_hook();
+///
+/// assert_eq!(
+///     receiver.try_iter().collect::<Vec<_>>(),
+///     vec![("Write the quarterly report".to_owned(), MissReason::NoDateOrTime)]
+/// );
+/// ```
+pub fn set_miss_hook(hook: impl Fn(&Miss) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Stops calling the hook set with [`set_miss_hook`](fn.set_miss_hook.html).
+pub fn clear_miss_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Calls the miss hook, if one is set, with `text`, why it was missed and what was parsed from it.
+pub(crate) fn report(text: &str, reason: MissReason, outcome: &ParseOutcome) {
+    // the hook is called without holding the lock, so it may set another
+    let hook = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        hook(&Miss {
+            text,
+            reason,
+            outcome,
+        });
+    }
+}
+
+#[cfg(test)]
+mod feedback_tests {
+    use super::{clear_miss_hook, set_miss_hook, MissReason};
+    use crate::{parse_at, Parsers};
+    use chrono::NaiveDate;
+    use icalendar::Component;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn miss_hook_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        let missed = Arc::new(Mutex::new(Vec::new()));
+        let log = missed.clone();
+        // other tests parse at the same time, so only texts mentioning the feedback room are kept
+        set_miss_hook(move |miss| {
+            if miss.text.contains("feedback room") {
+                let summary = miss.outcome.event.properties()["SUMMARY"]
+                    .value()
+                    .to_owned();
+                log.lock()
+                    .unwrap()
+                    .push((miss.text.to_owned(), miss.reason, summary));
+            }
+        });
+
+        for text in [
+            "Review in the feedback room at 3pm",
+            "Review in feedback room 5",
+            "Tidy the feedback room",
+        ] {
+            parse_at(text, now, &Parsers::default());
+        }
+        clear_miss_hook();
+        parse_at("Sweep the feedback room", now, &Parsers::default());
+
+        assert_eq!(
+            *missed.lock().unwrap(),
+            vec![
+                (
+                    "Review in feedback room 5".to_owned(),
+                    MissReason::LowConfidence,
+                    "Review feedback room".to_owned()
+                ),
+                (
+                    "Tidy the feedback room".to_owned(),
+                    MissReason::NoDateOrTime,
+                    "Tidy the feedback room".to_owned()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Split a text into labeled pieces so a quick-add box can underline what the parser understood.
+
+use chrono::NaiveDateTime;
+use date_time_parser::{Clock, LocalClock};
+use icalendar::Event;
+use std::ops::Range;
+
+use crate::extract::{extract_at, extract_with, find_location, EntityKind};
+use crate::normalize::{is_word, trim_trailing_punctuation};
+use crate::range::ranges;
+use crate::{summary_around, to_event_at, Parsers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a [`Segment`](struct.Segment.html) of text was understood as.
+pub enum Label {
+    /// Text the parser did not use, like "at" or punctuation
+    Plain,
+
+    /// A date, date range or repeating schedule, e.g. "June 5" or "every other week"
+    Date,
+
+    /// A time, time range or duration, e.g. "7pm" or "9-10am"
+    Time,
+
+    /// The place the event is at
+    Location,
+
+    /// Words that make up the event's summary
+    Summary,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A piece of the original text with what it was understood as.
+pub struct Segment<'a> {
+    /// What the piece was understood as.
+    pub label: Label,
+    /// The piece itself.
+    pub text: &'a str,
+    /// Where the piece is in the original text, in bytes.
+    pub span: Range<usize>,
+}
+
+/// Splits `text` into consecutive [`Segment`](struct.Segment.html)s that together cover all of it, each labeled with
+/// what the parser understood it as.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::{highlight, Label};
+///
+/// let segments = highlight("Lunch at Cafe Rio at noon");
+/// let labeled: Vec<_> = segments.iter().map(|s| (s.label, s.text)).collect();
+///
+/// assert_eq!(
+///     labeled,
+///     vec![
+///         (Label::Summary, "Lunch"),
+///         (Label::Plain, " at "),
+///         (Label::Location, "Cafe Rio"),
+///         (Label::Plain, " at "),
+///         (Label::Time, "noon"),
+///     ]
+/// );
+/// ```
+pub fn highlight(text: &str) -> Vec<Segment<'_>> {
+    highlight_at(text, LocalClock.now())
+}
+
+/// Splits `text` into labeled segments with respect to `now`.
+pub(crate) fn highlight_at(text: &str, now: NaiveDateTime) -> Vec<Segment<'_>> {
+    highlight_with_parsers(text, now, &Parsers::default())
+}
+
+/// Splits `text` into labeled segments with respect to `now`, as `parsers` read it.
+pub(crate) fn highlight_with_parsers<'a>(
+    text: &'a str,
+    now: NaiveDateTime,
+    parsers: &Parsers,
+) -> Vec<Segment<'a>> {
+    let place = find_location(text).filter(|_| parsers.location);
+    let summary = summary_around(text, place, parsers.names_in_summary).unwrap_or_default();
+    let summary_words: Vec<&str> = summary.split_whitespace().collect();
+    let mut next_word = 0;
+
+    let mut segments = Vec::new();
+
+    let mut gaps = Vec::new();
+    let mut at = 0;
+    for entity in extract_with(text, now, parsers) {
+        gaps.push((at..entity.span.start, None));
+        gaps.push((entity.span.clone(), Some(label(&entity.kind))));
+        at = entity.span.end;
+    }
+    gaps.push((at..text.len(), None));
+
+    for (span, label) in gaps {
+        if let Some(label) = label {
+            push(&mut segments, text, label, span);
+            continue;
+        }
+        // words the summary kept, in order, are summary; the rest of the gap, stray punctuation included, is plain
+        let mut from = span.start;
+        let mut after_summary = false;
+        let words = text[span.clone()].split_whitespace();
+        for word in words.filter(|w| is_word(w)) {
+            let start = word.as_ptr() as usize - text.as_ptr() as usize;
+            // the summary leaves off the punctuation ending the text
+            let found = summary_words[next_word..]
+                .iter()
+                .position(|w| *w == word || *w == trim_trailing_punctuation(word));
+            if let Some(i) = found {
+                next_word += i + 1;
+                // spaces between two summary words belong to the summary
+                let between = if after_summary && text[from..start].trim().is_empty() {
+                    Label::Summary
+                } else {
+                    Label::Plain
+                };
+                push(&mut segments, text, between, from..start);
+                push(&mut segments, text, Label::Summary, start..start + summary_words[next_word - 1].len());
+                from = start + summary_words[next_word - 1].len();
+            }
+            after_summary = found.is_some();
+        }
+        push(&mut segments, text, Label::Plain, from..span.end);
+    }
+    segments
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Where the parts of an event were read from in its text, found by [`parse_with_spans`](fn.parse_with_spans.html).
+/// Every span is a byte range of the text.
+pub struct EventSpans {
+    /// The date, date range or repeating schedule, e.g. "June 5", "6/1-6/8" or "every Monday"
+    pub date: Option<Range<usize>>,
+    /// The time or time range, e.g. "7pm" or "9-10am"
+    pub time: Option<Range<usize>>,
+    /// What joins the two ends of a range, e.g. "-" of "9-10am" or " till " of "7 till 9pm"
+    pub connector: Option<Range<usize>>,
+    /// The words left for the summary, e.g. "Lunch" of "Lunch at noon"
+    pub summary: Vec<Range<usize>>,
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), along with where the date, time, range connector
+/// and summary were read from, so they can be highlighted in the text.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::parse_with_spans;
+///
+/// let text = "Standup tomorrow 9-10am";
+/// let (_event, spans) = parse_with_spans(text);
+///
+/// assert_eq!(&text[spans.date.unwrap()], "tomorrow");
+/// assert_eq!(&text[spans.time.unwrap()], "9-10am");
+/// assert_eq!(&text[spans.connector.unwrap()], "-");
+/// assert_eq!(&text[spans.summary[0].clone()], "Standup");
+/// ```
+pub fn parse_with_spans(text: &str) -> (Event, EventSpans) {
+    parse_with_spans_at(text, LocalClock.now())
+}
+
+/// Parses `text` into an `Event` with its spans with respect to `now`.
+pub(crate) fn parse_with_spans_at(text: &str, now: NaiveDateTime) -> (Event, EventSpans) {
+    let entities = extract_at(text, now);
+    let first = |wanted: fn(&EntityKind) -> bool| {
+        entities
+            .iter()
+            .find(|entity| wanted(&entity.kind))
+            .map(|entity| entity.span.clone())
+    };
+    let connector = entities
+        .iter()
+        .filter(|entity| {
+            matches!(
+                entity.kind,
+                EntityKind::DateRange(..) | EntityKind::TimeRange(..)
+            )
+        })
+        .find_map(|entity| ranges(text).find(|range| range.span == entity.span))
+        .map(|range| range.connector);
+    let spans = EventSpans {
+        date: first(|kind| {
+            matches!(
+                kind,
+                EntityKind::Date(_) | EntityKind::DateRange(..) | EntityKind::Recurrence(_)
+            )
+        }),
+        time: first(|kind| matches!(kind, EntityKind::Time(_) | EntityKind::TimeRange(..))),
+        connector,
+        summary: highlight_at(text, now)
+            .into_iter()
+            .filter(|segment| segment.label == Label::Summary)
+            .map(|segment| segment.span)
+            .collect(),
+    };
+    (to_event_at(text, now), spans)
+}
+
+/// Adds a segment, merging it into the last one when they touch and have the same label.
+fn push<'a>(segments: &mut Vec<Segment<'a>>, text: &'a str, label: Label, span: Range<usize>) {
+    if span.is_empty() {
+        return;
+    }
+    match segments.last_mut() {
+        Some(last) if last.label == label && last.span.end == span.start => {
+            last.span.end = span.end;
+            last.text = &text[last.span.clone()];
+        }
+        _ => segments.push(Segment {
+            label,
+            text: &text[span.clone()],
+            span,
+        }),
+    }
+}
+
+fn label(kind: &EntityKind) -> Label {
+    match kind {
+        EntityKind::Date(_)
+        | EntityKind::DateRange(_, _)
+        | EntityKind::Recurrence(_)
+        | EntityKind::RecurrenceEnd(_) => Label::Date,
+        EntityKind::Time(_) | EntityKind::TimeRange(_, _) | EntityKind::Duration(_) => Label::Time,
+        EntityKind::Location(_) => Label::Location,
+    }
+}
+
+#[cfg(test)]
+mod highlight_tests {
+    use super::{highlight_at, parse_with_spans_at, Label};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    #[test]
+    fn highlight_tests() {
+        assert_segments(
+            "Team Standup every day at 9am",
+            vec![
+                (Label::Summary, "Team Standup"),
+                (Label::Plain, " "),
+                (Label::Date, "every day"),
+                (Label::Plain, " at "),
+                (Label::Time, "9am"),
+            ],
+        );
+        assert_segments(
+            "Summer Camp 6/1-6/8",
+            vec![
+                (Label::Summary, "Summer Camp"),
+                (Label::Plain, " "),
+                (Label::Date, "6/1-6/8"),
+            ],
+        );
+        assert_segments(
+            "Dinner with Sam tomorrow, at 7pm",
+            vec![
+                (Label::Summary, "Dinner with Sam"),
+                (Label::Plain, " "),
+                (Label::Date, "tomorrow"),
+                (Label::Plain, ", at "),
+                (Label::Time, "7pm"),
+            ],
+        );
+        assert_segments(
+            "🎉 Party at 7pm!",
+            vec![
+                (Label::Summary, "🎉 Party"),
+                (Label::Plain, " at "),
+                (Label::Time, "7pm"),
+                (Label::Plain, "!"),
+            ],
+        );
+        assert_segments("", vec![]);
+    }
+
+    #[test]
+    fn covers_text_tests() {
+        for text in ["Coffee @ Blue Bottle 9-10am", "Deep work 2h next Friday", "  spaced   out  "] {
+            let joined: String = highlight_at(text, now()).iter().map(|s| s.text).collect();
+            assert_eq!(joined, text);
+        }
+    }
+
+    #[test]
+    fn parse_with_spans_tests() {
+        let spans = |text: &'static str| {
+            let (_, spans) = parse_with_spans_at(text, now());
+            let part = |span: Option<std::ops::Range<usize>>| span.map(|span| &text[span]);
+            (
+                part(spans.date),
+                part(spans.time),
+                part(spans.connector),
+                spans
+                    .summary
+                    .into_iter()
+                    .map(|span| &text[span])
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(
+            spans("Coffee at Blue Bottle tomorrow 9-10am"),
+            (Some("tomorrow"), Some("9-10am"), Some("-"), vec!["Coffee"])
+        );
+        assert_eq!(
+            spans("Summer Camp 6/1 through 6/8"),
+            (
+                Some("6/1 through 6/8"),
+                None,
+                Some(" through "),
+                vec!["Summer Camp"]
+            )
+        );
+        assert_eq!(
+            spans("Dinner with Sam at 7 till 9pm"),
+            (
+                None,
+                Some("7 till 9pm"),
+                Some(" till "),
+                vec!["Dinner with Sam"]
+            )
+        );
+        assert_eq!(
+            spans("Deep work 2h next Friday"),
+            (Some("next Friday"), None, None, vec!["Deep work"])
+        );
+        assert_eq!(spans(""), (None, None, None, vec![]));
+    }
+
+    fn assert_segments(text: &str, expected: Vec<(Label, &str)>) {
+        let found: Vec<_> = highlight_at(text, now())
+            .iter()
+            .map(|s| (s.label, s.text))
+            .collect();
+        assert_eq!(found, expected)
+    }
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Aims to parse unstructered text into [iCalendar Events](../icalendar/struct.Event.html).
 //! * Parses text into events with a date and time relative to the local time.
-//! * Event Parser defaults to be timezone aware.
+//! * Event Parser reads times as wall-clock times; [`to_event_in_timezone`](fn.to_event_in_timezone.html) resolves them in a timezone.
 //! * Leverages the crate [date_time_parser](../date_time_parser/index.html) for parsing out the dates and time of events.
 //!
 //! ## Usage
@@ -44,6 +44,9 @@
 //! The crate parses events relative to the current local time, meaning it's timezone sensitive and allows for events
 //! to be parsed without a particular date. Specifying a date like "tomorrow" or "next friday" is enough to 
 //! determine the date on which that event is supposed to take place.
+//! Times are not resolved in the local timezone: `DTSTART` and `DTEND` carry the wall-clock times of the text with a
+//! `Z` suffix, so 7pm is written `T190000Z` wherever the parser runs. [`to_event_in_timezone`](fn.to_event_in_timezone.html)
+//! writes the UTC times they happen at in a given timezone instead.
 //! 
 //! Additionally, if no end time is given for an event, the event duration defauls to 1 hour (similar to Google Calendar).
 //! 
@@ -79,17 +82,66 @@
 //! ```
 //! Output:
 //! ```txt
-//! date: 2020-04-25T12:00:00Z
 //! Event: "Flight"
 //! 12:00pm April 25 2020 - 01:00pm April 25 2020
 //! ```
 //! 
 
-use chrono::{Date, DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
-use date_time_parser::DateParser;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+pub use date_time_parser::{Clock, Direction, FixedClock, LocalClock, MeridiemPolicy, OffsetClock, Rounding, UtcClock, TimeZoneMention, may_be_temporal};
+use date_time_parser::{DateExpr, DateOptions, DateOrder, DateParser, MonthSpec};
+use date_time_parser::DurationParser;
+use date_time_parser::{Frequency, RecurParser, Recurrence, RecurrenceEnd};
 use date_time_parser::TimeParser;
-use icalendar::{Component, Event};
+use date_time_parser::patterns::{
+    find_non_temporal, in_name, is_word_time, weekday_named, Pattern, CLOCK_TIME, COLLOQUIAL_TIME, CONTINENTAL_TIME, DATE_MENTION, DAY_MONTH, DAY_OF_MONTH, DURATION, PERIOD, ISO_DATE_TIME, ISO_WEEK, MERIDIEM, MILITARY_TIME, MONTH_DATE, NTH_WEEKDAY_OF_MONTH, NUMERIC_DATE,
+    NUMERIC_DATE_WITH_YEAR, ORDINAL_DATE, QUARTER, SEASON, SPAN, TIME_ZONE, WEEKDAY, WEEKEND, WORD_TIME,
+};
+use icalendar::{Calendar, Component, Event, EventStatus, Property};
 use regex::Regex;
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::sync::Arc;
+
+mod availability;
+pub use availability::{free_times, free_times_with_clock, FreeBusy, TimeRange};
+mod classify;
+pub use classify::{classify, EventKind};
+#[cfg(feature = "config")]
+mod config;
+mod describe;
+pub use describe::describe_event;
+mod error;
+pub use error::{try_to_event, EventParseError};
+mod feedback;
+pub use feedback::{clear_miss_hook, set_miss_hook, Miss, MissReason};
+#[cfg(feature = "config")]
+pub use config::{highlight_with, to_event_with_config, ConfigError, ParserConfig, Rules};
+mod extract;
+pub use extract::{extract, Entity, EntityKind};
+use extract::find_location;
+mod highlight;
+pub use highlight::{highlight, parse_with_spans, EventSpans, Label, Segment};
+mod merge;
+pub use merge::{merge_events, MergeReport};
+mod normalize;
+pub use normalize::normalize;
+mod parsed;
+mod parser;
+pub use parser::{EventParser, EventParserBuilder};
+mod participants;
+use participants::{find_participants, without_participants, EMAIL};
+mod range;
+use range::{ranges, BETWEEN_RANGE, TILL_RANGE};
+pub use parsed::{extract_events, extract_events_with_clock, to_parsed_event, to_parsed_event_with_clock, ParsedEvent};
+use normalize::{is_word, trim_trailing_punctuation};
+mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "config")]
+pub use stream::parse_events_with_config;
+pub use stream::{parse_events_from_reader, scan_entities, write_events, write_events_with_format};
 
 
 
@@ -115,12 +167,214 @@ enum EventStartAndEndExpr {
 
     /// A multi-day event with a start date and an end date
     AllDayStartsAndEnds(NaiveDate, NaiveDate),
+
+    /// An all-day event on each of several non-consecutive dates
+    AllDayOn(Vec<NaiveDate>),
+
+    /// An event with a start date and time and an end date and time, e.g. overnight
+    StartsAndEndsAt(NaiveDateTime, NaiveDateTime),
+
+    /// An event with a start time and end time on each of several dates
+    StartsAndEndsOn(NaiveTime, NaiveTime, Vec<NaiveDate>),
+}
+
+impl EventStartAndEndExpr {
+    /// Returns the date the event starts on, defaulting to `today`, and its start time unless it is all-day.
+    fn start(&self, today: NaiveDate) -> (NaiveDate, Option<NaiveTime>) {
+        match self {
+            EventStartAndEndExpr::Unknown => (today, None),
+            EventStartAndEndExpr::Starts(t) | EventStartAndEndExpr::StartsAndEnds(t, _) => (today, Some(*t)),
+            EventStartAndEndExpr::StartsWithDate(t, d)
+            | EventStartAndEndExpr::StartsAndEndsWithDate(t, _, d) => (*d, Some(*t)),
+            EventStartAndEndExpr::StartsAndEndsOn(t, _, dates) => (dates[0], Some(*t)),
+            EventStartAndEndExpr::AllDay(d) | EventStartAndEndExpr::AllDayStartsAndEnds(d, _) => (*d, None),
+            EventStartAndEndExpr::AllDayOn(dates) => (dates[0], None),
+            EventStartAndEndExpr::StartsAndEndsAt(start, _) => (start.date(), Some(start.time())),
+        }
+    }
+
+    /// Moves the event to the first day on or after its date, defaulting to `today`, that falls on one of `days`.
+    fn on_first_of(self, days: &[Weekday], today: NaiveDate) -> EventStartAndEndExpr {
+        let (date, _) = self.start(today);
+        let first = date.iter_days().take(7).find(|d| days.contains(&d.weekday())).unwrap_or(date);
+        self.moved_to(first)
+    }
+
+    /// Moves the event to the first day on or after its date, defaulting to `today`, that is day `day` of its month.
+    fn on_day_of_month(self, day: u32, today: NaiveDate) -> EventStartAndEndExpr {
+        let (date, _) = self.start(today);
+        // every day of the month comes around within a year, even the 31st
+        let first = date.iter_days().take(366).find(|d| d.day() == day).unwrap_or(date);
+        self.moved_to(first)
+    }
+
+    /// Moves the event to the first day on or after its date, defaulting to `today`, that is the `n`th `weekday` of its
+    /// month, counting from the end of the month when `n` is negative.
+    fn on_weekday_of_month(self, n: i8, weekday: Weekday, today: NaiveDate) -> EventStartAndEndExpr {
+        let (date, _) = self.start(today);
+        let nth = DateExpr::NthWeekdayOfMonth(n, weekday, MonthSpec::InNMonths(0));
+        let first = date.iter_days().take(366).find(|d| nth.resolve(*d) == Some(*d)).unwrap_or(date);
+        self.moved_to(first)
+    }
+
+    /// Moves an event on a single day, or with no date, to `first`.
+    fn moved_to(self, first: NaiveDate) -> EventStartAndEndExpr {
+        match self {
+            EventStartAndEndExpr::Unknown | EventStartAndEndExpr::AllDay(_) => EventStartAndEndExpr::AllDay(first),
+            EventStartAndEndExpr::Starts(t) | EventStartAndEndExpr::StartsWithDate(t, _) => {
+                EventStartAndEndExpr::StartsWithDate(t, first)
+            }
+            EventStartAndEndExpr::StartsAndEnds(start, end) | EventStartAndEndExpr::StartsAndEndsWithDate(start, end, _) => {
+                EventStartAndEndExpr::StartsAndEndsWithDate(start, end, first)
+            }
+            expr => expr,
+        }
+    }
+
+    /// Returns the event without its times, all day on the dates it falls on, or of unknown date and time if it has none.
+    fn without_time(self) -> EventStartAndEndExpr {
+        match self {
+            EventStartAndEndExpr::Starts(_) | EventStartAndEndExpr::StartsAndEnds(_, _) => EventStartAndEndExpr::Unknown,
+            EventStartAndEndExpr::StartsWithDate(_, d) | EventStartAndEndExpr::StartsAndEndsWithDate(_, _, d) => {
+                EventStartAndEndExpr::AllDay(d)
+            }
+            EventStartAndEndExpr::StartsAndEndsAt(start, end) => {
+                EventStartAndEndExpr::AllDayStartsAndEnds(start.date(), end.date())
+            }
+            EventStartAndEndExpr::StartsAndEndsOn(_, _, dates) => EventStartAndEndExpr::AllDayOn(dates),
+            expr => expr,
+        }
+    }
+
+    /// Returns every date the event falls on, defaulting to `today`.
+    fn dates(&self, today: NaiveDate) -> Vec<NaiveDate> {
+        let days = |start: NaiveDate, end: NaiveDate| start.iter_days().take_while(|d| *d <= end).collect();
+        match self {
+            EventStartAndEndExpr::Unknown
+            | EventStartAndEndExpr::Starts(_)
+            | EventStartAndEndExpr::StartsAndEnds(_, _) => vec![today],
+            EventStartAndEndExpr::StartsWithDate(_, d)
+            | EventStartAndEndExpr::StartsAndEndsWithDate(_, _, d)
+            | EventStartAndEndExpr::AllDay(d) => vec![*d],
+            EventStartAndEndExpr::AllDayStartsAndEnds(start, end) => days(*start, *end),
+            EventStartAndEndExpr::StartsAndEndsAt(start, end) => days(start.date(), end.date()),
+            EventStartAndEndExpr::AllDayOn(dates) | EventStartAndEndExpr::StartsAndEndsOn(_, _, dates) => dates.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A caveat about how text was read that did not stop it from being parsed into an `Event`.
+pub enum ParseWarning {
+    /// The weekday given does not fall on the date given, e.g. "Friday June 5" in a year June 5 is a Saturday. The date is kept.
+    WeekdayMismatch(Weekday, NaiveDate),
+
+    /// The day given is past the end of its month, e.g. "Feb 30", so the last day of the month was used.
+    ClampedDay(u32, NaiveDate),
+
+    /// The time was given without am or pm, e.g. "at 7", so one was guessed.
+    AmbiguousMeridiem(NaiveTime),
+
+    /// A date was found in the text but the event does not fall on it, e.g. the second date of "June 5 or June 12".
+    IgnoredDate(NaiveDate),
+
+    /// A slash date reads as a date both month first and day first, e.g. "6/5" as June 5 or May 6, given in that order.
+    /// The event uses the reading usual in the parser's locale, month first by default;
+    /// [`DateParser::slash_date_readings`](../date_time_parser/date_parse/struct.DateParser.html#method.slash_date_readings)
+    /// ranks both for a locale.
+    AmbiguousDateOrder(NaiveDate, NaiveDate),
+
+    /// A schedule was given as "biweekly" or "bimonthly", read as every other week or month, though it is sometimes
+    /// meant as twice a week or month.
+    AmbiguousInterval(Frequency),
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseWarning::WeekdayMismatch(weekday, date) => {
+                write!(f, "{} is a {}, not a {}", date.format("%B %-d %Y"), date.format("%A"), weekday_name(*weekday))
+            }
+            ParseWarning::ClampedDay(day, date) => {
+                write!(f, "{} has no day {}, so {} was used", date.format("%B %Y"), day, date.format("%B %-d"))
+            }
+            ParseWarning::AmbiguousMeridiem(time) => {
+                write!(f, "no am or pm was given, so {} was guessed", time.format("%-I:%M%P"))
+            }
+            ParseWarning::IgnoredDate(date) => write!(f, "the event is not on {}", date.format("%B %-d %Y")),
+            ParseWarning::AmbiguousDateOrder(month_first, day_first) => write!(
+                f,
+                "the date could be {} or {}",
+                month_first.format("%B %-d"),
+                day_first.format("%B %-d")
+            ),
+            ParseWarning::AmbiguousInterval(frequency) => {
+                let unit = match frequency {
+                    Frequency::Daily => "day",
+                    Frequency::Weekly => "week",
+                    Frequency::Monthly => "month",
+                    Frequency::Yearly => "year",
+                };
+                write!(f, "every other {} was assumed, though it can mean twice a {}", unit, unit)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "config", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "snake_case"))]
+/// How sure the parser is of the time it read from text, from least to most.
+pub enum Confidence {
+    /// A bare number read as an hour, e.g. the "5" of "Meet in room 5"
+    #[default]
+    Low,
+
+    /// An hour without am or pm that reads as a time, e.g. "at 7" or "7-9", or a time estimated from a vague phrase
+    /// like "soon"
+    Medium,
+
+    /// A time stated outright, e.g. "7pm", "19:30" or "noon", or no time at all
+    High,
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[derive(Debug)]
+/// An `Event` parsed from text, with any caveats about how the text was read.
+pub struct ParseOutcome {
+    /// The parsed event, the same one [`to_event`](fn.to_event.html) returns.
+    pub event: Event,
+    /// Caveats about how the text was read, empty if there are none.
+    pub warnings: Vec<ParseWarning>,
+    /// Whether the text describes an event, a deadline or a reminder, as [`classify`](fn.classify.html) tells.
+    pub kind: EventKind,
+    /// Whether the text says the event may not happen, e.g. "maybe drinks Friday", in which case the event's `STATUS`
+    /// is `TENTATIVE`.
+    pub tentative: bool,
+    /// How sure the parser is of the event's start time. Times less certain than the parser's minimum confidence are
+    /// left out of the event, which is then all day.
+    pub confidence: Confidence,
 }
 
 
 
 /// Parses `text` into an `Event` in `VEVENT` format [(RFC 5545, Section 3.6.1 )](https://tools.ietf.org/html/rfc5545#section-3.6.1).
 ///
+/// Relative dates are read around the local time, but the times are written as wall-clock times with a `Z` suffix
+/// rather than as the UTC times they happen at; use [`to_event_in_timezone`](fn.to_event_in_timezone.html) with `Local`
+/// for those.
+///
 /// # Arguments
 ///
 /// * `text` - A string slice that holds the the text to be parsed.
@@ -144,143 +398,1096 @@ enum EventStartAndEndExpr {
 /// assert!(equal(event, expected_event));
 /// ```
 pub fn to_event(text: &str) -> Event {
+    EventParser::default().parse(text)
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), reading the current date and time from `clock`.
+/// A [`FixedClock`](struct.FixedClock.html) gives the same event whenever it runs. The same as
+/// [`EventParser::parse_with_clock`](struct.EventParser.html#method.parse_with_clock) with the default parser.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `clock` - The source of the current date and time that relative dates and times are read around.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use event_parser::{to_event_with_clock, FixedClock};
+/// use icalendar::Component;
+///
+/// let clock = FixedClock(NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap());
+/// let event = to_event_with_clock("Dinner at 7pm tomorrow", &clock);
+///
+/// assert_eq!(event.properties().get("DTSTART").unwrap().value(), "20210302T190000Z");
+/// ```
+pub fn to_event_with_clock(text: &str, clock: &dyn Clock) -> Event {
+    EventParser::default().parse_with_clock(text, clock)
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), reading relative dates and times around `now`
+/// instead of the current time, so the same text and `now` always give the same event.
+///
+/// Only the wall-clock time of `now` is read; its offset is not applied, so the times are written as wall-clock times
+/// like those of [`to_event`](fn.to_event.html).
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `now` - The local date and time that relative dates and times, like "tomorrow" or "in 2 hours", are read around.
+///
+/// # Example
+/// ```
+/// use chrono::{Local, NaiveDate, TimeZone};
+/// use event_parser::to_event_relative;
+/// use icalendar::Component;
+///
+/// let now = Local.from_local_datetime(&NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap()).unwrap();
+/// let event = to_event_relative("Call Sam in 2 hours", now);
+///
+/// assert_eq!(event.properties()["DTSTART"].value(), "20210301T110000Z");
+/// ```
+pub fn to_event_relative(text: &str, now: DateTime<Local>) -> Event {
+    to_event_at(text, now.naive_local())
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), reading its times as wall-clock times in `tz`, so
+/// that `DTSTART` and `DTEND` are the UTC times the event really happens at. Relative dates and times, like "tomorrow"
+/// or "in 2 hours", are read around the current time in `tz`. All-day events keep their dates. A shortcut for a parser
+/// built with [`EventParserBuilder::timezone`](struct.EventParserBuilder.html#method.timezone).
+///
+/// [`to_event`](fn.to_event.html) instead writes the wall-clock times as they are, as if they were UTC.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `tz` - The timezone the text is written in, e.g. `Local`, a `FixedOffset` or a timezone from the `chrono-tz`
+///   crate.
+///
+/// # Example
+/// ```
+/// use chrono::FixedOffset;
+/// use event_parser::to_event_in_timezone;
+/// use icalendar::Component;
+///
+/// // 7pm at UTC-5 is midnight UTC
+/// let event = to_event_in_timezone("Dinner at 7pm", FixedOffset::west_opt(5 * 3600).unwrap());
+/// assert!(event.properties()["DTSTART"].value().ends_with("T000000Z"));
+/// ```
+pub fn to_event_in_timezone<Tz>(text: &str, tz: Tz) -> Event
+where
+    Tz: TimeZone + Send + Sync + 'static,
+{
+    EventParser::builder().timezone(tz).build().parse(text)
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), along with caveats about how the text was read,
+/// such as a weekday that does not match the date or a guessed am/pm. The same as
+/// [`EventParser::parse_with_warnings`](struct.EventParser.html#method.parse_with_warnings) with the default parser.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::{to_event_with_warnings, ParseWarning};
+///
+/// let parsed = to_event_with_warnings("Dinner at 7");
+/// assert!(matches!(parsed.warnings[..], [ParseWarning::AmbiguousMeridiem(_)]));
+/// ```
+pub fn to_event_with_warnings(text: &str) -> ParseOutcome {
+    EventParser::default().parse_with_warnings(text)
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), reading dates without a year or week in
+/// `direction`. With [`Direction::Past`](enum.Direction.html), used for journals and meeting minutes, "Friday" is the
+/// Friday just gone and a time with no date that is yet to come today is yesterday's. A shortcut for a parser built
+/// with [`EventParserBuilder::direction`](struct.EventParserBuilder.html#method.direction).
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `direction` - Whether dates are read toward the next or the last occurrence.
+///
+/// # Example
+/// ```
+/// use event_parser::{to_event_with_direction, Direction};
+/// use icalendar::Component;
+///
+/// let event = to_event_with_direction("Retro last Friday at 3pm", Direction::Past);
+/// assert_eq!(event.properties()["SUMMARY"].value(), "Retro");
+/// ```
+pub fn to_event_with_direction(text: &str, direction: Direction) -> Event {
+    EventParser::builder().direction(direction).build().parse(text)
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), rounding times worked out from now, like
+/// "in 2 hours" or "soon", with `rounding` so that events start on a round minute. A shortcut for a parser built with
+/// [`EventParserBuilder::rounding`](struct.EventParserBuilder.html#method.rounding).
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `rounding` - How to round times worked out from now.
+///
+/// # Example
+/// ```
+/// use event_parser::{to_event_with_rounding, Rounding};
+/// use icalendar::Component;
+///
+/// let event = to_event_with_rounding("Call mom in 2 hours", Rounding::Nearest15);
+/// let start = event.properties()["DTSTART"].value();
+///
+/// assert!(["00", "15", "30", "45"].contains(&&start[11..13]));
+/// ```
+pub fn to_event_with_rounding(text: &str, rounding: Rounding) -> Event {
+    EventParser::builder().rounding(rounding).build().parse(text)
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html), leaving out a start time less certain than
+/// `min_confidence`, so that automated pipelines do not schedule events at times the text may not give. A shortcut for a
+/// parser built with [`EventParserBuilder::min_confidence`](struct.EventParserBuilder.html#method.min_confidence).
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+/// * `min_confidence` - How sure the parser has to be of a time to keep it.
+///
+/// # Example
+/// ```
+/// use event_parser::{to_event_with_min_confidence, Confidence};
+/// use icalendar::Component;
+///
+/// let event = to_event_with_min_confidence("Meet in room 5", Confidence::Medium);
+/// assert_eq!(event.properties()["DTSTART"].value().len(), 8); // all day
+///
+/// let event = to_event_with_min_confidence("Meet in the lobby at 3pm", Confidence::Medium);
+/// assert!(event.properties()["DTSTART"].value().ends_with("150000Z"));
+/// ```
+pub fn to_event_with_min_confidence(text: &str, min_confidence: Confidence) -> Event {
+    EventParser::builder().min_confidence(min_confidence).build().parse(text)
+}
+
+/// Parses `text` into every `Event` it may describe, each with a score from 0 to 1 of how likely it is meant, most
+/// likely first, so that callers can offer the alternatives or drop those below a threshold. The first is the one
+/// [`to_event`](fn.to_event.html) gives. A slash date that reads both month first and day first, like "3/4", and an
+/// hour without am or pm, like "at 7", also give their other reading, and a bare number read as an hour, like the "5"
+/// of "Meet in room 5", also gives the event without a time.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::to_event_candidates;
+/// use icalendar::Component;
+///
+/// let candidates = to_event_candidates("Dinner at 7pm on 3/4");
+/// assert_eq!(candidates.len(), 2);
+/// assert!(candidates[0].0.properties()["DTSTART"].value().ends_with("0304T190000Z"));
+/// assert!(candidates[1].0.properties()["DTSTART"].value().ends_with("0403T190000Z"));
+/// assert!(candidates[0].1 > candidates[1].1);
+///
+/// assert_eq!(to_event_candidates("Dinner at 7pm tomorrow")[0].1, 1.0);
+/// ```
+pub fn to_event_candidates(text: &str) -> Vec<(Event, f32)> {
+    candidates_at(text, LocalClock.now(), &Parsers::default())
+}
+
+/// Parses `text` into every `Event` it may describe with respect to `now`, each with its score.
+fn candidates_at(text: &str, now: NaiveDateTime, parsers: &Parsers) -> Vec<(Event, f32)> {
+    let outcome = parse_at(text, now, parsers);
+    // each ambiguity splits every reading found so far into the one taken and the other
+    let split = |readings: Vec<(Parsers, f32)>, other: &dyn Fn(&Parsers) -> Parsers| {
+        readings
+            .into_iter()
+            .flat_map(|(taken, score)| {
+                let other = other(&taken);
+                vec![(taken, score * 0.6), (other, score * 0.4)]
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut readings = vec![(parsers.clone(), 1.0)];
+    if outcome.warnings.iter().any(|w| matches!(w, ParseWarning::AmbiguousDateOrder(..))) {
+        readings = split(readings, &|taken| {
+            let order = match taken.date.order() {
+                DateOrder::MonthDayYear => DateOrder::DayMonthYear,
+                _ => DateOrder::MonthDayYear,
+            };
+            Parsers { date: taken.date.clone().with_options(DateOptions { order }), ..taken.clone() }
+        });
+    }
+    let guessed = outcome.warnings.iter().find_map(|w| match w {
+        ParseWarning::AmbiguousMeridiem(time) => Some(*time),
+        _ => None,
+    });
+    if let Some(time) = guessed {
+        let other_half = if time.hour() < 12 { MeridiemPolicy::Pm } else { MeridiemPolicy::Am };
+        readings = split(readings, &|taken| Parsers { time: taken.time.clone().with_meridiem_policy(other_half), ..taken.clone() });
+    }
+    if outcome.confidence == Confidence::Low {
+        readings = split(readings, &|taken| Parsers { min_confidence: Confidence::Medium, ..taken.clone() });
+    }
+
+    let mut candidates: Vec<(Event, f32)> = Vec::new();
+    let mut seen = Vec::new();
+    let mut first = Some(outcome.event);
+    for (reading, score) in readings {
+        let event = first.take().unwrap_or_else(|| parse_at(text, now, &reading).event);
+        let when = ["DTSTART", "DTEND"].map(|key| event.properties().get(key).map(|p| p.value().to_owned()));
+        if !seen.contains(&when) {
+            seen.push(when);
+            candidates.push((event, score));
+        }
+    }
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+    candidates
+}
+
+/// Parses each of `texts` into an `Event` like [`to_event`](fn.to_event.html) and collects them into one `Calendar`,
+/// ready to be written out as a `VCALENDAR` with its `VERSION` and `PRODID` headers. Blank texts are skipped, and every
+/// text is read around the same current date and time.
+///
+/// # Arguments
+///
+/// * `texts` - The string slices to parse, one event each.
+///
+/// # Example
+/// ```
+/// use event_parser::to_calendar;
+///
+/// let calendar = to_calendar(vec!["Lunch at noon", "Dinner at 7pm tomorrow"]);
+/// let ics = calendar.to_string();
+///
+/// assert_eq!(calendar.len(), 2);
+/// assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+/// assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+/// ```
+pub fn to_calendar<'a>(texts: impl IntoIterator<Item = &'a str>) -> Calendar {
+    to_calendar_at(texts, LocalClock.now())
+}
+
+/// Parses `text` into an `Event` like [`to_event`](fn.to_event.html) and writes it out as a whole `VCALENDAR`, with the
+/// `VERSION` and `PRODID` of the calendar and a `UID` and `DTSTAMP` for the event, ready to be saved as an .ics file and
+/// imported.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::to_ics;
+///
+/// let ics = to_ics("Dinner at 7pm");
+///
+/// assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:"));
+/// assert!(ics.contains("\r\nUID:"));
+/// assert!(ics.contains("\r\nDTSTAMP:"));
+/// assert!(ics.contains("\r\nSUMMARY:Dinner\r\n"));
+/// ```
+pub fn to_ics(text: &str) -> String {
+    to_ics_at(text, LocalClock.now())
+}
+
+/// Parses `text` into an `Event` with respect to `now` and writes it out as a `VCALENDAR`.
+fn to_ics_at(text: &str, now: NaiveDateTime) -> String {
+    std::iter::once(to_event_at(text, now)).collect::<Calendar>().to_string()
+}
+
+/// Parses each of `texts` into an `Event` with respect to `now` and collects them into a `Calendar`.
+fn to_calendar_at<'a>(texts: impl IntoIterator<Item = &'a str>, now: NaiveDateTime) -> Calendar {
+    texts
+        .into_iter()
+        .filter(|text| !text.trim().is_empty())
+        .map(|text| to_event_at(text, now))
+        .collect()
+}
+
+/// Parses `text` into an `Event` with respect to `now`. Relative times that cross midnight land on the next day.
+pub(crate) fn to_event_at(text: &str, now: NaiveDateTime) -> Event {
+    parse_at(text, now, &Parsers::default()).event
+}
+
+/// The date and time parsers an `Event` is parsed with, so that their settings reach every date and time in the text,
+/// and what else is read from it.
+#[derive(Clone)]
+struct Parsers {
+    date: DateParser,
+    time: TimeParser,
+    /// How long events without an end or a duration last, unless they mention a word matched in `durations`
+    duration: Duration,
+    durations: Vec<(Regex, Duration)>,
+    recurrence: bool,
+    location: bool,
+    /// Whether people named after "with" and email addresses become `ATTENDEE`s
+    attendees: bool,
+    /// Whether the names of people the event is with stay in the summary
+    names_in_summary: bool,
+    /// Whether dated birthdays and anniversaries repeat every year
+    occasions: bool,
+    /// Whether events given a date but no time last all day, rather than starting in the morning
+    all_day: bool,
+    /// How sure a time has to be to be kept
+    min_confidence: Confidence,
+    /// The timezone wall-clock times are in, which events give as UTC instants. Without one, wall-clock times are
+    /// written as they are, as if they were UTC.
+    zone: Option<Zone>,
+}
+
+/// Turns a wall-clock time in a timezone into the UTC time it happens at.
+type Zone = Arc<dyn Fn(NaiveDateTime) -> NaiveDateTime + Send + Sync>;
+
+/// Returns the [`Zone`] of `tz`. A time skipped when clocks go forward is read an hour later, and one repeated when they
+/// go back is read the first time round.
+fn zone_of<Tz>(tz: Tz) -> Zone
+where
+    Tz: TimeZone + Send + Sync + 'static,
+{
+    Arc::new(move |local: NaiveDateTime| {
+        tz.from_local_datetime(&local)
+            .earliest()
+            .or_else(|| tz.from_local_datetime(&(local + Duration::hours(1))).earliest())
+            .map_or(local, |dt| dt.naive_utc())
+    })
+}
+
+/// Returns the [`Zone`] of a timezone named in the text.
+fn zone_mentioned(zone: TimeZoneMention) -> Zone {
+    match zone {
+        TimeZoneMention::Offset(offset) => zone_of(offset),
+        TimeZoneMention::Named(tz) => zone_of(tz),
+    }
+}
+
+impl Default for Parsers {
+    fn default() -> Parsers {
+        Parsers {
+            date: DateParser::new(),
+            time: TimeParser::new(),
+            duration: Duration::hours(1),
+            durations: Vec::new(),
+            recurrence: true,
+            location: true,
+            attendees: true,
+            names_in_summary: true,
+            occasions: false,
+            all_day: true,
+            min_confidence: Confidence::Low,
+            zone: None,
+        }
+    }
+}
+
+impl Parsers {
+    /// Returns how long an event described by `text` lasts when it gives no end or duration.
+    fn default_duration(&self, text: &str) -> Duration {
+        self.durations
+            .iter()
+            .find(|(word, _)| word.is_match(text))
+            .map_or(self.duration, |(_, duration)| *duration)
+    }
+
+    /// Returns when `local`, a wall-clock time in the timezone of these parsers, happens in UTC.
+    fn instant(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&self.zone.as_ref().map_or(local, |zone| zone(local)))
+    }
+
+    /// Finds a time in `text` with respect to `now`.
+    fn time(&self, text: &str, now: NaiveTime) -> Option<NaiveTime> {
+        self.time.find(text, now).map(|found| found.time)
+    }
+}
+
+/// Parses `text` into an `Event` and its warnings with respect to `now`, finding dates and times with `parsers`.
+fn parse_at(text: &str, now: NaiveDateTime, parsers: &Parsers) -> ParseOutcome {
+    parse_checked(text, now, parsers).0
+}
+
+/// Parses `text` like [`parse_at`], also returning why it was reported to the miss hook, if it was.
+fn parse_checked(text: &str, now: NaiveDateTime, parsers: &Parsers) -> (ParseOutcome, Option<MissReason>) {
     let mut e = Event::new();
 
-    let today = Local::today();
+    let input = text;
+    let text = normalize(text);
+    let text = text.as_str();
+    let kind = classify(text);
+
+    let today = now.date();
+
+    // a schedule like "every day for two weeks" is taken out first, so the rest gives the first occurrence
+    let recurrence = RecurParser::parse_relative(text, today).filter(|_| parsers.recurrence);
+    let text = recurrence.as_ref().map_or(text, |found| found.rest.as_str());
+
+    // the number in a duration like "2 hours" is not a time
+    let duration = DurationParser::find(text);
+    let mut timing = text.to_owned();
+    if let Some((_, span)) = &duration {
+        timing.replace_range(span.clone(), &" ".repeat(span.len()));
+    }
+    // the numbers in an email address are not dates or times, nor are phone numbers, prices and versions
+    for address in EMAIL.find_iter(text) {
+        timing.replace_range(address.range(), &" ".repeat(address.len()));
+    }
+    if parsers.time.context_filters() {
+        for number in find_non_temporal(text) {
+            timing.replace_range(number.range(), &" ".repeat(number.len()));
+        }
+    }
+    // a timezone named with the time, like "3pm EST", is what the time is in, so the event is given in UTC
+    let zoned;
+    let parsers = match TimeParser::find_zone(text) {
+        Some((zone, span)) => {
+            timing.replace_range(span.clone(), &" ".repeat(span.len()));
+            zoned = Parsers { zone: Some(zone_mentioned(zone)), ..parsers.clone() };
+            &zoned
+        }
+        None => parsers,
+    };
+    let expr = to_start_end_expr(&timing, now, parsers);
+
+    // a dated birthday or anniversary, like "Mom's birthday April 5", is all day and comes back every year
+    let occasion = match expr {
+        EventStartAndEndExpr::AllDay(d)
+        | EventStartAndEndExpr::StartsWithDate(_, d)
+        | EventStartAndEndExpr::StartsAndEndsWithDate(_, _, d)
+            if parsers.occasions && recurrence.is_none() && OCCASION.is_match(text) && !PARTY.is_match(text) =>
+        {
+            Some(d)
+        }
+        _ => None,
+    };
+    let expr = occasion.map_or(expr, EventStartAndEndExpr::AllDay);
+    // a duration given with only a part of the day, like "2 hours tomorrow afternoon", starts when that part does, and
+    // one given with only a date starts in the morning, as does any event with only a date when events are not all day
+    let expr = match (expr, duration.as_ref().and_then(|_| window_start(&timing, now, parsers))) {
+        (EventStartAndEndExpr::Starts(_), Some(start)) => EventStartAndEndExpr::Starts(start),
+        (EventStartAndEndExpr::StartsWithDate(_, d), Some(start)) => EventStartAndEndExpr::StartsWithDate(start, d),
+        (EventStartAndEndExpr::AllDay(d), _) if (duration.is_some() || !parsers.all_day) && occasion.is_none() => {
+            EventStartAndEndExpr::StartsWithDate(part_of_day("morning").0, d)
+        }
+        (expr, _) => expr,
+    };
+    // a schedule on set weekdays, like "MWF", starts on the first of them from the date given, and one on a day or
+    // weekday of the month, like "monthly on the 1st" or "the first Monday of every month", on the next such day
+    let expr = match recurrence.as_ref().map(|found| &found.recurrence) {
+        Some(r) if !r.days.is_empty() => expr.on_first_of(&r.days, today),
+        Some(Recurrence { month_day: Some(day), .. }) => expr.on_day_of_month(*day, today),
+        Some(Recurrence { month_weekday: Some((n, weekday)), .. }) => expr.on_weekday_of_month(*n, *weekday, today),
+        _ => expr,
+    };
+    // a time the parser is unsure of, like the "5" of "Meet in room 5", is dropped rather than guessed
+    let confidence = confidence(&timing, &expr, now, parsers);
+    let miss = match expr {
+        EventStartAndEndExpr::Unknown => Some(MissReason::NoDateOrTime),
+        _ if confidence == Confidence::Low => Some(MissReason::LowConfidence),
+        _ => None,
+    };
+    let expr = if confidence < parsers.min_confidence { expr.without_time() } else { expr };
+    let mut warnings = warnings(&timing, &expr, now, parsers);
+
+    if let Some(found) = &recurrence {
+        if found.ambiguous {
+            warnings.push(ParseWarning::AmbiguousInterval(found.recurrence.frequency));
+        }
+        let (date, time) = expr.start(today);
+        e.add_property("RRULE", &rrule(&found.recurrence, date, time, parsers));
+    }
+    if let Some(d) = occasion {
+        let yearly = Recurrence {
+            frequency: Frequency::Yearly,
+            interval: 1,
+            end: None,
+            days: Vec::new(),
+            month_day: None,
+            month_weekday: None,
+        };
+        e.add_property("RRULE", &rrule(&yearly, d, None, parsers));
+    }
+
+    // events without an end time last 1 hour, or as long as set for a word they mention, unless a duration like "2h"
+    // is given
+    let duration = duration.map_or_else(|| parsers.default_duration(text), |(duration, _)| duration);
 
-    let expr = to_start_end_expr(text);
+    // a time with no date is today, unless reading the past and it is yet to come today, or preferring the future and
+    // it has gone by
+    let day_of = |t: NaiveTime| match (today.pred_opt(), today.succ_opt()) {
+        (Some(yesterday), _) if parsers.date.direction() == Direction::Past && today.and_time(t) > now => yesterday,
+        (_, Some(tomorrow))
+            if parsers.date.direction() == Direction::Future && parsers.date.prefers_future() && today.and_time(t) < now =>
+        {
+            tomorrow
+        }
+        _ => today,
+    };
 
     match expr {
         EventStartAndEndExpr::Unknown => {
-            e.all_day(today);
+            e.all_day(utc_date(today));
         }
         EventStartAndEndExpr::Starts(t) => {
-            // default to today
-            let dt = DateTime::<Utc>::from_utc(NaiveDateTime::new(today.naive_utc(), t), Utc);
-            dt.with_timezone(&Local);
+            let dt = parsers.instant(NaiveDateTime::new(day_of(t), t));
 
             e.starts(dt);
-            e.ends(dt.checked_add_signed(Duration::hours(1)).unwrap()); // end is 1 hour after start
+            e.ends(dt.checked_add_signed(duration).unwrap());
         }
         EventStartAndEndExpr::AllDay(d) => {
-            e.all_day(Date::<Utc>::from_utc(d, Utc));
+            e.all_day(utc_date(d));
         }
         EventStartAndEndExpr::StartsWithDate(t, d) => {
-            let dt = DateTime::<Utc>::from_utc(NaiveDateTime::new(d, t), Utc);
-            dt.with_timezone(&Local);
+            let dt = parsers.instant(NaiveDateTime::new(d, t));
 
             e.starts(dt);
-            e.ends(dt.checked_add_signed(Duration::hours(1)).unwrap()); // end is 1 hour after start
-            println!("date: {:?}", dt)
+            e.ends(dt.checked_add_signed(duration).unwrap());
         }
         EventStartAndEndExpr::StartsAndEnds(start, end) => {
-            // default to today
-            let start_dt =
-                DateTime::<Utc>::from_utc(NaiveDateTime::new(today.naive_utc(), start), Utc);
-            start_dt.with_timezone(&Local);
-
-            let end_dt = DateTime::<Utc>::from_utc(NaiveDateTime::new(today.naive_utc(), end), Utc);
-            end_dt.with_timezone(&Local);
+            let start = NaiveDateTime::new(day_of(start), start);
+            let start_dt = parsers.instant(start);
+            let end_dt = parsers.instant(overnight_end(start, end));
 
             e.starts(start_dt);
             e.ends(end_dt);
         }
         EventStartAndEndExpr::StartsAndEndsWithDate(start, end, d) => {
-            let start_dt = DateTime::<Utc>::from_utc(NaiveDateTime::new(d, start), Utc);
-            start_dt.with_timezone(&Local);
-
-            let end_dt = DateTime::<Utc>::from_utc(NaiveDateTime::new(d, end), Utc);
-            end_dt.with_timezone(&Local);
+            let start = NaiveDateTime::new(d, start);
+            let start_dt = parsers.instant(start);
+            let end_dt = parsers.instant(overnight_end(start, end));
 
             e.starts(start_dt);
             e.ends(end_dt);
         }
         EventStartAndEndExpr::AllDayStartsAndEnds(start, end) => {
-            e.start_date(Date::<Utc>::from_utc(start, Utc));
-            e.end_date(Date::<Utc>::from_utc(end, Utc));
+            e.start_date(utc_date(start));
+            e.end_date(utc_date(end));
+        }
+        EventStartAndEndExpr::StartsAndEndsAt(start, end) => {
+            e.starts(parsers.instant(start));
+            e.ends(parsers.instant(end));
+        }
+        EventStartAndEndExpr::StartsAndEndsOn(start, end, dates) => {
+            let first = NaiveDateTime::new(dates[0], start);
+            e.starts(parsers.instant(first));
+            e.ends(parsers.instant(overnight_end(first, end)));
+            // the first date starts the event, the others repeat it
+            for d in &dates[1..] {
+                let rdate = parsers.instant(d.and_time(start)).format("%Y%m%dT%H%M%SZ").to_string();
+                e.append_multi_property(Property::new("RDATE", &rdate));
+            }
         }
+        EventStartAndEndExpr::AllDayOn(dates) => {
+            // the first date starts the event, the others repeat it
+            e.all_day(utc_date(dates[0]));
+            for d in &dates[1..] {
+                let rdate = Property::new("RDATE", &d.format("%Y%m%d").to_string())
+                    .add_parameter("VALUE", "DATE")
+                    .done();
+                e.append_multi_property(rdate);
+            }
+        }
+    }
+
+    let place = find_location(text).filter(|_| parsers.location);
+    if let Some(place) = place.clone() {
+        e.location(&text[place]);
     }
 
-    // location parsing is not yet supported
-    // if let Some(loc) = get_location(text) {
-    //     e.location(&loc);
-    // }
+    if parsers.attendees {
+        for person in find_participants(text) {
+            e.append_multi_property(person.attendee());
+        }
+    }
 
-    if let Some(summary) = summary(text) {
+    if let Some(summary) = summary_around(text, place, parsers.names_in_summary) {
         e.summary(&summary);
     }
 
-    e.done()
+    let tentative = TENTATIVE.is_match(text);
+    if tentative {
+        e.status(EventStatus::Tentative);
+    }
+
+    // times estimated from vague phrases like "soon" are flagged
+    if let Some(found) = parsers.time.find(text, now.time()) {
+        if found.approximate {
+            e.add_property("X-APPROXIMATE", "TRUE");
+        }
+    }
+
+    let outcome = ParseOutcome {
+        event: e.done(),
+        warnings,
+        kind,
+        tentative,
+        confidence,
+    };
+    if let Some(reason) = miss {
+        feedback::report(input, reason, &outcome);
+    }
+    (outcome, miss)
 }
 
-/// Parses `text` with `date_parser` and `time_parser` to return an `Option` containing an `EventStartAndEndExpr`.
-fn to_start_end_expr(text: &str) -> EventStartAndEndExpr {
-    // Hack: look for {'-', "to"}, if found, then it's a StartsAndEnds, StartsAndEndsWithDate, or AllDayStartsAndEnds
-    //  Get expressions before and after {'-', "to"}
-    let re = Regex::new(r"(?P<start>[/\w]+)(\s?(-|to)\s?)(?P<end>[/\w]+)").unwrap();
-    if let Some(caps) = re.captures(text) {
-        if let Some(start_match) = caps.name("start") {
-            if let Some(start_time) = TimeParser::parse(start_match.as_str()) {
-                if let Some(end_match) = caps.name("end") {
-                    if let Some(end_time) = TimeParser::parse(end_match.as_str()) {
-                        if let Some(date) = DateParser::parse(text) {
-                            return EventStartAndEndExpr::StartsAndEndsWithDate(
-                                start_time, end_time, date,
-                            );
-                        }
+/// Returns how sure the parser is of the start time of `expr`, read from `text` with respect to `now`.
+fn confidence(text: &str, expr: &EventStartAndEndExpr, now: NaiveDateTime, parsers: &Parsers) -> Confidence {
+    if expr.start(now.date()).1.is_none() {
+        return Confidence::High;
+    }
+    match parsers.time.find(text, now.time()) {
+        Some(found) if found.approximate => Confidence::Medium,
+        Some(found) if found.guessed_meridiem && !stated_hour(text) => Confidence::Low,
+        Some(found) if found.guessed_meridiem => Confidence::Medium,
+        _ => Confidence::High,
+    }
+}
 
-                        return EventStartAndEndExpr::StartsAndEnds(start_time, end_time);
-                    }
-                }
+/// Returns `true` if `text` gives an hour that reads as a time even without am or pm, like "at 7", "half past 7" or
+/// "nine fifteen", rather than a number like the "5" of "room 5".
+fn stated_hour(text: &str) -> bool {
+    STATED_HOUR.is_match(text)
+        || COLLOQUIAL_TIME.is_match(text)
+        || WORD_TIME.captures_iter(text).any(|caps| is_word_time(&caps, text))
+}
+
+/// Returns caveats about how `text` was read into `expr` with respect to `now`.
+fn warnings(text: &str, expr: &EventStartAndEndExpr, now: NaiveDateTime, parsers: &Parsers) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    let dates = expr.dates(now.date());
+
+    // dates written out like "June 5", "4 July" or "6/5/2021"
+    for caps in DATE_MENTION.captures_iter(text) {
+        let day = caps.name("day").or_else(|| caps.name("first_day")).or_else(|| caps.name("numeric_day")).or_else(|| caps.name("iso_day")).or_else(|| caps.name("nth_day"));
+        let day: u32 = day.unwrap().as_str().parse().unwrap();
+        if let Some(date) = parsers.date.find_at(&caps[0], now) {
+            if date.day() < day {
+                warnings.push(ParseWarning::ClampedDay(day, date));
+            }
+            if !dates.contains(&date) {
+                warnings.push(ParseWarning::IgnoredDate(date));
+            }
+        }
+        if let [month_first, day_first] = DateParser::slash_date_readings(&caps[0], now.date(), "en_US")[..] {
+            warnings.push(ParseWarning::AmbiguousDateOrder(month_first, day_first));
+        }
+    }
+
+    for caps in WEEKDAY.captures_iter(text) {
+        let weekday = weekday_named(&caps["day"]).unwrap();
+        // a range whose ends could not both be read has no dates to check the weekday against
+        match dates.first() {
+            Some(&first) if !dates.iter().any(|d| d.weekday() == weekday) => {
+                warnings.push(ParseWarning::WeekdayMismatch(weekday, first));
             }
+            _ => {}
+        }
+    }
 
-            if let Some(start_date) = DateParser::parse(start_match.as_str()) {
-                if let Some(end_match) = caps.name("end") {
-                    if let Some(end_date) = DateParser::parse(end_match.as_str()) {
-                        return EventStartAndEndExpr::AllDayStartsAndEnds(start_date, end_date);
-                    }
-                }
+    // a meridiem on either end of a range settles both
+    if let (_, Some(time)) = expr.start(now.date()) {
+        let guessed = parsers.time.find(text, now.time()).is_some_and(|found| found.guessed_meridiem);
+        if guessed && !MERIDIEM.is_match(text) {
+            warnings.push(ParseWarning::AmbiguousMeridiem(time));
+        }
+    }
+
+    warnings
+}
+
+/// Formats `recurrence` as an `RRULE` value [(RFC 5545, Section 3.8.5.3)](https://tools.ietf.org/html/rfc5545#section-3.8.5.3)
+/// for an event first starting on `date`, at `time` unless it is all-day, with its `UNTIL` in the timezone of `parsers`.
+fn rrule(recurrence: &Recurrence, date: NaiveDate, time: Option<NaiveTime>, parsers: &Parsers) -> String {
+    let mut rule = format!("FREQ={:?}", recurrence.frequency).to_uppercase();
+    if recurrence.interval > 1 {
+        rule.push_str(&format!(";INTERVAL={}", recurrence.interval));
+    }
+    if !recurrence.days.is_empty() {
+        let days: Vec<String> = recurrence.days.iter().map(|d| format!("{:?}", d)[..2].to_uppercase()).collect();
+        rule.push_str(&format!(";BYDAY={}", days.join(",")));
+    }
+    if let Some(day) = recurrence.month_day {
+        rule.push_str(&format!(";BYMONTHDAY={}", day));
+    }
+    if let Some((n, weekday)) = recurrence.month_weekday {
+        rule.push_str(&format!(";BYDAY={}{}", n, &format!("{:?}", weekday)[..2].to_uppercase()));
+    }
+    match recurrence.resolve_end(date) {
+        Some(RecurrenceEnd::Count(n)) => rule.push_str(&format!(";COUNT={}", n)),
+        // UNTIL takes the same form as DTSTART
+        Some(RecurrenceEnd::Until(until)) => match time {
+            Some(t) => rule.push_str(&parsers.instant(until.and_time(t)).format(";UNTIL=%Y%m%dT%H%M%SZ").to_string()),
+            None => rule.push_str(&until.format(";UNTIL=%Y%m%d").to_string()),
+        },
+        _ => {}
+    }
+    rule
+}
+
+/// Returns when an event starting at `start` and ending at `end` ends, which is the next day for ranges that cross
+/// midnight, like "10pm-2am".
+fn overnight_end(start: NaiveDateTime, end: NaiveTime) -> NaiveDateTime {
+    let same_day = start.date().and_time(end);
+    if same_day < start {
+        same_day + Duration::days(1)
+    } else {
+        same_day
+    }
+}
+
+/// Wraps a `NaiveDate` in the `chrono::Date` that icalendar's date-only setters still expect.
+#[allow(deprecated)]
+fn utc_date(d: NaiveDate) -> chrono::Date<Utc> {
+    Utc.from_utc_date(&d)
+}
+
+/// Returns the hours covered by a part of the day like "morning".
+pub(crate) fn part_of_day(part: &str) -> (NaiveTime, NaiveTime) {
+    let (start, end) = match part.to_lowercase().as_str() {
+        "morning" => (9, 12),
+        "afternoon" => (12, 17),
+        "evening" => (17, 21),
+        _ => (21, 0),
+    };
+    (NaiveTime::from_hms_opt(start, 0, 0).unwrap(), NaiveTime::from_hms_opt(end, 0, 0).unwrap())
+}
+
+/// Returns when the part of the day named in `text`, like "afternoon", starts, if no other time is given.
+fn window_start(text: &str, now: NaiveDateTime, parsers: &Parsers) -> Option<NaiveTime> {
+    let part = PART_OF_DAY.find(text)?;
+    let rest = format!("{} {}", &text[..part.start()], &text[part.end()..]);
+    match parsers.time(&rest, now.time()) {
+        Some(_) => None,
+        None => Some(part_of_day(part.as_str()).0),
+    }
+}
+
+/// A range whose sides may each carry a date and time, like "June 5 7pm to June 6 2am", with the captures `start` and `end`.
+static DATETIME_RANGE: Pattern =
+    Pattern::new(r"^(?P<start>.+?)\s?(\bto\b|-|–|—|\bthrough\b|\bthru\b|\btill?\b|\buntil\b)\s?(?P<end>.+)$");
+
+/// Parses `text` with `date_parser` and `time_parser` with respect to `now` to return an `Option` containing an `EventStartAndEndExpr`.
+fn to_start_end_expr(text: &str, now: NaiveDateTime, parsers: &Parsers) -> EventStartAndEndExpr {
+    // with British times read, "20 to 8" is a time rather than a range
+    let colloquial = parsers.time.british_times() && COLLOQUIAL_TIME.is_match(text);
+
+    if let Some(expr) = iso_expr(text, now, parsers) {
+        return expr;
+    }
+
+    // each side of a range like "June 5 7pm to June 6 2am" or "Friday 5pm to Sunday noon" may carry its own date and time
+    if let Some(caps) = DATETIME_RANGE.captures(text).filter(|_| !colloquial) {
+        if let Some((start, end)) = datetime_range(&caps["start"], &caps["end"], now, parsers) {
+            return EventStartAndEndExpr::StartsAndEndsAt(start, end);
+        }
+    }
+
+    // a range like "7-9pm", "from 7 to 9", "between 2 and 4" or "6/1-6/8" is a StartsAndEnds, StartsAndEndsWithDate,
+    // StartsAndEndsOn or AllDayStartsAndEnds
+    // the first range whose sides are times or dates is read, so "to" inside a word, like "tomorrow", is passed over
+    for range in ranges(text).filter(|_| !colloquial) {
+        // the sides of "till" and "between" are times even when written in words, like "seven until nine pm"
+        let stated = |side: &str| parsers.time(&format!("at {}", side), now.time());
+        let time = |side: &str| match parsers.time(side, now.time()) {
+            None if range.times_only => stated(side),
+            time => time,
+        };
+        // a four-digit side on the quarter hour, like the "1700" of "0900-1700", "midnight to 1700" or "1300-1700", is
+        // a time, unlike rooms or years like "1203-1205"
+        let quarter = |side: &str| Some(side).filter(|side| on_the_quarter(side)).and_then(stated);
+        let sides = match (time(range.start), time(range.end)) {
+            (Some(start_time), None) => (Some(start_time), quarter(range.end)),
+            (None, Some(end_time)) => (quarter(range.start), Some(end_time)),
+            (None, None) => (quarter(range.start), quarter(range.end)),
+            sides => sides,
+        };
+        if let (Some(start_time), Some(end_time)) = sides {
+            let (start_time, end_time) = propagate_meridiem(range.start, start_time, range.end, end_time);
+            // the dates given apply to both ends, e.g. "6/1 9am-5pm" or "9am-5pm on June 1 and 2"
+            if let Some(mut dates) = parsers.date.find_list(text, now.date()) {
+                dates.sort();
+                dates.dedup();
+                return EventStartAndEndExpr::StartsAndEndsOn(start_time, end_time, dates);
+            }
+            if let Some(date) = parsers.date.find_at(text, now) {
+                return EventStartAndEndExpr::StartsAndEndsWithDate(start_time, end_time, date);
+            }
+
+            return EventStartAndEndExpr::StartsAndEnds(start_time, end_time);
+        }
+
+        if !range.times_only {
+            // a run of weekdays like "Mon-Fri" ends on or after the day it starts
+            if let Some((start_date, end_date)) = parsers.date.find_span(&text[range.span.clone()], now.date()) {
+                return EventStartAndEndExpr::AllDayStartsAndEnds(start_date, end_date);
+            }
+            if let (Some(start_date), Some(end_date)) = (parsers.date.find_at(range.start, now), parsers.date.find_at(range.end, now)) {
+                return EventStartAndEndExpr::AllDayStartsAndEnds(start_date, end_date);
             }
         }
     }
 
-    if let Some(start_time) = TimeParser::parse(text) {
-        if let Some(start_date) = DateParser::parse(text) {
+    // consecutive dates like "June 5, 6, and 7" span a multi-day event, others like "June 5 and June 12" repeat it
+    if let Some(mut dates) = parsers.date.find_list(text, now.date()) {
+        dates.sort();
+        dates.dedup();
+        let first = dates[0];
+        let last = dates[dates.len() - 1];
+        if (last - first).num_days() as usize == dates.len() - 1 {
+            return EventStartAndEndExpr::AllDayStartsAndEnds(first, last);
+        }
+        return EventStartAndEndExpr::AllDayOn(dates);
+    }
+
+    if let Some((start_date, end_date)) = parsers.date.find_span(text, now.date()) {
+        return EventStartAndEndExpr::AllDayStartsAndEnds(start_date, end_date);
+    }
+
+    if let Some(start_time) = parsers.time(text, now.time()) {
+        if let Some(start_date) = parsers.date.find_at(text, now) {
             return EventStartAndEndExpr::StartsWithDate(start_time, start_date);
         }
         return EventStartAndEndExpr::Starts(start_time);
     }
 
-    if let Some(start_date) = DateParser::parse(text) {
+    if let Some(start_date) = parsers.date.find_at(text, now) {
         return EventStartAndEndExpr::AllDay(start_date);
     }
 
     EventStartAndEndExpr::Unknown
 }
 
+/// Returns whether `side` is a 24-hour time of four digits on the quarter hour, like "1300" or "0945".
+fn on_the_quarter(side: &str) -> bool {
+    side.len() == 4
+        && side.bytes().all(|b| b.is_ascii_digit())
+        && side[..2].parse::<u32>().is_ok_and(|hour| hour < 24)
+        && side[2..].parse::<u32>().is_ok_and(|minute| minute % 15 == 0 && minute < 60)
+}
+
+/// Reads the dates and times written in ISO 8601 in `text`, like "2021-06-05T14:30" or "2021-06-05 to 2021-06-08",
+/// whose hyphens are not ranges. A date alone takes its time from the rest of the text, as does the end of a date and
+/// time, like the "15:30" of "2021-06-05T14:00 until 15:30".
+fn iso_expr(text: &str, now: NaiveDateTime, parsers: &Parsers) -> Option<EventStartAndEndExpr> {
+    let found: Vec<_> = ISO_DATE_TIME.find_iter(text).collect();
+    let first = found.first()?;
+    let mut rest = text.to_owned();
+    rest.replace_range(first.range(), &" ".repeat(first.len()));
+    // a date that does not exist, like "2021-02-30", is left out rather than read in pieces
+    let start_date = match parsers.date.find(first.as_str(), now.date()) {
+        Some(date) => date,
+        None => return Some(to_start_end_expr(&rest, now, parsers)),
+    };
+    let start_time = parsers.time(first.as_str(), now.time());
+
+    if let Some(second) = found.get(1) {
+        let end_date = parsers.date.find(second.as_str(), now.date())?;
+        return Some(match (start_time, parsers.time(second.as_str(), now.time())) {
+            (Some(start), Some(end)) => {
+                EventStartAndEndExpr::StartsAndEndsAt(start_date.and_time(start), end_date.and_time(end))
+            }
+            _ => EventStartAndEndExpr::AllDayStartsAndEnds(start_date, end_date),
+        });
+    }
+
+    Some(match (start_time, to_start_end_expr(&rest, now, parsers)) {
+        (Some(start), EventStartAndEndExpr::Starts(end)) if end > start => {
+            EventStartAndEndExpr::StartsAndEndsWithDate(start, end, start_date)
+        }
+        (Some(start), _) => EventStartAndEndExpr::StartsWithDate(start, start_date),
+        (None, EventStartAndEndExpr::Starts(start)) => EventStartAndEndExpr::StartsWithDate(start, start_date),
+        (None, EventStartAndEndExpr::StartsAndEnds(start, end)) => {
+            EventStartAndEndExpr::StartsAndEndsWithDate(start, end, start_date)
+        }
+        (None, _) => EventStartAndEndExpr::AllDay(start_date),
+    })
+}
+
+/// Infers the meridiem missing from one end of a time range like "7-9pm" or "7pm-9" from the other end, taking the
+/// opposite meridiem when the same one would put the range out of order, as in "11-1pm".
+pub(crate) fn propagate_meridiem(start_text: &str, start: NaiveTime, end_text: &str, end: NaiveTime) -> (NaiveTime, NaiveTime) {
+    let with_meridiem_of = |t: NaiveTime, other: NaiveTime| {
+        NaiveTime::from_hms_opt(t.hour() % 12 + other.hour() / 12 * 12, t.minute(), 0).unwrap_or(t)
+    };
+    let flip = |t: NaiveTime| t.overflowing_add_signed(Duration::hours(12)).0;
+
+    let has_meridiem = |text: &str| {
+        MERIDIEM.is_match(text) || WORD_TIME.captures(text).is_some_and(|caps| caps.name("meridiem").is_some())
+    };
+    match (has_meridiem(start_text), has_meridiem(end_text)) {
+        (false, true) => {
+            let start = with_meridiem_of(start, end);
+            (if start > end { flip(start) } else { start }, end)
+        }
+        (true, false) => {
+            let end = with_meridiem_of(end, start);
+            (start, if end <= start { flip(end) } else { end })
+        }
+        // "7-9" reads 9 as 9am on its own
+        (false, false) if end < start && end.hour() < 12 => (start, flip(end)),
+        _ => (start, end),
+    }
+}
+
+/// Returns an `Option` containing the date and time in `text` if it gives both.
+fn to_datetime(text: &str, now: NaiveDateTime, parsers: &Parsers) -> Option<NaiveDateTime> {
+    let time = parsers.time(text, now.time())?;
+    let date = parsers.date.find_at(text, now)?;
+    Some(NaiveDateTime::new(date, time))
+}
+
+/// Returns when a range of days starts and ends if `start_text` gives a date and time and `end_text` a later date,
+/// with or without a time, like "Friday 5pm" and "Sunday noon". An end without a time lasts the whole of its day, and
+/// one that would come before the start, like a "Sunday" read as this week's, is read from the start instead.
+fn datetime_range(
+    start_text: &str,
+    end_text: &str,
+    now: NaiveDateTime,
+    parsers: &Parsers,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let start = to_datetime(start_text, now, parsers)?;
+    let end_date = match parsers.date.find_at(end_text, now)? {
+        date if date < start.date() => parsers.date.find_at(end_text, start)?,
+        date => date,
+    };
+    let end = match parsers.time(end_text, now.time()) {
+        Some(time) => end_date.and_time(time),
+        None => end_date.succ_opt()?.and_time(NaiveTime::MIN),
+    };
+    Some((start, end)).filter(|(start, end)| end > start)
+}
+
 /// Returns an `Option` containing an event's summary string parsed from `text`.
+#[cfg(test)]
 fn summary(text: &str) -> Option<String> {
+    summary_around(text, find_location(text), true)
+}
+
+/// Returns the summary of `text` leaving out the place at `place`, if any, and the people in it, keeping their names if
+/// `keep_names`.
+fn summary_around(text: &str, place: Option<Range<usize>>, keep_names: bool) -> Option<String> {
     let mut clean_text = text.to_string();
+    if let Some(place) = place {
+        clean_text.replace_range(place, "");
+    }
+    // the marks set below are private-use characters, so any already in the text are dropped rather than read as marks
+    clean_text.retain(|c| !MARKS.contains(&c));
+    clean_text = without_participants(&clean_text, keep_names);
+    // numbers that are not dates or times, like "$5" or "555-1230", stay as they are, set aside while the rest is
+    // taken out
+    let spans: Vec<Range<usize>> = find_non_temporal(&clean_text).map(|m| m.range()).collect();
+    let kept: Vec<String> = spans.iter().map(|span| clean_text[span.clone()].to_owned()).collect();
+    for (i, span) in spans.into_iter().enumerate().rev() {
+        clean_text.replace_range(span, &format!("\u{E001}{}\u{E001}", "\u{E000}".repeat(i + 1)));
+    }
+    // an ISO 8601 date and time goes with its offset, which is not a timezone name
+    clean_text = ISO_DATE_TIME.replace_all(&clean_text, "").to_string();
+    // a timezone is taken out from after its time, which goes with the other patterns
+    clean_text = TIME_ZONE.replace_all(&clean_text, "${before}").to_string();
+    // numbers in words are only taken out when they are times, unlike the "two" of "two friends"
+    let words = clean_text.clone();
+    clean_text = WORD_TIME
+        .replace_all(&words, |caps: &regex::Captures| if is_word_time(caps, &words) { String::new() } else { caps[0].to_owned() })
+        .to_string();
     // replace all patterns with ""
-    let set = vec![
-        r"\d{1,2}/(\d{1,2})",                            // dates
-        r"(\d{1,2})(/)(\d{1,2})(/)(\d{4}|\d{2})",        // dates
-        r"(?i)(^|\b)(\d{1,2}):?(\d{2})?([ap]m?)?($|\b)", // times
-        r"(?i)(jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)\s(?P<date>\d{1,2})?", // month dates
-        r"(?i)(mon|tue|wed|thurs|fri|sat|sun)(r?day|r?sday|nesay|urday)?\b", // weekdays
-        r"(?i)(next|last|this)\s\w+",                                        // relative words
-        r"(?i)\b(at|in|on|from|next|this|last|morning|afternoon|evening|night|noon|afternoon|tomorrow)\b",
-        r"(?i)-",
+    let set = [
+        &BETWEEN_RANGE,
+        &TILL_RANGE,
+        &LISTED_DAYS,
+        &NUMERIC_DATE_WITH_YEAR,
+        &NUMERIC_DATE,
+        &ISO_WEEK,
+        &DAY_MONTH,
+        &ORDINAL_DATE,
+        &DAY_OF_MONTH,
+        &NTH_WEEKDAY_OF_MONTH,
+        &PERIOD,
+        &SEASON,
+        &QUARTER,
+        &COLLOQUIAL_TIME,
+        &CONTINENTAL_TIME,
+        &MILITARY_TIME,
+        &RELATIVE_OFFSET,
+        &DATE_OFFSET,
+        &AFTER_NEXT,
+        &DAY_AROUND,
+        &CASUAL_TIME,
+        &DURATION,
+        &CLOCK_TIME,
+        &MONTH_DATE,
+        &WEEKDAY,
+        &SPAN,
+        &WEEKEND_OF,
+        &WEEK_OF,
+        &WEEKEND,
+        &RELATIVE_WORDS,
+        &TENTATIVE,
+        &FILLER_WORDS,
+        &DANGLING_TO,
+        &DASH,
     ]; // words to replace
 
-    for pattern in set {
-        let re = Regex::new(pattern).unwrap();
-        clean_text = re.replace_all(&clean_text, "").to_string();
+    // each part taken out leaves a mark, so a connector left only between them, like the "and" of "June 5 and June
+    // 12" or "Mon and Wed", can be taken out too
+    for re in set {
+        let words = clean_text.clone();
+        clean_text = re
+            .replace_all(&words, |caps: &regex::Captures| {
+                let found = caps.get(0).unwrap();
+                // a part of the day in a name, like the "Evening" of "Evening Star cafe", stays
+                let casual = std::ptr::eq(re, &FILLER_WORDS) || std::ptr::eq(re, &CASUAL_TIME);
+                if casual && in_name(&words, &found) { found.as_str().to_owned() } else { "\u{E002}".to_owned() }
+            })
+            .to_string();
     }
+    clean_text = LISTED_CONNECTOR.replace_all(&clean_text, "\u{E002}").replace('\u{E002}', "");
+    clean_text = KEPT_NUMBER
+        .replace_all(&clean_text, |caps: &regex::Captures| kept.get(caps[1].chars().count() - 1).map_or(&caps[0], |number| number).to_owned())
+        .to_string();
 
-    Some(clean_text.trim().to_owned())
+    // punctuation left stranded between the words taken out, like the "," of "Lunch at noon, tomorrow", is dropped
+    let words: Vec<&str> = clean_text.split_whitespace().filter(|word| is_word(word)).collect();
+    Some(trim_trailing_punctuation(&words.join(" ")).to_owned())
 }
 
+// Patterns only the summary takes out, alongside the shared ones from `date_time_parser::patterns`
+static LISTED_DAYS: Pattern = Pattern::new(r"(?i)(,?\s(and|&)|,)\s\d{1,2}(st|nd|rd|th)?\b");
+static RELATIVE_OFFSET: Pattern = Pattern::new(
+    r"(?i)\bin\s(\d{1,3}|an?|(twenty|thirty|forty|fifty)([\s-](one|two|three|four|five|six|seven|eight|nine))?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen)(\sand\sa\shalf)?\s(days?|weeks?|months?|years?|hours?|hrs?|minutes?|mins?)(\sand\sa\shalf)?\b",
+);
+static DATE_OFFSET: Pattern = Pattern::new(
+    r"(?i)\b(\d{1,3}|an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s(days?|weeks?|months?|years?)\s(ago|from\snow|from|after|before)\b",
+);
+static AFTER_NEXT: Pattern = Pattern::new(r"(?i)\bthe\s\w+\safter\snext\b");
+static DAY_AROUND: Pattern =
+    Pattern::new(r"(?i)\b(the\s)?(day\safter\stomorrow|day\sbefore\syesterday|night\sbefore\slast)\b");
+static CASUAL_TIME: Pattern = Pattern::new(
+    r"(?i)\b((by|at|before)\s)?(first\sthing|lunch\s?time|dinner\s?time|eod|end\sof\s(the\s)?day|close\sof\sbusiness|(?-i:COB))\b|\b(by|at|before)\scob\b",
+);
+static WEEKEND_OF: Pattern = Pattern::new(r"(?i)\b(the\s)?weekend\sof\b");
+static WEEK_OF: Pattern = Pattern::new(r"(?i)\b(the\s)?week\sof\b");
+static RELATIVE_WORDS: Pattern = Pattern::new(r"(?i)(next|last|this)\s\w+");
+static FILLER_WORDS: Pattern = Pattern::new(
+    r"(?i)\b(at|in|on|from|next|this|last|morning|afternoon|evening|tonight|night|noon|midnight|tomorrow)\b",
+);
+/// Parts of the day said instead of times, e.g. "Thursday morning", read as the hours they cover by [`part_of_day`].
+pub(crate) static PART_OF_DAY: Pattern = Pattern::new(r"(?i)\b(?P<part>morning|afternoon|evening|night)\b");
+/// Words saying an event may not happen, e.g. "maybe" or "tentatively".
+static TENTATIVE: Pattern =
+    Pattern::new(r"(?i)\b(maybe|perhaps|possibly|probably|might|tentative(ly)?|tbc|tbd|pencil(ed)?\sin)\b");
+// an hour without am or pm that still reads as a time, after "at" or "half", with minutes or in a range
+static STATED_HOUR: Pattern = Pattern::new(
+    r"(?i)(\b(at|around|from|until|till|til|by|half|past)\s|@\s?)\d{1,2}\b|\b\d{1,2}:\d{2}\b|\b\d{1,2}\s?(-|to)\s?\d{1,2}\b",
+);
+static OCCASION: Pattern = Pattern::new(r"(?i)\b(birthday|b-?day|anniversary)\b");
+static PARTY: Pattern = Pattern::new(r"(?i)\bpart(y|ies)\b"); // a birthday party happens once
+static DANGLING_TO: Pattern = Pattern::new(r"(?i)^[\s\x{E002}]*(to|through|thru|until|till)\b|\b(to|through|thru|until|till)[\s\x{E002}]*$"); // range connectors left dangling
+static DASH: Pattern = Pattern::new(r"(?i)[-–—]");
+// connectors between parts of the text the summary takes out, marked as they are
+static LISTED_CONNECTOR: Pattern = Pattern::new(r"(?i)\x{E002}((\s*(,|\band\b|&|\bor\b)\s*)+\x{E002})+");
+/// The private-use characters the summary marks the text with while taking parts out of it.
+const MARKS: [char; 3] = ['\u{E000}', '\u{E001}', '\u{E002}'];
+/// A number set aside from the summary's patterns, marked by how many of it came before.
+static KEPT_NUMBER: Pattern = Pattern::new("\u{E001}(\u{E000}+)\u{E001}");
+
 /// Pretty prints formatted `Event` to the standard output. Returns `Void` and prints to `stdout`.
 ///
 /// # Arguments
@@ -297,98 +1504,452 @@ fn summary(text: &str) -> Option<String> {
 /// ```
 /// Output:
 /// ```txt
-/// date: 2020-05-01T12:00:00Z
 /// Event: "Lunch"
 /// 12:00pm May 01 2020 - 01:00pm May 01 2020
 /// ```
 pub fn pretty_print(e: Event) {
+    write_pretty(&mut std::io::stdout().lock(), &e, HourFormat::default()).unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// How times are written by [`write_events_with_format`](fn.write_events_with_format.html).
+pub enum HourFormat {
+    /// On a 12-hour clock, e.g. "07:00pm"
+    #[default]
+    TwelveHour,
+
+    /// On a 24-hour clock, e.g. "19:00"
+    TwentyFourHour,
+}
+
+/// Writes `e` to `w` in the format [`pretty_print`](fn.pretty_print.html) prints, with times written in `hours`.
+fn write_pretty(w: &mut dyn Write, e: &Event, hours: HourFormat) -> io::Result<()> {
     // if start exists
     //  look for end
 
     if e.properties().contains_key("SUMMARY") {
-        println!(
+        writeln!(
+            w,
             "Event: {:?}",
             e.properties().get("SUMMARY").unwrap().value()
-        );
+        )?;
     }
 
     if e.properties().contains_key("LOCATION") {
-        println!(
+        writeln!(
+            w,
             "Location: {:?}",
             e.properties().get("LOCATION").unwrap().value()
-        );
+        )?;
     }
 
-    if e.properties().contains_key("DTSTART") {
-        let start_ndt = convert_ical_datetime(&e, "DTSTART");
-        if e.properties().contains_key("DTEND") {
-            let end_ndt = convert_ical_datetime(&e, "DTEND");
-            println!(
+    if let Some(start_ndt) = ical_datetime(e, "DTSTART") {
+        if let Some(end_ndt) = ical_datetime(e, "DTEND") {
+            let time = match hours {
+                HourFormat::TwelveHour => "%I:%M%P",
+                HourFormat::TwentyFourHour => "%H:%M",
+            };
+            writeln!(
+                w,
                 "{} {} - {} {}",
-                start_ndt.format("%I:%M%P"),
+                start_ndt.format(time),
                 start_ndt.format("%B %d %Y"),
-                end_ndt.format("%I:%M%P"),
+                end_ndt.format(time),
                 end_ndt.format("%B %d %Y"),
-            );
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns when `e` starts as a local date and time. `to_event` writes the wall-clock time the text names, read
+/// around the local clock, so this gives that same time back in the local timezone. All-day events start at local
+/// midnight. Returns `None` if `e` has no start or it falls in a gap skipped by daylight saving time.
+///
+/// # Example
+/// ```
+/// use event_parser::{start_local, to_event};
+/// use chrono::Timelike;
+///
+/// let event = to_event("Dinner at 7pm");
+/// assert_eq!(start_local(&event).unwrap().hour(), 19);
+/// ```
+pub fn start_local(e: &Event) -> Option<DateTime<Local>> {
+    Local.from_local_datetime(&ical_datetime(e, "DTSTART")?).earliest()
+}
+
+/// Returns when `e` ends as a local date and time, like [`start_local`](fn.start_local.html) does for its start.
+pub fn end_local(e: &Event) -> Option<DateTime<Local>> {
+    Local.from_local_datetime(&ical_datetime(e, "DTEND")?).earliest()
+}
+
+/// Returns when `e` starts as a date and time at `offset`, reading its wall-clock time as the time there. Returns
+/// `None` if `e` has no start.
+///
+/// # Example
+/// ```
+/// use event_parser::{start_with_offset, to_event};
+/// use chrono::{FixedOffset, Timelike};
+///
+/// let event = to_event("Dinner at 7pm");
+/// let start = start_with_offset(&event, FixedOffset::west_opt(5 * 3600).unwrap()).unwrap();
+///
+/// assert_eq!(start.hour(), 19);
+/// assert_eq!(start.to_rfc3339()[19..], *"-05:00");
+/// ```
+pub fn start_with_offset(e: &Event, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+    offset.from_local_datetime(&ical_datetime(e, "DTSTART")?).single()
+}
+
+/// Returns when `e` ends as a date and time at `offset`, like [`start_with_offset`](fn.start_with_offset.html) does
+/// for its start.
+pub fn end_with_offset(e: &Event, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+    offset.from_local_datetime(&ical_datetime(e, "DTEND")?).single()
+}
+
+#[cfg(test)]
+fn convert_ical_datetime(e: &Event, key: &str) -> NaiveDateTime {
+    ical_datetime(e, key).unwrap()
+}
+
+/// Reads the date or date and time in property `key` of `e`, if it has one. Returns `None` if it is not a valid date
+/// and time, like "20210230" or "T250000", rather than panicking.
+fn ical_datetime(e: &Event, key: &str) -> Option<NaiveDateTime> {
+    let value = e.properties().get(key)?.value();
+
+    fn to_naive_date(date: iso8601::Date) -> Option<NaiveDate> {
+        match date {
+            iso8601::Date::YMD { year, month, day } => {
+                NaiveDate::from_ymd_opt(year, month, day)
+            }
+            iso8601::Date::Week { year, ww, d } => {
+                let mut day = Weekday::Sun;
+                for _ in 0..d {
+                    day = day.succ();
+                }
+                NaiveDate::from_isoywd_opt(year, ww, day)
+            }
+            iso8601::Date::Ordinal { year, ddd } => {
+                NaiveDate::from_yo_opt(year, ddd)
+            }
+        }
+    }
+    match iso8601::datetime(value) {
+        Ok(dt) => {
+            Some(NaiveDateTime::new(
+                to_naive_date(dt.date)?,
+                NaiveTime::from_hms_opt(dt.time.hour, dt.time.minute, dt.time.second)?))
+        }
+        Err(_) => {
+            let date = iso8601::date(value).ok()?;
+            Some(NaiveDateTime::new(
+                to_naive_date(date)?,
+                NaiveTime::from_hms_opt(0, 0, 0)?))
         }
     }
 }
 
-fn convert_ical_datetime(e: &Event, key: &str) -> NaiveDateTime {
-    let value = e.properties().get(key).unwrap().value();
+///////////////////////////////
+// TESTS
+//////////////////////////////
+
+#[cfg(test)]
+mod to_event_tests {
+    use super::{summary, to_event_with_clock, to_event_at, parse_at, convert_ical_datetime, ical_datetime, write_pretty, FixedClock, HourFormat, ParseWarning};
+    use super::{candidates_at, to_event_relative, zone_of, end_local, end_with_offset, start_local, start_with_offset, to_calendar_at, to_ics_at, DateParser, Confidence, Direction, EventKind, Frequency, Parsers, Rounding, TimeParser};
+    use chrono::{prelude::*, Duration, FixedOffset, NaiveDate, NaiveDateTime, Weekday};
+    use icalendar::{Component, Event};
+
+    #[test]
+    fn start_tests() {
+        assert_to_event("Lunch at 1pm", time_today(13, 0, 0), time_today(14, 0, 0));
+        assert_to_event(
+            "Lunch at 12:30pm",
+            time_today(12, 30, 0),
+            time_today(13, 30, 0),
+        );
+        assert_to_event("Dinner at 7", time_today(19, 0, 0), time_today(20, 0, 0));
+        assert_to_event("Lunch at 12pm", time_today(12, 0, 0), time_today(13, 0, 0));
+        assert_to_event("Dinner at 7pm", time_today(19, 0, 0), time_today(20, 0, 0));
+        assert_to_event("Flight at noon", time_today(12, 0, 0), time_today(13, 0, 0));
+    }
+
+    #[test]
+    fn relative_start_tests() {
+        let evening = time_and_date(22, 30, 0, 3, 1, 2021);
+        assert_to_event_at(
+            "Call mom in 3 hours",
+            evening,
+            time_and_date(1, 30, 0, 3, 2, 2021),
+            time_and_date(2, 30, 0, 3, 2, 2021),
+        );
+        assert_to_event_at(
+            "Call mom in 45 minutes",
+            evening,
+            time_and_date(23, 15, 0, 3, 1, 2021),
+            time_and_date(0, 15, 0, 3, 2, 2021),
+        );
+        assert_to_event_at(
+            "Call mom soon",
+            evening,
+            time_and_date(23, 0, 0, 3, 1, 2021),
+            time_and_date(0, 0, 0, 3, 2, 2021),
+        );
+    }
+
+    #[test]
+    fn approximate_tests() {
+        let evening = time_and_date(22, 30, 0, 3, 1, 2021);
+        let e = to_event_at("Call mom in a bit", evening);
+        assert_eq!(e.properties().get("X-APPROXIMATE").map(|p| p.value()), Some("TRUE"));
+
+        let e = to_event_at("Call mom at 11pm", evening);
+        assert!(e.properties().get("X-APPROXIMATE").is_none());
+    }
+
+    #[test]
+    fn duration_tests() {
+        assert_to_event("Deep work 2h at 9am", time_today(9, 0, 0), time_today(11, 0, 0));
+        assert_to_event(
+            "Standup at 10am 15m",
+            time_today(10, 0, 0),
+            time_today(10, 15, 0),
+        );
+        assert_eq!(summary("Deep work 2h at 9am"), Some("Deep work".to_owned()));
+        assert_to_event("Review 90 minutes at 3pm", time_today(15, 0, 0), time_today(16, 30, 0));
+    }
+
+    #[test]
+    fn duration_window_tests() {
+        // Wednesday March 3 2021
+        let parsed = parse_at("block 2 hours tomorrow afternoon", now(), &Parsers::default());
+        assert_eq!(convert_ical_datetime(&parsed.event, "DTSTART"), time_and_date(12, 0, 0, 3, 4, 2021));
+        assert_eq!(convert_ical_datetime(&parsed.event, "DTEND"), time_and_date(14, 0, 0, 3, 4, 2021));
+        assert_eq!(parsed.event.properties()["SUMMARY"].value(), "block");
+        assert!(parsed.warnings.is_empty());
+
+        assert_to_event("Focus time 90 minutes this evening", time_today(17, 0, 0), time_today(18, 30, 0));
+        assert_to_event_at(
+            "Deep work 3h Friday",
+            now(),
+            time_and_date(9, 0, 0, 3, 5, 2021),
+            time_and_date(12, 0, 0, 3, 5, 2021),
+        );
+        // a time given with the part of the day is kept
+        assert_to_event_at(
+            "Run 45m Saturday morning at 7am",
+            now(),
+            time_and_date(7, 0, 0, 3, 6, 2021),
+            time_and_date(7, 45, 0, 3, 6, 2021),
+        );
+    }
+
+    #[test]
+    fn warning_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 6, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+        let june = |d: u32| NaiveDate::from_ymd_opt(2021, 6, d).unwrap();
+
+        assert_warnings(
+            "Party Friday June 5",
+            now,
+            vec![ParseWarning::WeekdayMismatch(Weekday::Fri, june(5))],
+        );
+        assert_warnings(
+            "Rent due Feb 30",
+            now,
+            vec![ParseWarning::ClampedDay(30, NaiveDate::from_ymd_opt(2021, 2, 28).unwrap())],
+        );
+        assert_warnings(
+            "Dinner at 7",
+            now,
+            vec![ParseWarning::AmbiguousMeridiem(NaiveTime::from_hms_opt(19, 0, 0).unwrap())],
+        );
+        assert_warnings("Dentist June 5 or June 12", now, vec![ParseWarning::IgnoredDate(june(12))]);
+
+        assert_warnings("Lunch at noon Saturday June 5", now, vec![]);
+        assert_warnings("Dinner 7-9pm", now, vec![]);
+        assert_warnings(
+            "Summer Camp 6/1-6/8",
+            now,
+            vec![
+                ParseWarning::AmbiguousDateOrder(june(1), NaiveDate::from_ymd_opt(2021, 1, 6).unwrap()),
+                ParseWarning::AmbiguousDateOrder(june(8), NaiveDate::from_ymd_opt(2021, 8, 6).unwrap()),
+            ],
+        );
+        assert_warnings(
+            "Picnic 6/5/2021",
+            now,
+            vec![ParseWarning::AmbiguousDateOrder(june(5), NaiveDate::from_ymd_opt(2021, 5, 6).unwrap())],
+        );
+        assert_warnings("Summer Camp 6/13-6/18", now, vec![]);
+        assert_warnings("Picnic 6/6", now, vec![]);
+        assert_warnings("Party June 5 7pm to June 6 2am", now, vec![]);
+        assert_warnings("Book club June 5 and June 12", now, vec![]);
+    }
+
+    #[test]
+    fn warning_message_tests() {
+        let messages: Vec<String> = [
+            ParseWarning::WeekdayMismatch(Weekday::Fri, NaiveDate::from_ymd_opt(2021, 6, 5).unwrap()),
+            ParseWarning::ClampedDay(30, NaiveDate::from_ymd_opt(2021, 2, 28).unwrap()),
+            ParseWarning::AmbiguousMeridiem(NaiveTime::from_hms_opt(19, 0, 0).unwrap()),
+            ParseWarning::AmbiguousDateOrder(
+                NaiveDate::from_ymd_opt(2021, 6, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2021, 5, 6).unwrap(),
+            ),
+            ParseWarning::AmbiguousInterval(Frequency::Weekly),
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "June 5 2021 is a Saturday, not a Friday",
+                "February 2021 has no day 30, so February 28 was used",
+                "no am or pm was given, so 7:00pm was guessed",
+                "the date could be June 5 or May 6",
+                "every other week was assumed, though it can mean twice a week",
+            ]
+        );
+    }
+
+    #[test]
+    fn weekday_set_tests() {
+        // Wednesday March 3 2021
+        let e = to_event_at("Calculus MWF 10-10:50am", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Calculus");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;BYDAY=MO,WE,FR");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(10, 0, 0));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_today(10, 50, 0));
+
+        let e = to_event_at("Lab Tu/Th 2-3:15", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Lab");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;BYDAY=TU,TH");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(14, 0, 0) + Duration::days(1));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_today(15, 15, 0) + Duration::days(1));
+
+        // a part of the day, or nothing at all, may follow the set
+        let e = to_event_at("Yoga MWF at noon", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Yoga");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;BYDAY=MO,WE,FR");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(12, 0, 0));
+        let e = to_event_at("Run Tu/Th mornings", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Run");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;BYDAY=TU,TH");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(9, 0, 0) + Duration::days(1));
+        let e = to_event_at("Office hours MWF", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Office hours");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;BYDAY=MO,WE,FR");
+        assert_eq!(e.properties()["DTSTART"].value(), "20210303");
+    }
+
+    #[test]
+    fn recurrence_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 6, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+
+        let e = to_event_at("Standup every day at 9am for two weeks", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(9, 0, 0, 6, 1, 2021));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(10, 0, 0, 6, 1, 2021));
+        assert_eq!(e.properties().get("RRULE").unwrap().value(), "FREQ=DAILY;COUNT=14");
+        assert_eq!(e.properties().get("SUMMARY").unwrap().value(), "Standup");
+
+        let e = to_event_at("Review every other week 2-3pm for 3 months", now);
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(15, 0, 0, 6, 1, 2021));
+        assert_eq!(
+            e.properties().get("RRULE").unwrap().value(),
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=20210831T140000Z"
+        );
+
+        // 7am has gone by at 8am, so the first one is tomorrow
+        let e = to_event_at("Gym every day until June 30 at 7am 45m", now);
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(7, 45, 0, 6, 2, 2021));
+        assert_eq!(
+            e.properties().get("RRULE").unwrap().value(),
+            "FREQ=DAILY;UNTIL=20210630T070000Z"
+        );
+
+        let e = to_event_at("Water plants every 3 days 10 times", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 1));
+        assert_eq!(e.properties().get("RRULE").unwrap().value(), "FREQ=DAILY;INTERVAL=3;COUNT=10");
+
+        let e = to_event_at("Meeting every Tuesday at 4pm", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(16, 0, 0, 6, 1, 2021));
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;BYDAY=TU");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Meeting");
+
+        let e = to_event_at("Lunch every other Friday", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 4));
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=FR");
+
+        let e = to_event_at("weekly standup", now);
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY");
+        assert_eq!(e.properties()["SUMMARY"].value(), "standup");
+
+        let e = to_event_at("Pay rent monthly on the 15th", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 15));
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=MONTHLY;BYMONTHDAY=15");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Pay rent");
+
+        let e = to_event_at("Book club the first Monday of every month at 7pm", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(19, 0, 0, 6, 7, 2021));
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=MONTHLY;BYDAY=1MO");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Book club");
+
+        let e = to_event_at("Retro monthly on the last Friday", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 25));
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=MONTHLY;BYDAY=-1FR");
+
+        let e = to_event_at("Lunch at noon", now);
+        assert!(!e.properties().contains_key("RRULE"));
+    }
+
+    #[test]
+    fn tentative_tests() {
+        let parsed = parse_at("Maybe drinks with Jo Friday at 6pm", now(), &Parsers::default());
+        assert!(parsed.tentative);
+        assert_eq!(parsed.event.properties()["STATUS"].value(), "TENTATIVE");
+        assert_eq!(parsed.event.properties()["SUMMARY"].value(), "drinks with Jo");
+        assert_eq!(convert_ical_datetime(&parsed.event, "DTSTART"), time_and_date(18, 0, 0, 3, 5, 2021));
+
+        assert_eq!(summary("Tentatively: offsite planning June 5"), Some("offsite planning".to_owned()));
+        assert_eq!(summary("Might go climbing tomorrow"), Some("go climbing".to_owned()));
 
-    fn to_naive_date(date: iso8601::Date) -> NaiveDate {
-        match date {
-            iso8601::Date::YMD { year, month, day } => {
-                NaiveDate::from_ymd(year, month, day)
-            }
-            iso8601::Date::Week { year, ww, d } => {
-                let mut day = Weekday::Sun;
-                for _ in 0..d {
-                    day = day.succ();
-                }
-                NaiveDate::from_isoywd(year, ww, day)
-            }
-            iso8601::Date::Ordinal { year, ddd } => {
-                NaiveDate::from_yo(year, ddd)
-            }
-        }
-    }
-    match iso8601::datetime(value) {
-        Ok(dt) => {
-            NaiveDateTime::new(
-                to_naive_date(dt.date),
-                NaiveTime::from_hms(dt.time.hour, dt.time.minute, dt.time.second))
-        }
-        Err(_) => {
-            let date = iso8601::date(value).unwrap();
-            NaiveDateTime::new(
-                to_naive_date(date),
-                NaiveTime::from_hms(0, 0, 0))
-        }
+        let parsed = parse_at("Drinks with Jo Friday at 6pm", now(), &Parsers::default());
+        assert!(!parsed.tentative);
+        assert!(!parsed.event.properties().contains_key("STATUS"));
     }
-}
 
-///////////////////////////////
-// TESTS
-//////////////////////////////
+    #[test]
+    fn kind_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 6, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+
+        let parsed = parse_at("Essay due Friday at 5pm", now, &Parsers::default());
+        assert_eq!(parsed.kind, EventKind::Deadline);
+        assert_eq!(convert_ical_datetime(&parsed.event, "DTSTART"), time_and_date(17, 0, 0, 6, 4, 2021));
+        assert_eq!(parse_at("Remind me to call Mom at 6pm", now, &Parsers::default()).kind, EventKind::Reminder);
+        assert_eq!(parse_at("Meet Sam for coffee at 10am", now, &Parsers::default()).kind, EventKind::Event);
+    }
 
-#[cfg(test)]
-mod to_event_tests {
-    use super::{summary, to_event, convert_ical_datetime};
-    use chrono::{prelude::*, Duration, Local, NaiveDate, NaiveDateTime, Weekday};
     #[test]
-    fn start_tests() {
-        assert_to_event("Lunch at 1pm", time_today(13, 0, 0), time_today(14, 0, 0));
-        assert_to_event(
-            "Lunch at 12:30pm",
-            time_today(12, 30, 0),
-            time_today(13, 30, 0),
-        );
-        assert_to_event("Dinner at 7", time_today(19, 0, 0), time_today(20, 0, 0));
-        assert_to_event("Lunch at 12pm", time_today(12, 0, 0), time_today(13, 0, 0));
-        assert_to_event("Dinner at 7pm", time_today(19, 0, 0), time_today(20, 0, 0));
-        assert_to_event("Flight at noon", time_today(12, 0, 0), time_today(13, 0, 0));
+    fn frequency_adverb_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 6, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+
+        let parsed = parse_at("Biweekly sync at 10am", now, &Parsers::default());
+        assert_eq!(parsed.event.properties()["SUMMARY"].value(), "sync");
+        assert_eq!(parsed.event.properties()["RRULE"].value(), "FREQ=WEEKLY;INTERVAL=2");
+        assert_eq!(parsed.warnings, vec![ParseWarning::AmbiguousInterval(Frequency::Weekly)]);
+
+        let parsed = parse_at("Quarterly review June 15 at 2pm", now, &Parsers::default());
+        assert_eq!(parsed.event.properties()["RRULE"].value(), "FREQ=MONTHLY;INTERVAL=3");
+        assert_eq!(convert_ical_datetime(&parsed.event, "DTSTART"), time_and_date(14, 0, 0, 6, 15, 2021));
+        assert!(parsed.warnings.is_empty());
+
+        let e = to_event_at("Fortnightly 1:1 with Sam at 3pm", now);
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=WEEKLY;INTERVAL=2");
+
+        let e = to_event_at("Pay rent monthly on the 1st", now);
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=MONTHLY;BYMONTHDAY=1");
     }
 
     #[test]
@@ -398,9 +1959,33 @@ mod to_event_tests {
         assert_to_event("Lunch 11-1pm", time_today(11, 0, 0), time_today(13, 0, 0));
     }
 
+    #[test]
+    fn meridiem_tests() {
+        // only the end says pm
+        assert_to_event("Dinner 7 to 9pm", time_today(19, 0, 0), time_today(21, 0, 0));
+        assert_to_event("Brunch 11:30-1pm", time_today(11, 30, 0), time_today(13, 0, 0));
+        // only the start says pm
+        assert_to_event("Dinner 7pm-9", time_today(19, 0, 0), time_today(21, 0, 0));
+        assert_to_event("Lunch 11am-1", time_today(11, 0, 0), time_today(13, 0, 0));
+        // both say
+        assert_to_event("Shift 9am-5pm", time_today(9, 0, 0), time_today(17, 0, 0));
+        assert_to_event("Drinks 5pm to 7pm", time_today(17, 0, 0), time_today(19, 0, 0));
+        // neither says
+        assert_to_event("Meeting 10-11", time_today(10, 0, 0), time_today(11, 0, 0));
+        assert_to_event("Dinner 7-9", time_today(19, 0, 0), time_today(21, 0, 0));
+        // on the continental 24-hour clock
+        assert_to_event("Dîner 19h30", time_today(19, 30, 0), time_today(20, 30, 0));
+        assert_to_event("Dinner at 19h", time_today(19, 0, 0), time_today(20, 0, 0));
+        assert_to_event("Réunion 9h00-10h30", time_today(9, 0, 0), time_today(10, 30, 0));
+                // spaced and dotted, as in pasted emails
+        assert_to_event("Call 7 to 9 P.M.", time_today(19, 0, 0), time_today(21, 0, 0));
+        assert_to_event("Review 10:30 a.m. - 11:30 a.m.", time_today(10, 30, 0), time_today(11, 30, 0));
+        assert_to_event("Dinner at 7 PM", time_today(19, 0, 0), time_today(20, 0, 0));
+    }
+
     #[test]
     fn starts_and_ends_with_date_tests() {
-        let year = Local::now().year();
+        let year = now().year();
         assert_to_event(
             "Lunch 1-2pm 6/10",
             time_and_date(13, 0, 0, 6, 10, year),
@@ -408,16 +1993,222 @@ mod to_event_tests {
         )
     }
 
+    #[test]
+    fn range_date_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 5, 20).unwrap().and_hms_opt(8, 0, 0).unwrap();
+        let start = time_and_date(9, 0, 0, 6, 1, 2021);
+        let end = time_and_date(17, 0, 0, 6, 1, 2021);
+        assert_to_event_at("Workshop 6/1 9am-5pm", now, start, end);
+        assert_to_event_at("Workshop 9am-5pm 6/1", now, start, end);
+        assert_to_event_at("Workshop 9am to 5pm on June 1", now, start, end);
+        assert_to_event_at("Workshop June 1st 9-5", now, start, end);
+
+        let e = to_event_at("Workshop 9am-5pm on June 1 and 2", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), start);
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), end);
+        let rdates: Vec<_> = e
+            .multi_properties()
+            .iter()
+            .filter(|p| p.key() == "RDATE")
+            .map(|p| p.value().to_owned())
+            .collect();
+        assert_eq!(rdates, vec!["20210602T090000Z".to_owned()]);
+    }
+
+    #[test]
+    fn range_connector_tests() {
+        let year = now().year();
+        let today = |h: u32| time_and_date(h, 0, 0, 3, 3, year);
+        for text in [
+            "Party from 7 to 9pm",
+            "Party 7 till 9pm",
+            "Party 7 until 9 pm",
+            "Party between 7 and 9pm",
+            "Party 7–9pm",
+            "Party 7 — 9pm",
+            "Party from seven until nine pm",
+        ] {
+            let e = to_event_at(text, now());
+            assert_eq!(convert_ical_datetime(&e, "DTSTART"), today(19), "{}", text);
+            assert_eq!(convert_ical_datetime(&e, "DTEND"), today(21), "{}", text);
+            assert_eq!(e.properties()["SUMMARY"].value(), "Party", "{}", text);
+        }
+        assert_to_event("Class from 10am until noon", today(10), today(12));
+        assert_to_event_all_day("Camp 6/1 through 6/8", time_and_date(0, 0, 0, 6, 1, year));
+        assert_eq!(summary("Camp 6/1 through 6/8"), Some("Camp".to_owned()));
+
+        // "until" also ends a schedule
+        let e = to_event_at("Yoga every Monday until 6/30", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(0, 0, 0, 3, 8, year));
+    }
+
+    #[test]
+    fn overnight_tests() {
+        let year = now().year();
+        assert_to_event("Party 10pm-2am", time_today(22, 0, 0), time_and_date(2, 0, 0, 3, 4, year));
+        assert_to_event("Party 10-2am", time_today(22, 0, 0), time_and_date(2, 0, 0, 3, 4, year));
+        assert_to_event(
+            "Party tomorrow 10pm-2am",
+            time_and_date(22, 0, 0, 3, 4, year),
+            time_and_date(2, 0, 0, 3, 5, year),
+        );
+        assert_to_event(
+            "Night shift June 5 11pm to 7am",
+            time_and_date(23, 0, 0, 6, 5, year),
+            time_and_date(7, 0, 0, 6, 6, year),
+        );
+        let e = to_event_at("Party 10pm-2am on June 5 and June 12", now());
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(2, 0, 0, 6, 6, year));
+        assert_eq!(e.multi_properties()[0].value(), "20210612T220000Z");
+
+        // ranges within a day are unchanged
+        assert_to_event("Lunch 12-1pm", time_today(12, 0, 0), time_today(13, 0, 0));
+    }
+
+    #[test]
+    fn attendee_tests() {
+        let attendees = |e: &Event| -> Vec<String> {
+            e.multi_properties().iter().filter(|p| p.key() == "ATTENDEE").map(|p| p.value().to_owned()).collect()
+        };
+        let e = to_event_at("Lunch with Alice and bob@example.com tomorrow at noon", now());
+        assert_eq!(attendees(&e), vec!["invalid:nomail".to_owned(), "mailto:bob@example.com".to_owned()]);
+        assert!(e.to_string().contains("ATTENDEE;CN=Alice:invalid:nomail"));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Lunch with Alice");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(12, 0, 0, 3, 4, now().year()));
+
+        // a weekday after the names is the date, not a name
+        let e = to_event_at("Coffee with Sam Friday at 3pm", now());
+        assert_eq!(attendees(&e).len(), 1);
+        assert_eq!(e.properties()["SUMMARY"].value(), "Coffee with Sam");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(15, 0, 0, 3, 5, now().year()));
+
+        // the digits of an address are not a time
+        let e = to_event_at("Review with ann2@example.com at 4pm", now());
+        assert_eq!(attendees(&e), vec!["mailto:ann2@example.com".to_owned()]);
+        assert_eq!(e.properties()["SUMMARY"].value(), "Review");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(16, 0, 0));
+
+        assert!(attendees(&to_event_at("Dinner with friends at 7pm", now())).is_empty());
+
+        let parsers = Parsers { names_in_summary: false, ..Parsers::default() };
+        let e = parse_at("Call with Alice, Bob Smith and Carol at 5pm", now(), &parsers).event;
+        assert_eq!(attendees(&e).len(), 3);
+        assert!(e.to_string().contains("ATTENDEE;CN=Bob Smith:invalid:nomail"));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Call");
+        let parsers = Parsers { attendees: false, ..Parsers::default() };
+        assert!(attendees(&parse_at("Call with Alice at 5pm", now(), &parsers).event).is_empty());
+    }
+
+    #[test]
+    fn context_filter_tests() {
+        for text in &["Call 555-1230", "$5 lunch", "deploy v2.30", "Pay 30 dollars", "Call (555) 123-4567"] {
+            let e = to_event_at(text, now());
+            assert_eq!(e.properties()["DTSTART"].value(), now().format("%Y%m%d").to_string(), "{}", text);
+            assert_eq!(e.properties()["SUMMARY"].value(), *text);
+        }
+        let e = to_event_at("$12.50 lunch at 1pm", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(13, 0, 0));
+        assert_eq!(e.properties()["SUMMARY"].value(), "$12.50 lunch");
+        let e = to_event_at("Call 555-123-4567 tomorrow at 3pm", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(15, 0, 0, 3, 4, now().year()));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Call 555-123-4567");
+        // a range of prices or versions is kept whole
+        let e = to_event_at("Buy milk for $5-10 tomorrow", now());
+        assert_eq!(e.properties()["DTSTART"].value(), "20210304");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Buy milk for $5-10");
+        let e = to_event_at("Release v1.2-3 Friday", now());
+        assert_eq!(e.properties()["DTSTART"].value(), "20210305");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Release v1.2-3");
+        // the characters the summary marks kept numbers with are not read as marks when the text has them
+        assert_eq!(summary("Lunch \u{E001}\u{E000}\u{E000}\u{E001} at noon"), Some("Lunch".to_owned()));
+        assert_eq!(summary("$5 lunch \u{E001}\u{E000}\u{E001} at noon"), Some("$5 lunch".to_owned()));
+
+        let parsers = Parsers {
+            date: DateParser::new().with_context_filters(false),
+            time: TimeParser::new().with_context_filters(false),
+            ..Parsers::default()
+        };
+        let e = parse_at("$5 lunch", now(), &parsers).event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(17, 0, 0));
+    }
+
+    #[test]
+    fn invalid_component_tests() {
+        // impossible dates and times are left unread rather than aborting
+        for text in &["Party 13/40", "Party 0/0", "Party 31/31/31", "Party 99/99/9999", "Party June 45th", "Party at 25:00", "Party 2021-02-30T25:61", "Party Monday - tomorrow"] {
+            let e = to_event_at(text, now());
+            assert!(e.properties().contains_key("DTSTART"), "{}", text);
+        }
+        let e = to_event_at("Party 13/40 at 5pm", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(17, 0, 0));
+
+        let e = Event::new().add_property("DTSTART", "20210230").add_property("DTEND", "20210431T100000").done();
+        assert_eq!(ical_datetime(&e, "DTSTART"), None);
+        assert_eq!(ical_datetime(&e, "DTEND"), None);
+        assert_eq!(start_local(&e), None);
+        let mut written = Vec::new();
+        write_pretty(&mut written, &e, HourFormat::default()).unwrap();
+        assert!(written.is_empty());
+    }
+
+    #[test]
+    fn starts_and_ends_at_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 6, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+        assert_to_event_at(
+            "Party June 5 7pm to June 6 2am",
+            now,
+            time_and_date(19, 0, 0, 6, 5, 2021),
+            time_and_date(2, 0, 0, 6, 6, 2021),
+        );
+        assert_to_event_at(
+            "Hackathon Friday 6pm - Saturday 6pm",
+            now,
+            time_and_date(18, 0, 0, 6, 4, 2021),
+            time_and_date(18, 0, 0, 6, 5, 2021),
+        );
+        assert_eq!(
+            to_event_at("Party June 5 7pm to June 6 2am", now).properties().get("SUMMARY").unwrap().value(),
+            "Party"
+        );
+        // now is a Tuesday, so this Sunday comes before Friday and the Sunday after it is meant
+        assert_to_event_at(
+            "Retreat Friday 5pm to Sunday noon",
+            now,
+            time_and_date(17, 0, 0, 6, 4, 2021),
+            time_and_date(12, 0, 0, 6, 6, 2021),
+        );
+        assert_to_event_at(
+            "Retreat Sunday 5pm until Tuesday noon",
+            now,
+            time_and_date(17, 0, 0, 6, 6, 2021),
+            time_and_date(12, 0, 0, 6, 8, 2021),
+        );
+        // an end without a time lasts its whole day
+        assert_to_event_at(
+            "Trip June 5 at 9am to June 8",
+            now,
+            time_and_date(9, 0, 0, 6, 5, 2021),
+            time_and_date(0, 0, 0, 6, 9, 2021),
+        );
+        assert_eq!(
+            to_event_at("Camp Friday at 5pm until Sunday at noon", now).properties()["SUMMARY"].value(),
+            "Camp"
+        );
+    }
+
     #[test]
     fn all_day_tests() {
-        let year = Local::now().year();
+        let year = now().year();
         assert_to_event_all_day("America's Birthday 7/4", ndt_from_ymd(year, 7, 4));
         assert_to_event_all_day("America's Birthday July 4th", ndt_from_ymd(year, 7, 4));
+        assert_to_event_all_day("Christmas Eve party", ndt_from_ymd(year, 12, 24));
+        assert_to_event_all_day("Halloween", ndt_from_ymd(year, 10, 31));
+        assert_to_event_all_day("Christmas party Dec 20", ndt_from_ymd(year, 12, 20));
     }
 
     #[test]
     fn start_with_date_tests() {
-        let year = Local::now().year();
+        let year = now().year();
         assert_to_event(
             "Lunch at 1pm 6/15",
             time_and_date(13, 0, 0, 6, 15, year),
@@ -425,9 +2216,39 @@ mod to_event_tests {
         );
     }
 
+    #[test]
+    fn day_of_month_tests() {
+        // now() is March 3
+        assert_to_event(
+            "Dinner on the 5th at 7pm",
+            time_and_date(19, 0, 0, 3, 5, 2021),
+            time_and_date(20, 0, 0, 3, 5, 2021),
+        );
+        assert_to_event(
+            "Book club the 1st at 6pm",
+            time_and_date(18, 0, 0, 4, 1, 2021),
+            time_and_date(19, 0, 0, 4, 1, 2021),
+        );
+        assert_eq!(summary("Rent due on the 15th"), Some("Rent due".to_owned()));
+    }
+
+    #[test]
+    fn period_tests() {
+        // now() is Wednesday, March 3
+        assert_to_event_all_day("Review next week", time_and_date(0, 0, 0, 3, 8, 2021));
+        assert_to_event_all_day("Report due end of this month", time_and_date(0, 0, 0, 3, 31, 2021));
+        assert_to_event(
+            "Planning beginning of next year at 10am",
+            time_and_date(10, 0, 0, 1, 1, 2022),
+            time_and_date(11, 0, 0, 1, 1, 2022),
+        );
+        assert_eq!(summary("Report due by the end of the month"), Some("Report due by".to_owned()));
+        assert_eq!(summary("Planning beginning of next year"), Some("Planning".to_owned()));
+    }
+
     #[test]
     fn all_day_starts_and_ends_tests() {
-        let year = Local::now().year();
+        let year = now().year();
         assert_to_event(
             "Welcome Week 9/1-9/8",
             ndt_from_ymd(year, 9, 1),
@@ -435,6 +2256,85 @@ mod to_event_tests {
         )
     }
 
+    #[test]
+    fn span_tests() {
+        let now = time_and_date(9, 0, 0, 3, 3, 2021);
+        let e = to_event_at("Conference all week", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 3, 1));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 3, 7));
+        assert_eq!(summary("Conference all week"), Some("Conference".to_owned()));
+
+        let e = to_event_at("Camping the weekend of July 4", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 7, 3));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 7, 4));
+        assert_eq!(summary("Camping the weekend of July 4"), Some("Camping".to_owned()));
+
+        let e = to_event_at("BBQ this weekend", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 3, 6));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 3, 7));
+        assert_eq!(summary("BBQ on the weekend"), Some("BBQ".to_owned()));
+
+        let e = to_event_at("Conference Mon–Fri", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 3, 8));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 3, 12));
+        assert_eq!(summary("free Mon-Wed"), Some("free".to_owned()));
+
+        let e = to_event_at("Vacation this summer", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 1));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 8, 31));
+        assert_eq!(summary("Vacation this summer"), Some("Vacation".to_owned()));
+
+        let e = to_event_at("Planning first quarter of 2022", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2022, 1, 1));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2022, 3, 31));
+        assert_eq!(summary("Planning Q3"), Some("Planning".to_owned()));
+        assert_eq!(summary("Summer Camp 6/1-6/8"), Some("Summer Camp".to_owned()));
+
+        let e = to_event_at("Vacation the week of June 9", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 7));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 6, 13));
+        assert_eq!(summary("Vacation the week of June 9"), Some("Vacation".to_owned()));
+
+        let e = to_event_at("Sprint week 32 2025", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2025, 8, 4));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2025, 8, 10));
+        assert_eq!(summary("Sprint week 32 2025"), Some("Sprint".to_owned()));
+        assert_eq!(summary("Offsite 2026-W05"), Some("Offsite".to_owned()));
+
+        let e = to_event_at("Workshop June 5, 6, and 7", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 5));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 6, 7));
+        assert_eq!(summary("Workshop June 5, 6, and 7"), Some("Workshop".to_owned()));
+
+        let e = to_event_at("Book club June 5 and June 12", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 6, 5));
+        let rdates: Vec<_> = e
+            .multi_properties()
+            .iter()
+            .filter(|p| p.key() == "RDATE")
+            .map(|p| p.value().to_owned())
+            .collect();
+        assert_eq!(rdates, vec!["20210612".to_owned()]);
+        assert!(e.to_string().contains("RDATE;VALUE=DATE:20210612"));
+        // the connectors between listed dates go with them
+        assert_eq!(summary("Book club June 5 and June 12"), Some("Book club".to_owned()));
+        assert_eq!(summary("Pottery June 5 and June 12 6-8pm"), Some("Pottery".to_owned()));
+        assert_eq!(summary("Class 6/1, 6/8 & 6/15"), Some("Class".to_owned()));
+        assert_eq!(summary("June 5 and June 12"), Some(String::new()));
+        assert_eq!(summary("Lunch June 5 and drinks June 12"), Some("Lunch and drinks".to_owned()));
+
+        let e = to_event_at("Mon and Wed at 6pm", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 3, 3));
+        assert_eq!(summary("Mon and Wed at 6pm"), Some(String::new()));
+        assert_eq!(summary("Swim Tue & Thu"), Some("Swim".to_owned()));
+        assert_eq!(summary("Office hours Mon, Wed, and Fri"), Some("Office hours".to_owned()));
+        assert_eq!(summary("Tom and Jerry Friday"), Some("Tom and Jerry".to_owned()));
+
+        let e = to_event_at("Out of office all next month", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), ndt_from_ymd(2021, 4, 1));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), ndt_from_ymd(2021, 4, 30));
+    }
+
     #[test]
     fn get_summary_tests() {
         assert_eq!(
@@ -472,60 +2372,553 @@ mod to_event_tests {
         assert_eq!(
             summary("Senior Week 6/17-6/21"),
             Some("Senior Week".to_owned())
-        )
+        );
+        assert_eq!(
+            summary("Launch window day 200 of 2025"),
+            Some("Launch window".to_owned())
+        );
+        assert_eq!(
+            summary("Lunch at Cafe Rio at noon"),
+            Some("Lunch".to_owned())
+        );
+        assert_eq!(
+            summary("Standup tues at 9am"),
+            Some("Standup".to_owned())
+        );
+        assert_eq!(
+            summary("Book club Wednesday at 7pm"),
+            Some("Book club".to_owned())
+        );
+        assert_eq!(summary("Dîner 19h30"), Some("Dîner".to_owned()));
+        assert_eq!(summary("Dinner at 19h"), Some("Dinner".to_owned()))
+    }
+
+    #[test]
+    fn day_around_tests() {
+        assert_to_event_all_day("Party the day after tomorrow", ndt_from_ymd(2021, 3, 5));
+        assert_to_event_all_day("Lunch the day before yesterday", ndt_from_ymd(2021, 3, 1));
+        assert_to_event("Dinner tomorrow night at 10", time_and_date(22, 0, 0, 3, 4, 2021), time_and_date(23, 0, 0, 3, 4, 2021));
+        assert_to_event("Drinks last night", time_and_date(21, 0, 0, 3, 2, 2021), time_and_date(22, 0, 0, 3, 2, 2021));
+        let e = to_event_at("Movie the night before last", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(21, 0, 0, 3, 1, 2021));
+
+        assert_eq!(summary("Party the day after tomorrow"), Some("Party".to_owned()));
+        assert_eq!(summary("Lunch the day before yesterday"), Some("Lunch".to_owned()));
+        assert_eq!(summary("Dinner tomorrow night at 10"), Some("Dinner".to_owned()));
+        assert_eq!(summary("Movie the night before last"), Some("Movie".to_owned()));
+    }
+
+    #[test]
+    fn casual_time_tests() {
+        assert_to_event("Send report EOD", time_today(17, 0, 0), time_today(18, 0, 0));
+        assert_to_event("Walk at lunchtime tomorrow", time_and_date(12, 0, 0, 3, 4, 2021), time_and_date(13, 0, 0, 3, 4, 2021));
+        assert_to_event("Call the bank first thing Friday", time_and_date(9, 0, 0, 3, 5, 2021), time_and_date(10, 0, 0, 3, 5, 2021));
+
+        assert_eq!(summary("Send report EOD"), Some("Send report".to_owned()));
+        assert_eq!(summary("Walk at lunchtime tomorrow"), Some("Walk".to_owned()));
+        assert_eq!(summary("Call the bank first thing Friday"), Some("Call the bank".to_owned()));
+        assert_eq!(summary("Invoices by COB"), Some("Invoices".to_owned()));
+
+        // parts of the day in names stay
+        assert_eq!(summary("Tickets for Midnight Express Friday"), Some("Tickets for Midnight Express".to_owned()));
+        assert_eq!(summary("Drinks with the Evening Standard team tonight"), Some("Drinks with the Evening Standard team".to_owned()));
+        assert_eq!(summary("Coffee at Evening Star cafe at 7"), Some("Coffee cafe".to_owned()));
+        let e = to_event_at("Coffee at Evening Star cafe at 7", now());
+        assert_eq!(e.properties()["LOCATION"].value(), "Evening Star");
+        assert_eq!(summary("Movie midnight to 1am"), Some("Movie".to_owned()));
+    }
+
+    #[test]
+    fn ordinal_date_tests() {
+        assert_to_event_all_day("Launch day 200 of 2025", ndt_from_ymd(2025, 7, 19));
+        assert_to_event_all_day("shipment 2025-200", ndt_from_ymd(2025, 7, 19));
+        assert_to_event("julian day 045 at 3pm", time_and_date(15, 0, 0, 2, 14, 2021), time_and_date(16, 0, 0, 2, 14, 2021));
+        assert_to_event("Launch day 200 of 2025 at 9am", time_and_date(9, 0, 0, 7, 19, 2025), time_and_date(10, 0, 0, 7, 19, 2025));
+        // a day of something else is not a day of the year
+        assert_to_event("Day 2 of the conference at 3pm", time_today(15, 0, 0), time_today(16, 0, 0));
+        assert_to_event("Sprint day 3 standup at 10am", time_today(10, 0, 0), time_today(11, 0, 0));
+    }
+
+    #[test]
+    fn day_first_date_tests() {
+        assert_to_event("Party 4th July at 7", time_and_date(19, 0, 0, 7, 4, 2021), time_and_date(20, 0, 0, 7, 4, 2021));
+        assert_to_event_all_day("BBQ the 1st of June", ndt_from_ymd(2021, 6, 1));
+        assert_to_event("Wedding 12 Dec 2026 at 3pm", time_and_date(15, 0, 0, 12, 12, 2026), time_and_date(16, 0, 0, 12, 12, 2026));
+
+        assert_eq!(summary("Party 4th July at 7"), Some("Party".to_owned()));
+        assert_eq!(summary("BBQ the 1st of June"), Some("BBQ".to_owned()));
+        assert_eq!(summary("Wedding 12 Dec 2026 at 3pm"), Some("Wedding".to_owned()));
+    }
+
+    #[test]
+    fn twenty_four_hour_time_tests() {
+        assert_to_event("Standup 0700 tomorrow", time_and_date(7, 0, 0, 3, 4, 2021), time_and_date(8, 0, 0, 3, 4, 2021));
+        assert_to_event("Muster 1830h", time_today(18, 30, 0), time_today(19, 30, 0));
+        assert_to_event("Watch handover 0900 hours", time_today(9, 0, 0), time_today(10, 0, 0));
+
+        assert_eq!(summary("Muster 1830h"), Some("Muster".to_owned()));
+        assert_eq!(summary("Watch handover 0900 hours"), Some("Watch handover".to_owned()));
+
+        // years and room numbers stay out of the time
+        assert_to_event("Budget 2025 review at 3pm", time_today(15, 0, 0), time_today(16, 0, 0));
+        assert_to_event("Plan for 2026 kickoff tomorrow at 9am", time_and_date(9, 0, 0, 3, 4, 2021), time_and_date(10, 0, 0, 3, 4, 2021));
+        assert_to_event("Room 1203 meeting at 3pm", time_today(15, 0, 0), time_today(16, 0, 0));
+
+        // the sides of a range are times when the other side is, or when both fall on the quarter hour
+        assert_to_event("Shift 0900-1700", time_today(9, 0, 0), time_today(17, 0, 0));
+        assert_to_event("Shift 9am-1700", time_today(9, 0, 0), time_today(17, 0, 0));
+        assert_to_event("Shift 1300-1700", time_today(13, 0, 0), time_today(17, 0, 0));
+        assert_to_event("Shift from 1300 to 1700", time_today(13, 0, 0), time_today(17, 0, 0));
+        assert_to_event("Movie midnight to 1900 tomorrow", time_and_date(0, 0, 0, 3, 4, 2021), time_and_date(19, 0, 0, 3, 4, 2021));
+        assert_eq!(summary("Shift 0900-1700"), Some("Shift".to_owned()));
+        assert_eq!(summary("Movie midnight to 1900"), Some("Movie".to_owned()));
+        assert_to_event_all_day("Report 2021-200", ndt_from_ymd(2021, 7, 19));
+    }
+
+    #[test]
+    fn month_date_year_tests() {
+        assert_to_event("Wedding June 5th, 2026 at 3pm", time_and_date(15, 0, 0, 6, 5, 2026), time_and_date(16, 0, 0, 6, 5, 2026));
+        assert_to_event_all_day("Launch Jun 5 '26", ndt_from_ymd(2026, 6, 5));
+        assert_to_event_all_day("Reunion Aug 21 2020", ndt_from_ymd(2020, 8, 21));
+
+        assert_eq!(summary("Wedding June 5th, 2026 at 3pm"), Some("Wedding".to_owned()));
+        assert_eq!(summary("Launch Jun 5 '26"), Some("Launch".to_owned()));
+    }
+
+    #[test]
+    fn nth_weekday_of_month_tests() {
+        assert_to_event_all_day("Club the first Monday of June", ndt_from_ymd(2021, 6, 7));
+        assert_to_event("Book club the third Thursday in March at 7pm", time_and_date(19, 0, 0, 3, 18, 2021), time_and_date(20, 0, 0, 3, 18, 2021));
+        assert_to_event_all_day("Review last Friday of the month", ndt_from_ymd(2021, 3, 26));
+
+        assert_eq!(summary("Club the first Monday of June"), Some("Club".to_owned()));
+        assert_eq!(summary("Review last Friday of the month"), Some("Review".to_owned()));
+        assert_eq!(summary("Meetup 2nd Tuesday of next month at 6pm"), Some("Meetup".to_owned()));
+    }
+
+    #[test]
+    fn word_number_tests() {
+        assert_to_event("Dinner at seven", time_today(19, 0, 0), time_today(20, 0, 0));
+        assert_to_event("Coffee in two hours", time_today(10, 0, 0), time_today(11, 0, 0));
+        assert_to_event("Standup quarter past nine", time_today(9, 15, 0), time_today(10, 15, 0));
+        assert_to_event_all_day("Dentist in three days", ndt_from_ymd(2021, 3, 6));
+        let e = to_event_at("Call seven thirty pm tomorrow", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(19, 30, 0, 3, 4, 2021));
+
+        assert_eq!(summary("Dinner at seven"), Some("Dinner".to_owned()));
+        assert_eq!(summary("Call seven thirty pm tomorrow"), Some("Call".to_owned()));
+        assert_eq!(summary("Dentist in three days"), Some("Dentist".to_owned()));
+        assert_eq!(summary("Dentist in 3 days"), Some("Dentist".to_owned()));
+        assert_to_event_all_day("Trip in two weeks", ndt_from_ymd(2021, 3, 17));
+        assert_to_event_all_day("Trip in a year", ndt_from_ymd(2022, 3, 3));
+        assert_eq!(summary("Trip in a month"), Some("Trip".to_owned()));
+        assert_eq!(summary("Call in an hour and a half"), Some("Call".to_owned()));
+        assert_to_event_all_day("Met Bob 3 days ago", ndt_from_ymd(2021, 2, 28));
+        assert_eq!(summary("Met Bob 3 days ago"), Some("Met Bob".to_owned()));
+        assert_eq!(summary("Dinner with Sam a week ago"), Some("Dinner with Sam".to_owned()));
+        assert_to_event_all_day("Review two weeks from tomorrow", ndt_from_ymd(2021, 3, 18));
+        assert_eq!(summary("Review two weeks from tomorrow"), Some("Review".to_owned()));
+        assert_eq!(summary("Standup the Monday after next"), Some("Standup".to_owned()));
+        assert_eq!(summary("Lunch with two friends at noon"), Some("Lunch with two friends".to_owned()));
+    }
+
+    #[test]
+    fn past_direction_tests() {
+        let past = Parsers {
+            date: DateParser::new().with_direction(Direction::Past),
+            ..Parsers::default()
+        };
+        let parse = |text: &str| parse_at(text, now(), &past).event;
+        let at = |d: u32, h: u32| NaiveDate::from_ymd_opt(2021, 3, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+
+        let e = parse("Retro last Friday at 3pm");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(15, 0, 0, 2, 26, 2021));
+        let e = parse("Standup Monday 9-10am");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(1, 9));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), at(1, 10));
+        let e = parse("Sync yesterday 9-10am");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(2, 9));
+        // times still to come today were yesterday's
+        let e = parse("Dinner at 7pm");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(2, 19));
+        let e = parse("Breakfast 7-7:30am");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(3, 7));
+        let e = parse("Offsite June 5");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(0, 0, 0, 6, 5, 2020));
+
+        // the future is unchanged
+        let e = to_event_at("Dinner at 7pm Friday", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(5, 19));
+    }
+
+    #[test]
+    fn prefer_future_tests() {
+        let late = time_today(23, 0, 0);
+        let at = |d: u32, h: u32| NaiveDate::from_ymd_opt(2021, 3, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+
+        // times that have gone by today are tomorrow's
+        let e = to_event_at("Dinner at 7pm", late);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(4, 19));
+        let e = to_event_at("Dinner at 7", late);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(4, 19));
+        let e = to_event_at("Review 2-3pm", late);
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), at(4, 15));
+        let e = to_event_at("Drinks at 11:30pm", late);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(23, 30, 0));
+        let e = to_event_at("Dinner today at 7pm", late);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(3, 19));
+
+        let this_week = Parsers {
+            date: DateParser::new().with_prefer_future(false),
+            ..Parsers::default()
+        };
+        let e = parse_at("Dinner at 7pm", late, &this_week).event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(3, 19));
+        let e = parse_at("Standup Monday 9am", late, &this_week).event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), at(1, 9));
+    }
+
+    #[test]
+    fn rounding_tests() {
+        let rounded = Parsers {
+            time: TimeParser::new().with_rounding(Rounding::Nearest15),
+            ..Parsers::default()
+        };
+        let now = time_today(12, 47, 0);
+        let parse = |text: &str| parse_at(text, now, &rounded).event;
+
+        let e = parse("Call mom in 2 hours");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(14, 45, 0));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_today(15, 45, 0));
+        let e = parse("Coffee soon");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(13, 15, 0));
+        let e = parse("Standup at 9:05am");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(9, 5, 0, 3, 4, 2021));
+
+        let e = to_event_at("Call mom in 2 hours", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(14, 47, 0));
+    }
+
+    #[test]
+    fn relative_to_tests() {
+        let now = Local.from_local_datetime(&time_today(23, 30, 0)).unwrap();
+        let e = to_event_relative("Flight tomorrow at 6am", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(6, 0, 0, 3, 4, 2021));
+        let e = to_event_relative("Call back in 2 hours", now);
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(1, 30, 0, 3, 4, 2021));
+    }
+
+    #[test]
+    fn timezone_tests() {
+        let east = Parsers {
+            zone: Some(zone_of(FixedOffset::east_opt(2 * 3600).unwrap())),
+            ..Parsers::default()
+        };
+        let parse = |text: &str| parse_at(text, now(), &east).event;
+
+        let e = parse("Breakfast tomorrow at 1am");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(23, 0, 0, 3, 3, 2021));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(0, 0, 0, 3, 4, 2021));
+        let e = parse("Yoga every day at 7am until March 10");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(5, 0, 0, 3, 4, 2021));
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=DAILY;UNTIL=20210310T050000Z");
+        let e = parse("Pottery June 5 and June 12 6-8pm");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(16, 0, 0, 6, 5, 2021));
+        assert_eq!(e.multi_properties()[0].value(), "20210612T160000Z");
+        let e = parse("Dentist June 5");
+        assert_eq!(e.properties()["DTSTART"].value(), "20210605");
+
+        // an offset off the hour moves the time by its minutes too, while without a timezone the wall clock is kept
+        let india = Parsers {
+            zone: Some(zone_of(FixedOffset::east_opt(5 * 3600 + 1800).unwrap())),
+            ..Parsers::default()
+        };
+        let e = parse_at("Dinner at 7pm", now(), &india).event;
+        assert_eq!(e.properties()["DTSTART"].value(), "20210303T133000Z");
+        assert_eq!(e.properties()["DTEND"].value(), "20210303T143000Z");
+        let e = parse_at("Dinner at 7pm", now(), &Parsers::default()).event;
+        assert_eq!(e.properties()["DTSTART"].value(), "20210303T190000Z");
+    }
+
+    #[test]
+    fn time_zone_mention_tests() {
+        let parse = |text: &str| parse_at(text, now(), &Parsers::default()).event;
+
+        let e = parse("Call with Sam tomorrow at 3pm EST");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(20, 0, 0, 3, 4, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Call with Sam");
+        let e = parse("Standup 14:00 CET");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(13, 0, 0));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Standup");
+        let e = parse("Webinar June 5 3-4pm America/New_York");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(19, 0, 0, 6, 5, 2021));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(20, 0, 0, 6, 5, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Webinar");
+        let e = parse("Sync 9am UTC+2");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(7, 0, 0));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Sync");
+
+        // the timezone named wins over the one events are parsed in
+        let east = Parsers {
+            zone: Some(zone_of(FixedOffset::east_opt(2 * 3600).unwrap())),
+            ..Parsers::default()
+        };
+        let e = parse_at("Call at 3pm GMT", now(), &east).event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(15, 0, 0));
+    }
+
+    #[test]
+    fn iso_8601_tests() {
+        let parse = |text: &str| parse_at(text, now(), &Parsers::default());
+
+        let e = parse("Launch 2022-06-05").event;
+        assert_eq!(e.properties()["DTSTART"].value(), "20220605");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Launch");
+        let e = parse("Deploy 2021-06-05T14:30:00Z").event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(14, 30, 0, 6, 5, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Deploy");
+        let outcome = parse("Review 2021-06-05T14:30:00+02:00");
+        assert_eq!(convert_ical_datetime(&outcome.event, "DTSTART"), time_and_date(12, 30, 0, 6, 5, 2021));
+        assert_eq!(outcome.confidence, Confidence::High);
+        assert!(outcome.warnings.is_empty());
+
+        // a date alone takes the time given with it, and a date and time an end time
+        let e = parse("Dentist 2021-06-05 at 3pm").event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(15, 0, 0, 6, 5, 2021));
+        let e = parse("Standup 2021-06-05 09:30-10:00").event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(9, 30, 0, 6, 5, 2021));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(10, 0, 0, 6, 5, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Standup");
+
+        // two of them are a range
+        let e = parse("Trip 2021-06-05 to 2021-06-08").event;
+        assert_eq!(e.properties()["DTSTART"].value(), "20210605");
+        assert_eq!(e.properties()["DTEND"].value(), "20210608");
+        let e = parse("Server upgrade 2021-06-05T22:00/2021-06-06T06:00").event;
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_and_date(6, 0, 0, 6, 6, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Server upgrade");
+
+        // a date that does not exist is left out
+        let outcome = parse("Retro 2021-02-30 at 3pm");
+        assert_eq!(convert_ical_datetime(&outcome.event, "DTSTART"), time_today(15, 0, 0));
+    }
+
+    #[test]
+    fn confidence_tests() {
+        let confidence = |text: &str| parse_at(text, now(), &Parsers::default()).confidence;
+        assert_eq!(confidence("Meet in room 5"), Confidence::Low);
+        assert_eq!(confidence("Dinner at 7"), Confidence::Medium);
+        assert_eq!(confidence("Lunch 12-1"), Confidence::Medium);
+        assert_eq!(confidence("Coffee soon"), Confidence::Medium);
+        assert_eq!(confidence("Dinner at 7pm"), Confidence::High);
+        assert_eq!(confidence("Lunch at noon"), Confidence::High);
+        assert_eq!(confidence("Vacation 6/1-6/8"), Confidence::High);
+
+        let sure = Parsers {
+            min_confidence: Confidence::Medium,
+            ..Parsers::default()
+        };
+        let parsed = parse_at("Meet in room 5 Friday", now(), &sure);
+        assert_eq!(parsed.confidence, Confidence::Low);
+        assert_eq!(parsed.event.properties()["DTSTART"].value(), "20210305");
+        assert!(parsed.warnings.is_empty());
+        let e = parse_at("Meet in the lobby Friday at 3pm", now(), &sure).event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(15, 0, 0, 3, 5, 2021));
+        let e = parse_at("Dinner at 7", now(), &sure).event;
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(19, 0, 0));
+
+        let certain = Parsers {
+            min_confidence: Confidence::High,
+            ..Parsers::default()
+        };
+        let e = parse_at("Dinner at 7 tomorrow", now(), &certain).event;
+        assert_eq!(e.properties()["DTSTART"].value(), "20210304");
+    }
+
+    #[test]
+    fn candidates_tests() {
+        let candidates = |text: &str| -> Vec<(String, f32)> {
+            candidates_at(text, now(), &Parsers::default())
+                .into_iter()
+                .map(|(e, score)| (e.properties()["DTSTART"].value().to_owned(), score))
+                .collect()
+        };
+        assert_eq!(candidates("Dinner at 7pm tomorrow"), vec![("20210304T190000Z".to_owned(), 1.0)]);
+        assert_eq!(
+            candidates("Party on 3/4"),
+            vec![("20210304".to_owned(), 0.6), ("20210403".to_owned(), 0.4)]
+        );
+        assert_eq!(
+            candidates("Dinner at 7 tomorrow"),
+            vec![("20210304T190000Z".to_owned(), 0.6), ("20210304T070000Z".to_owned(), 0.4)]
+        );
+        let found = candidates("Dinner at 7 on 3/4");
+        assert_eq!(found.len(), 4);
+        assert_eq!(found[0].0, "20210304T190000Z");
+        assert_eq!(found[3].0, "20210403T070000Z");
+
+        // a bare number may be no time at all
+        let found = candidates("Meet in room 5 Friday");
+        assert_eq!(found[0].0, "20210305T170000Z");
+        assert!(found.iter().any(|(start, _)| start == "20210305"));
+    }
+
+    #[test]
+    fn occasion_tests() {
+        let occasions = Parsers {
+            occasions: true,
+            ..Parsers::default()
+        };
+        let parse = |text: &str| parse_at(text, now(), &occasions).event;
+
+        let e = parse("Mom's birthday April 5");
+        assert_eq!(e.properties()["SUMMARY"].value(), "Mom's birthday");
+        assert_eq!(e.properties()["DTSTART"].value(), "20210405");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=YEARLY");
+        let e = parse("our anniversary June 12 at 7pm");
+        assert_eq!(e.properties()["DTSTART"].value(), "20210612");
+        assert_eq!(e.properties()["RRULE"].value(), "FREQ=YEARLY");
+
+        for text in ["Sam's birthday party April 5", "Birthday cake at 3pm", "Anniversary dinner every month"] {
+            let e = parse(text);
+            assert_ne!(e.properties().get("RRULE").map(|p| p.value()), Some("FREQ=YEARLY"), "{}", text);
+        }
+
+        let e = to_event_at("Mom's birthday April 5", now());
+        assert!(!e.properties().contains_key("RRULE"));
+    }
+
+    #[test]
+    fn past_phrase_tests() {
+        let e = to_event_at("Pizza with Sam last night", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(21, 0, 0, 3, 2, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Pizza with Sam");
+
+        let e = to_event_at("Retro this past Monday at 3pm", now());
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_and_date(15, 0, 0, 3, 1, 2021));
+        assert_eq!(e.properties()["SUMMARY"].value(), "Retro");
+    }
+
+    #[test]
+    fn calendar_tests() {
+        let calendar = to_calendar_at(vec!["Lunch at noon", "  ", "Dinner at 7pm tomorrow"], now());
+        assert_eq!(calendar.len(), 2);
+
+        let ics = calendar.to_string();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("SUMMARY:Lunch\r\n"));
+        assert!(ics.contains("DTSTART:20210304T190000Z\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(to_calendar_at(Vec::new(), now()).len(), 0);
+    }
+
+    #[test]
+    fn ics_tests() {
+        let uid = |ics: &str| ics.lines().find(|line| line.starts_with("UID:")).map(str::to_owned);
+        let ics = to_ics_at("Dinner at 7pm tomorrow", now());
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+        assert_eq!(ics.matches("\r\nUID:").count(), 1);
+        assert_eq!(ics.matches("\r\nDTSTAMP:").count(), 1);
+        assert!(ics.contains("DTSTART:20210304T190000Z\r\n"));
+
+        // every event gets its own UID
+        assert_ne!(uid(&ics), uid(&to_ics_at("Dinner at 7pm tomorrow", now())));
+    }
+
+    #[test]
+    fn local_time_tests() {
+        let e = to_event_at("Dinner at 7pm", now());
+        let local = start_local(&e).unwrap();
+        assert_eq!(local.naive_local(), time_today(19, 0, 0));
+        assert_eq!(end_local(&e).unwrap().naive_local(), time_today(20, 0, 0));
+
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let start = start_with_offset(&e, offset).unwrap();
+        assert_eq!(start.to_rfc3339(), "2021-03-03T19:00:00+02:00");
+        assert_eq!(end_with_offset(&e, offset).unwrap().to_rfc3339(), "2021-03-03T20:00:00+02:00");
+
+        let e = to_event_at("Hackathon all day tomorrow", now());
+        assert_eq!(start_with_offset(&e, offset).unwrap().to_rfc3339(), "2021-03-04T00:00:00+02:00");
+        assert_eq!(end_local(&Event::new()), None);
+    }
+
+    #[test]
+    fn location_tests() {
+        let e = to_event_at("Lunch at Cafe Rio at noon", now());
+        assert_eq!(e.properties().get("LOCATION").unwrap().value(), "Cafe Rio");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(12, 0, 0));
+
+        let e = to_event_at("Flight on Saturday at Noon", now());
+        assert!(!e.properties().contains_key("LOCATION"));
+    }
+
+    #[test]
+    fn normalized_text_tests() {
+        let e = to_event_at("Doctor’s appointment 9–10am tomorrow", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Doctor's appointment");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(9, 0, 0) + Duration::days(1));
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), time_today(10, 0, 0) + Duration::days(1));
+
+        let e = to_event_at("Party 7pm🎉 tomorrow!", now());
+        assert_eq!(e.properties()["SUMMARY"].value(), "Party 🎉");
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), time_today(19, 0, 0) + Duration::days(1));
+
+        assert_eq!(summary("Lunch at noon, tomorrow."), Some("Lunch".to_owned()));
+        assert_eq!(summary("Coffee with Sam at 3pm?"), Some("Coffee with Sam".to_owned()));
+        assert_eq!(summary("🎂 Cake at 3"), Some("🎂 Cake".to_owned()));
     }
 
     fn ndt_from_ymd(y: i32, m: u32, d: u32) -> NaiveDateTime {
-        NaiveDate::from_ymd(y, m, d).and_hms(0, 0, 0)
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    /// The time tests run at, so they pass the same way near midnight.
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap()
     }
 
     fn time_today(h: u32, m: u32, s: u32) -> NaiveDateTime {
-        Local::today().and_hms(h, m, s).naive_local()
+        now().date().and_hms_opt(h, m, s).unwrap()
     }
 
     fn time_and_date(h: u32, min: u32, s: u32, mon: u32, d: u32, y: i32) -> NaiveDateTime {
-        NaiveDate::from_ymd(y, mon, d).and_hms(h, min, s)
+        NaiveDate::from_ymd_opt(y, mon, d)
+            .unwrap()
+            .and_hms_opt(h, min, s)
+            .unwrap()
     }
 
     #[allow(dead_code)]
     fn date_for_friday(h: u32, m: u32, next: bool) -> NaiveDateTime {
-        let today_weekday = Local::now().weekday();
+        let today_weekday = now().weekday();
         let today_num = today_weekday.number_from_monday() as i64;
 
         let goal_num = Weekday::Fri.number_from_monday() as i64;
 
         let diff = goal_num - today_num;
-        if diff > 0 {
-            let duration;
-            if next {
-                duration = Duration::days(diff + 7);
-            } else {
-                duration = Duration::days(diff);
-            }
-            return Local::today().and_hms(h, m, 0).naive_local() + duration;
+        let days = if diff > 0 {
+            diff
         } else if diff == 0 {
-            let duration;
-            println!("day: {:?}", Local::now().weekday());
-            if next {
-                duration = Duration::days(14);
-            } else {
-                duration = Duration::days(7);
-            }
-            return Local::today().and_hms(h, m, 0).naive_local() + duration;
+            7
         } else {
-            let pos_diff = 7 + diff;
-            let duration;
-            if next {
-                duration = Duration::days(pos_diff + 7);
-            } else {
-                duration = Duration::days(pos_diff);
-            }
-            return Local::today().and_hms(h, m, 0).naive_local() + duration;
-        }
+            7 + diff
+        };
+        let duration = if next {
+            Duration::days(days + 7)
+        } else {
+            Duration::days(days)
+        };
+        time_today(h, m, 0) + duration
     }
 
     fn assert_to_event_all_day(input: &str, expected_start: NaiveDateTime) {
-        let e = to_event(input);
+        let e = to_event_with_clock(input, &FixedClock(now()));
 
         assert_eq!(
             convert_ical_datetime(&e, "DTSTART"),
@@ -533,8 +2926,24 @@ mod to_event_tests {
         );
     }
 
+    fn assert_warnings(input: &str, now: NaiveDateTime, expected: Vec<ParseWarning>) {
+        assert_eq!(parse_at(input, now, &Parsers::default()).warnings, expected);
+    }
+
+    fn assert_to_event_at(
+        input: &str,
+        now: NaiveDateTime,
+        expected_start: NaiveDateTime,
+        expected_end: NaiveDateTime,
+    ) {
+        let e = to_event_at(input, now);
+
+        assert_eq!(convert_ical_datetime(&e, "DTSTART"), expected_start);
+        assert_eq!(convert_ical_datetime(&e, "DTEND"), expected_end);
+    }
+
     fn assert_to_event(input: &str, expected_start: NaiveDateTime, expected_end: NaiveDateTime) {
-        let e = to_event(input);
+        let e = to_event_with_clock(input, &FixedClock(now()));
 
         let start = convert_ical_datetime(&e, "DTSTART");
         let end = convert_ical_datetime(&e, "DTEND");
@@ -0,0 +1,186 @@
+//! Merge newly parsed events into the events of an existing calendar without adding the same event twice.
+
+use icalendar::{Component, Event, Property};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// What [`merge_events`](fn.merge_events.html) did with each new event, as positions in the merged events.
+pub struct MergeReport {
+    /// New events that matched none of the existing ones and were added at the end.
+    pub added: Vec<usize>,
+    /// Existing events replaced by a new event that matched them but said something different.
+    pub updated: Vec<usize>,
+    /// Existing events a new event matched exactly, so it was left out.
+    pub skipped: Vec<usize>,
+}
+
+/// Merges `new` events into `existing`, the events of a calendar. A new event matches an existing one with the same
+/// `UID`, or, when either has no `UID`, the same summary (ignoring case and spacing) and start. A match that differs
+/// replaces the existing event, keeping its `UID`; an identical match is skipped; anything else is added.
+///
+/// `icalendar::Calendar` can be collected from the merged events once they are ready to be written out.
+///
+/// # Arguments
+///
+/// * `existing` - The events already in the calendar, which are merged into.
+/// * `new` - The events to merge in, like those returned by [`to_event`](fn.to_event.html).
+///
+/// # Example
+/// ```
+/// use event_parser::{merge_events, to_event};
+/// use icalendar::Calendar;
+///
+/// let mut events = vec![to_event("Lunch at noon")];
+/// let report = merge_events(&mut events, vec![to_event("Lunch at 12pm"), to_event("Dinner at 7pm")]);
+///
+/// assert_eq!(report.skipped, vec![0]);
+/// assert_eq!(report.added, vec![1]);
+///
+/// let calendar: Calendar = events.into_iter().collect();
+/// assert_eq!(calendar.len(), 2);
+/// ```
+pub fn merge_events(
+    existing: &mut Vec<Event>,
+    new: impl IntoIterator<Item = Event>,
+) -> MergeReport {
+    let mut report = MergeReport::default();
+    for mut event in new {
+        let found = existing.iter().position(|old| {
+            same_uid(old, &event).unwrap_or_else(|| same_summary_and_start(old, &event))
+        });
+        match found {
+            Some(i) if contents(&existing[i]) == contents(&event) => report.skipped.push(i),
+            Some(i) => {
+                if let (Some(uid), None) = (value(&existing[i], "UID"), value(&event, "UID")) {
+                    event.append_property(Property::new("UID", uid));
+                }
+                existing[i] = event;
+                report.updated.push(i);
+            }
+            None => {
+                existing.push(event);
+                report.added.push(existing.len() - 1);
+            }
+        }
+    }
+    report
+}
+
+/// Returns whether `a` and `b` have the same `UID`, or `None` if either has none.
+fn same_uid(a: &Event, b: &Event) -> Option<bool> {
+    Some(value(a, "UID")? == value(b, "UID")?)
+}
+
+fn same_summary_and_start(a: &Event, b: &Event) -> bool {
+    let normalized = |e: &Event| {
+        value(e, "SUMMARY").map(|s| {
+            s.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        })
+    };
+    value(a, "DTSTART").is_some()
+        && value(a, "DTSTART") == value(b, "DTSTART")
+        && normalized(a) == normalized(b)
+}
+
+/// Returns every property of `e` as written, except those that change each time an event is made.
+fn contents(e: &Event) -> Vec<String> {
+    let properties = e
+        .properties()
+        .values()
+        .filter(|p| !matches!(p.key(), "UID" | "DTSTAMP"));
+    properties
+        .chain(e.multi_properties())
+        .map(|p| {
+            let mut line = String::new();
+            p.fmt_write(&mut line).unwrap();
+            line
+        })
+        .collect()
+}
+
+fn value<'a>(e: &'a Event, key: &str) -> Option<&'a str> {
+    e.properties().get(key).map(Property::value)
+}
+
+#[cfg(test)]
+mod merge_tests {
+    use super::{merge_events, MergeReport};
+    use crate::to_event_at;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use icalendar::{Component, Event};
+
+    #[test]
+    fn merge_tests() {
+        let mut events = vec![parse("Lunch at noon"), parse("Gym at 6am")];
+        let report = merge_events(
+            &mut events,
+            vec![
+                parse("Lunch at 12pm"),
+                parse("GYM  at 6am 2h"),
+                parse("Dinner at 7pm"),
+                parse("Lunch at noon tomorrow"),
+            ],
+        );
+        assert_eq!(
+            report,
+            MergeReport {
+                added: vec![2, 3],
+                updated: vec![1],
+                skipped: vec![0],
+            }
+        );
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[1].properties()["SUMMARY"].value(), "GYM");
+        assert_eq!(events[1].properties()["DTEND"].value(), "20210304T080000Z");
+    }
+
+    #[test]
+    fn uid_tests() {
+        let mut events = vec![parse("Lunch at noon").uid("lunch-1").done()];
+        let report = merge_events(
+            &mut events,
+            vec![parse("Brunch at 11am").uid("lunch-1").done()],
+        );
+        assert_eq!(report.updated, vec![0]);
+        assert_eq!(events[0].properties()["SUMMARY"].value(), "Brunch");
+
+        // a different UID is a different event, even with the same summary and start
+        let report = merge_events(
+            &mut events,
+            vec![parse("Brunch at 11am").uid("lunch-2").done()],
+        );
+        assert_eq!(report.added, vec![1]);
+
+        // an update without a UID keeps the one it replaces
+        let report = merge_events(&mut events, vec![parse("Brunch at 11am 2h")]);
+        assert_eq!(report.updated, vec![0]);
+        assert_eq!(events[0].properties()["UID"].value(), "lunch-1");
+    }
+
+    #[test]
+    fn empty_tests() {
+        let mut events: Vec<Event> = Vec::new();
+        assert_eq!(
+            merge_events(&mut events, Vec::new()),
+            MergeReport::default()
+        );
+        let report = merge_events(
+            &mut events,
+            vec![parse("Lunch at noon"), parse("Lunch at noon")],
+        );
+        assert_eq!((report.added, report.skipped), (vec![0], vec![0]));
+    }
+
+    fn parse(text: &str) -> Event {
+        to_event_at(text, now())
+    }
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+    }
+}
@@ -0,0 +1,104 @@
+//! Clean up text pasted from chat apps and documents before it is parsed.
+
+/// Returns `text` with typographic characters replaced by the plain ones the parser reads: curly quotes become straight
+/// quotes, en and em dashes become hyphens, ellipses become three dots and unusual spaces become plain spaces.
+/// Emoji stuck to a word or number, like "7pm🎉", are set apart from it by a space.
+///
+/// [`to_event`](fn.to_event.html) and the functions like it clean up their text this way themselves.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be cleaned up.
+///
+/// # Example
+/// ```
+/// use event_parser::normalize;
+///
+/// assert_eq!(normalize("Doctor’s appt 9–10am🎉"), "Doctor's appt 9-10am 🎉");
+/// ```
+pub fn normalize(text: &str) -> String {
+    let mut clean = String::with_capacity(text.len());
+    let mut last: Option<char> = None;
+    for c in text.chars() {
+        let plain = match c {
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => "'",
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => "\"",
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+            '\u{2026}' => "...",
+            '\u{200B}' | '\u{2060}' | '\u{FEFF}' => "",
+            c if c.is_whitespace() && c != '\n' => " ",
+            _ => {
+                if let Some(last) = last {
+                    if (is_emoji(last) && c.is_alphanumeric())
+                        || (last.is_alphanumeric() && is_emoji(c))
+                    {
+                        clean.push(' ');
+                    }
+                }
+                clean.push(c);
+                last = Some(c);
+                continue;
+            }
+        };
+        clean.push_str(plain);
+        last = plain.chars().last().or(last);
+    }
+    clean
+}
+
+/// Returns whether `c` is a pictograph, like an emoji, rather than a letter, digit or punctuation.
+pub(crate) fn is_emoji(c: char) -> bool {
+    matches!(c, '\u{1F000}'..='\u{1FAFF}' | '\u{2600}'..='\u{27BF}' | '\u{2B00}'..='\u{2BFF}')
+}
+
+/// Returns whether a whitespace-separated `token` could be part of a summary, rather than stray punctuation.
+pub(crate) fn is_word(token: &str) -> bool {
+    token.chars().any(|c| c.is_alphanumeric() || is_emoji(c))
+}
+
+/// Returns `summary` without the punctuation ending the sentence it was taken from, like the "!" of "Dinner at 7pm!".
+pub(crate) fn trim_trailing_punctuation(summary: &str) -> &str {
+    summary.trim_end_matches([',', '.', ';', ':', '!', '?'])
+}
+
+#[cfg(test)]
+mod normalize_tests {
+    use super::{is_word, normalize, trim_trailing_punctuation};
+
+    #[test]
+    fn normalize_tests() {
+        assert_eq!(normalize("Doctor’s appointment"), "Doctor's appointment");
+        assert_eq!(normalize("“Launch” party"), "\"Launch\" party");
+        assert_eq!(
+            normalize("Meeting 9–10am — Friday"),
+            "Meeting 9-10am - Friday"
+        );
+        assert_eq!(
+            normalize("Lunch\u{a0}at\u{202f}noon\u{200b}"),
+            "Lunch at noon"
+        );
+        assert_eq!(normalize("Wait…"), "Wait...");
+        assert_eq!(normalize("Party 7pm🎉"), "Party 7pm 🎉");
+        assert_eq!(normalize("🎂Cake at 3"), "🎂 Cake at 3");
+        assert_eq!(normalize("🎉 Party"), "🎉 Party");
+        assert_eq!(normalize("Lunch at noon\nDinner"), "Lunch at noon\nDinner");
+    }
+
+    #[test]
+    fn is_word_tests() {
+        assert!(is_word("Lunch,"));
+        assert!(is_word("🎉"));
+        assert!(!is_word("!"));
+        assert!(!is_word(",-"));
+    }
+
+    #[test]
+    fn trim_trailing_punctuation_tests() {
+        assert_eq!(
+            trim_trailing_punctuation("Lunch with Sam."),
+            "Lunch with Sam"
+        );
+        assert_eq!(trim_trailing_punctuation("Coffee?!"), "Coffee");
+        assert_eq!(trim_trailing_punctuation("Q&A"), "Q&A");
+    }
+}
@@ -0,0 +1,424 @@
+//! Events parsed from text with their start, end, summary and location as plain values, for applications that would
+//! rather not read them back out of an `Event`'s properties, whether from a line of text or every one in an email.
+//! With the `serde` feature they serialize to JSON, or any other format, for services to return.
+
+use chrono::NaiveDateTime;
+use date_time_parser::{Clock, LocalClock};
+use icalendar::{Component, Event};
+
+#[cfg(feature = "serde")]
+use crate::utc_date;
+use crate::{ical_datetime, parse_at, parse_checked, MissReason, Parsers};
+#[cfg(feature = "serde")]
+use chrono::{TimeZone, Utc};
+
+/// Abbreviations whose period does not end a sentence, like the "Dr." of "Lunch with Dr. Lee".
+const ABBREVIATIONS: [&str; 12] = [
+    "a.m", "p.m", "dr", "mr", "mrs", "ms", "st", "jr", "sr", "vs", "approx", "etc",
+];
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "EventFields"))]
+/// An event parsed from text. It converts into the `Event` [`to_event`](fn.to_event.html) returns for the same text.
+///
+/// With the `serde` feature it serializes to its start and end as RFC 3339 date-times in UTC, like the times of the
+/// `Event`, whether it is all day, its summary and its location. One deserialized back has an `Event` made from those
+/// alone, without any `RRULE` or `STATUS` the text gave.
+pub struct ParsedEvent {
+    #[cfg_attr(feature = "serde", serde(with = "rfc3339"))]
+    start: NaiveDateTime,
+    #[cfg_attr(feature = "serde", serde(with = "rfc3339"))]
+    end: NaiveDateTime,
+    all_day: bool,
+    summary: Option<String>,
+    location: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    event: Event,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+/// The fields a [`ParsedEvent`] is serialized to, which it is deserialized from.
+struct EventFields {
+    #[serde(with = "rfc3339")]
+    start: NaiveDateTime,
+    #[serde(with = "rfc3339")]
+    end: NaiveDateTime,
+    all_day: bool,
+    summary: Option<String>,
+    location: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<EventFields> for ParsedEvent {
+    fn from(fields: EventFields) -> ParsedEvent {
+        let mut event = Event::new();
+        match (fields.all_day, fields.start.date(), fields.end.date()) {
+            (true, start, end) if start == end => {
+                event.all_day(utc_date(start));
+            }
+            (true, start, end) => {
+                event.start_date(utc_date(start));
+                event.end_date(utc_date(end));
+            }
+            (false, ..) => {
+                event.starts(Utc.from_utc_datetime(&fields.start));
+                event.ends(Utc.from_utc_datetime(&fields.end));
+            }
+        }
+        if let Some(summary) = &fields.summary {
+            event.summary(summary);
+        }
+        if let Some(location) = &fields.location {
+            event.location(location);
+        }
+        ParsedEvent {
+            start: fields.start,
+            end: fields.end,
+            all_day: fields.all_day,
+            summary: fields.summary,
+            location: fields.location,
+            event,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+/// Writes the times of a [`ParsedEvent`] as RFC 3339 date-times in UTC, e.g. "2021-03-04T19:00:00Z", and reads them
+/// back at any offset.
+mod rfc3339 {
+    use chrono::{DateTime, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        time: &NaiveDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(
+            &Utc.from_utc_datetime(time)
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+        )
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NaiveDateTime, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&text)
+            .map(|time| time.naive_utc())
+            .map_err(de::Error::custom)
+    }
+}
+
+impl ParsedEvent {
+    /// Reads the values of `event`, like one [`to_event`](fn.to_event.html) returns. An event without an end ends when
+    /// it starts. Returns `None` if `event` has no start.
+    ///
+    /// # Example
+    /// ```
+    /// use event_parser::{to_event, ParsedEvent};
+    /// use icalendar::Event;
+    ///
+    /// let parsed = ParsedEvent::new(to_event("Lunch at noon")).unwrap();
+    /// assert_eq!(parsed.summary(), Some("Lunch"));
+    /// assert!(ParsedEvent::new(Event::new()).is_none());
+    /// ```
+    pub fn new(event: Event) -> Option<ParsedEvent> {
+        let text = |key: &str| {
+            let value = event.properties().get(key)?.value();
+            Some(value.to_owned()).filter(|v| !v.is_empty())
+        };
+        let start = ical_datetime(&event, "DTSTART")?;
+        Some(ParsedEvent {
+            start,
+            end: ical_datetime(&event, "DTEND").unwrap_or(start),
+            all_day: event
+                .properties()
+                .get("DTSTART")
+                .is_some_and(|p| p.value().len() == 8),
+            summary: text("SUMMARY"),
+            location: text("LOCATION"),
+            event,
+        })
+    }
+
+    /// Returns when the event starts, at midnight if it is all day. Like the times of the `Event`, it is a wall-clock
+    /// time in no particular timezone.
+    pub fn start(&self) -> NaiveDateTime {
+        self.start
+    }
+
+    /// Returns when the event ends, or the last day of an all-day event at midnight.
+    pub fn end(&self) -> NaiveDateTime {
+        self.end
+    }
+
+    /// Returns `true` if the event lasts all day rather than starting at a time.
+    pub fn is_all_day(&self) -> bool {
+        self.all_day
+    }
+
+    /// Returns what the event is, e.g. "Dinner" of "Dinner at 7pm", if the text says.
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    /// Returns where the event is, e.g. "Cafe Rio" of "Lunch at Cafe Rio at noon", if the text says.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Returns the event as an [iCalendar Event](../icalendar/struct.Event.html), with any `RRULE` or `STATUS` it has.
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl From<ParsedEvent> for Event {
+    fn from(parsed: ParsedEvent) -> Event {
+        parsed.event
+    }
+}
+
+/// Parses `text` into a [`ParsedEvent`](struct.ParsedEvent.html) like [`to_event`](fn.to_event.html) parses it into
+/// an `Event`.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::to_parsed_event;
+/// use chrono::Timelike;
+/// use icalendar::{Component, Event};
+///
+/// let parsed = to_parsed_event("Lunch at Cafe Rio at noon");
+///
+/// assert_eq!(parsed.summary(), Some("Lunch"));
+/// assert_eq!(parsed.location(), Some("Cafe Rio"));
+/// assert_eq!(parsed.start().hour(), 12);
+/// assert!(!parsed.is_all_day());
+///
+/// let event: Event = parsed.into();
+/// assert_eq!(event.properties()["SUMMARY"].value(), "Lunch");
+/// ```
+pub fn to_parsed_event(text: &str) -> ParsedEvent {
+    to_parsed_event_with_clock(text, &LocalClock)
+}
+
+/// Parses `text` into a [`ParsedEvent`](struct.ParsedEvent.html) like [`to_parsed_event`](fn.to_parsed_event.html),
+/// with respect to the time given by `clock`.
+pub fn to_parsed_event_with_clock(text: &str, clock: &dyn Clock) -> ParsedEvent {
+    // text without a date or time is all day today, so every parsed event has a start
+    ParsedEvent::new(parse_at(text, clock.now(), &Parsers::default()).event)
+        .expect("a parsed event has a start")
+}
+
+/// Finds every event in a block of text, like an email or meeting notes, parsing each line and sentence that has a
+/// date or time in it into a [`ParsedEvent`](struct.ParsedEvent.html). Lines and sentences without one, like
+/// greetings, are skipped.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the the text to be parsed.
+///
+/// # Example
+/// ```
+/// use event_parser::extract_events;
+///
+/// let events = extract_events("Lunch at noon Friday. Dentist 3pm Tuesday.");
+/// let summaries: Vec<_> = events.iter().map(|e| e.summary()).collect();
+///
+/// assert_eq!(summaries, vec![Some("Lunch"), Some("Dentist")]);
+/// ```
+pub fn extract_events(text: &str) -> Vec<ParsedEvent> {
+    extract_events_with_clock(text, &LocalClock)
+}
+
+/// Finds every event in `text` like [`extract_events`](fn.extract_events.html), with respect to the time given by
+/// `clock`.
+pub fn extract_events_with_clock(text: &str, clock: &dyn Clock) -> Vec<ParsedEvent> {
+    let now = clock.now();
+    sentences(text)
+        .filter_map(
+            |sentence| match parse_checked(sentence, now, &Parsers::default()) {
+                (_, Some(MissReason::NoDateOrTime)) => None,
+                (outcome, _) => ParsedEvent::new(outcome.event),
+            },
+        )
+        .collect()
+}
+
+/// Splits `text` into its lines, and each line into sentences ending at a ".", "!", "?" or ";" followed by a space,
+/// leaving out blank ones.
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().flat_map(|line| {
+        let mut sentences = Vec::new();
+        let mut from = 0;
+        let mut chars = line.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let at_space = chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+            if matches!(c, '.' | '!' | '?' | ';')
+                && at_space
+                && !(c == '.' && ends_abbreviation(&line[from..i]))
+            {
+                sentences.push(&line[from..=i]);
+                from = i + 1;
+            }
+        }
+        sentences.push(&line[from..]);
+        sentences.into_iter().filter(|s| !s.trim().is_empty())
+    })
+}
+
+/// Returns `true` if `text` ends with an abbreviation, so a period after it does not end the sentence.
+fn ends_abbreviation(text: &str) -> bool {
+    let word = text
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+    ABBREVIATIONS.contains(&word.as_str())
+}
+
+#[cfg(test)]
+mod parsed_tests {
+    use super::{extract_events_with_clock, sentences, to_parsed_event_with_clock, ParsedEvent};
+    use crate::FixedClock;
+    use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+    use icalendar::{Component, Event};
+
+    #[test]
+    fn parsed_event_tests() {
+        let clock = FixedClock(at(3, 3, 8, 0));
+
+        let parsed = to_parsed_event_with_clock("Dinner at Cafe Rio 7-9pm tomorrow", &clock);
+        assert_eq!(parsed.start(), at(3, 4, 19, 0));
+        assert_eq!(parsed.end(), at(3, 4, 21, 0));
+        assert!(!parsed.is_all_day());
+        assert_eq!(parsed.summary(), Some("Dinner"));
+        assert_eq!(parsed.location(), Some("Cafe Rio"));
+
+        let parsed = to_parsed_event_with_clock("Summer Camp 6/1-6/8", &clock);
+        assert_eq!(parsed.start(), at(6, 1, 0, 0));
+        assert_eq!(parsed.end(), at(6, 8, 0, 0));
+        assert!(parsed.is_all_day());
+        assert_eq!(parsed.location(), None);
+
+        let parsed = to_parsed_event_with_clock("June 5", &clock);
+        assert_eq!(parsed.end(), at(6, 5, 0, 0));
+        assert_eq!(parsed.summary(), None);
+
+        let parsed = to_parsed_event_with_clock("Standup every day at 9am", &clock);
+        let event = Event::from(parsed);
+        assert_eq!(event.properties()["RRULE"].value(), "FREQ=DAILY");
+
+        assert!(ParsedEvent::new(Event::new().summary("Lunch").done()).is_none());
+        let parsed = ParsedEvent::new(
+            Event::new()
+                .starts(Utc.from_utc_datetime(&at(3, 4, 12, 0)))
+                .done(),
+        )
+        .unwrap();
+        assert_eq!(parsed.end(), at(3, 4, 12, 0));
+    }
+
+    #[test]
+    fn extract_events_tests() {
+        let clock = FixedClock(at(3, 3, 8, 0));
+
+        let events =
+            extract_events_with_clock("Lunch at noon Friday. Dentist 3pm Tuesday.", &clock);
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            (events[0].summary(), events[0].start()),
+            (Some("Lunch"), at(3, 5, 12, 0))
+        );
+        assert_eq!(
+            (events[1].summary(), events[1].start()),
+            (Some("Dentist"), at(3, 9, 15, 0))
+        );
+
+        let email = "Hi team,\n\nThanks for coming! Retro on Thursday at 2pm; dinner at 7 p.m. Friday with Dr. Lee.\n\
+                     \nCheers,\nSam";
+        let events = extract_events_with_clock(email, &clock);
+        let found: Vec<_> = events.iter().map(|e| (e.summary(), e.start())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some("Retro"), at(3, 4, 14, 0)),
+                (Some("dinner with Dr. Lee"), at(3, 5, 19, 0)),
+            ]
+        );
+
+        assert!(extract_events_with_clock("Thanks, see you around!", &clock).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_tests() {
+        let clock = FixedClock(at(3, 3, 8, 0));
+
+        let parsed = to_parsed_event_with_clock("Dinner at Cafe Rio 7-9pm tomorrow", &clock);
+        let json = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "start": "2021-03-04T19:00:00Z",
+                "end": "2021-03-04T21:00:00Z",
+                "all_day": false,
+                "summary": "Dinner",
+                "location": "Cafe Rio",
+            })
+        );
+        let back: ParsedEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            (back.start(), back.end()),
+            (at(3, 4, 19, 0), at(3, 4, 21, 0))
+        );
+        assert_eq!(back.location(), Some("Cafe Rio"));
+        assert_eq!(
+            back.event().properties()["DTSTART"].value(),
+            "20210304T190000Z"
+        );
+        assert_eq!(back.event().properties()["SUMMARY"].value(), "Dinner");
+
+        let parsed = to_parsed_event_with_clock("Summer Camp 6/1-6/8", &clock);
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert!(json.contains(r#""end":"2021-06-08T00:00:00Z","all_day":true"#));
+        let back: ParsedEvent = serde_json::from_str(&json).unwrap();
+        assert!(back.is_all_day());
+        assert_eq!(back.event().properties()["DTEND"].value(), "20210608");
+
+        // times at other offsets are read as the same instant
+        let back: ParsedEvent = serde_json::from_str(
+            r#"{"start": "2021-03-04T20:00:00+01:00", "end": "2021-03-04T21:00:00+01:00", "all_day": false,
+                "summary": null, "location": null}"#,
+        )
+        .unwrap();
+        assert_eq!(back.start(), at(3, 4, 19, 0));
+        assert_eq!(back.summary(), None);
+        assert!(serde_json::from_str::<ParsedEvent>(r#"{"start": "tomorrow"}"#).is_err());
+    }
+
+    #[test]
+    fn sentences_tests() {
+        assert_eq!(
+            sentences("Call at 9 a.m. Monday. Pay rent!\n\nTea? Yes").collect::<Vec<_>>(),
+            vec!["Call at 9 a.m. Monday.", " Pay rent!", "Tea?", " Yes"]
+        );
+        assert_eq!(
+            sentences("v1.2 ships 6/1").collect::<Vec<_>>(),
+            vec!["v1.2 ships 6/1"]
+        );
+    }
+
+    fn at(month: u32, day: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2021, month, day)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+}
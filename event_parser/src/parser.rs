@@ -0,0 +1,270 @@
+//! A parser built once with its settings and reused for every text, like the date and time parsers of
+//! `date_time_parser`. [`to_event`](fn.to_event.html) parses with a default one.
+
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc, Weekday};
+use date_time_parser::{Clock, Direction, LocalClock, MeridiemPolicy, Rounding};
+use icalendar::Event;
+use std::sync::Arc;
+
+use crate::{parse_at, zone_of, Confidence, ParseOutcome, Parsers};
+
+/// Reads the current wall-clock time, in the timezone the parser reads text in.
+type Now = Arc<dyn Fn() -> NaiveDateTime + Send + Sync>;
+
+#[derive(Clone)]
+/// Parses text into events with the settings it was built with. Build one with
+/// [`EventParser::builder`](#method.builder) and reuse it for every text; the default one parses like
+/// [`to_event`](fn.to_event.html).
+///
+/// # Example
+/// ```
+/// use chrono::{Duration, NaiveDate, Weekday};
+/// use event_parser::{EventParser, FixedClock, MeridiemPolicy};
+/// use icalendar::Component;
+///
+/// let parser = EventParser::builder()
+///     .default_duration(Duration::minutes(30))
+///     .meridiem(MeridiemPolicy::Am)
+///     .week_start(Weekday::Sun)
+///     .locale("en_GB")
+///     .build();
+///
+/// let clock = FixedClock(NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(6, 0, 0).unwrap());
+/// let event = parser.parse_with_clock("Run at 7 on 5/6", &clock);
+///
+/// assert_eq!(event.properties()["DTSTART"].value(), "20210605T070000Z");
+/// assert_eq!(event.properties()["DTEND"].value(), "20210605T073000Z");
+/// ```
+pub struct EventParser {
+    parsers: Parsers,
+    now: Now,
+}
+
+impl Default for EventParser {
+    fn default() -> EventParser {
+        EventParser::builder().build()
+    }
+}
+
+impl EventParser {
+    /// Returns a builder for a parser, starting from the settings [`to_event`](fn.to_event.html) uses.
+    pub fn builder() -> EventParserBuilder {
+        EventParserBuilder {
+            parsers: Parsers::default(),
+            now: Arc::new(|| LocalClock.now()),
+        }
+    }
+
+    /// Parses `text` into an `Event`, reading relative dates and times like "tomorrow" around the current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed.
+    pub fn parse(&self, text: &str) -> Event {
+        self.parse_with_warnings(text).event
+    }
+
+    /// Parses `text` into an `Event` like [`parse`](#method.parse), reading the current date and time from `clock`,
+    /// which gives it in the timezone the parser reads text in.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed.
+    /// * `clock` - The source of the current date and time that relative dates and times are read around.
+    pub fn parse_with_clock(&self, text: &str, clock: &dyn Clock) -> Event {
+        parse_at(text, clock.now(), &self.parsers).event
+    }
+
+    /// Parses `text` into an `Event` like [`parse`](#method.parse), along with caveats about how the text was read,
+    /// as [`to_event_with_warnings`](fn.to_event_with_warnings.html) gives them.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - A string slice that holds the the text to be parsed.
+    pub fn parse_with_warnings(&self, text: &str) -> ParseOutcome {
+        parse_at(text, (self.now)(), &self.parsers)
+    }
+}
+
+#[derive(Clone)]
+/// Settings for an [`EventParser`](struct.EventParser.html), from [`EventParser::builder`](struct.EventParser.html#method.builder).
+pub struct EventParserBuilder {
+    parsers: Parsers,
+    now: Now,
+}
+
+impl EventParserBuilder {
+    /// Sets how long events without an end or a duration last. Defaults to an hour.
+    pub fn default_duration(mut self, duration: Duration) -> EventParserBuilder {
+        self.parsers.duration = duration;
+        self
+    }
+
+    /// Sets whether events given a date but no time, like "Dentist June 5", last all day, as they do by default. When
+    /// off, they start at 9am and last the default duration.
+    pub fn all_day(mut self, all_day: bool) -> EventParserBuilder {
+        self.parsers.all_day = all_day;
+        self
+    }
+
+    /// Sets the first day of the week, used for phrases like "all week" and "this week". Defaults to Monday.
+    pub fn week_start(mut self, week_start: Weekday) -> EventParserBuilder {
+        self.parsers.date = self.parsers.date.with_week_start(week_start);
+        self
+    }
+
+    /// Sets how an hour without am or pm, like "at 7", is read. Defaults to
+    /// [`MeridiemPolicy::Guess`](enum.MeridiemPolicy.html#variant.Guess).
+    pub fn meridiem(mut self, policy: MeridiemPolicy) -> EventParserBuilder {
+        self.parsers.time = self.parsers.time.with_meridiem_policy(policy);
+        self
+    }
+
+    /// Sets whether dates without a year or week, like "Friday", are read toward the next or the last occurrence, as
+    /// [`to_event_with_direction`](fn.to_event_with_direction.html) does. Defaults to
+    /// [`Direction::Future`](enum.Direction.html#variant.Future).
+    pub fn direction(mut self, direction: Direction) -> EventParserBuilder {
+        self.parsers.date = self.parsers.date.with_direction(direction);
+        self
+    }
+
+    /// Sets how times worked out from now, like "in 2 hours" or "soon", are rounded. Defaults to
+    /// [`Rounding::Exact`](enum.Rounding.html#variant.Exact).
+    pub fn rounding(mut self, rounding: Rounding) -> EventParserBuilder {
+        self.parsers.time = self.parsers.time.with_rounding(rounding);
+        self
+    }
+
+    /// Sets how sure the parser has to be of a start time to keep it; events whose time is less certain are all day.
+    /// Defaults to [`Confidence::Low`](enum.Confidence.html#variant.Low), keeping every time.
+    pub fn min_confidence(mut self, min_confidence: Confidence) -> EventParserBuilder {
+        self.parsers.min_confidence = min_confidence;
+        self
+    }
+
+    /// Sets the timezone text is written in, e.g. `Local`, a `FixedOffset` or a timezone from the `chrono-tz` crate,
+    /// like [`to_event_in_timezone`](fn.to_event_in_timezone.html). Times are read as wall-clock times in it and
+    /// given in UTC, and relative dates and times are read around the current time in it. Without one, wall-clock
+    /// times are written as they are, as if they were UTC.
+    pub fn timezone<Tz>(mut self, tz: Tz) -> EventParserBuilder
+    where
+        Tz: TimeZone + Send + Sync + 'static,
+    {
+        let local = tz.clone();
+        self.now = Arc::new(move || Utc::now().with_timezone(&local).naive_local());
+        self.parsers.zone = Some(zone_of(tz));
+        self
+    }
+
+    /// Sets the locale slash dates like "5/6" are read in, e.g. "en_GB" for day first. Defaults to month first, as in
+    /// "en_US". Dates and times are also read in its language, like "es_MX" for "mañana a las 5", if the language's
+    /// feature is enabled.
+    pub fn locale(mut self, locale: &str) -> EventParserBuilder {
+        self.parsers.date = self.parsers.date.with_locale(locale);
+        self.parsers.time = self.parsers.time.with_locale(locale);
+        self
+    }
+
+    /// Sets whether numbers that are not dates or times, like the phone number of "Call 555-1230", the price of
+    /// "$5 lunch" and the version of "deploy v2.30", are left out of the dates and times read, as they are by default.
+    pub fn context_filters(mut self, context_filters: bool) -> EventParserBuilder {
+        self.parsers.date = self.parsers.date.with_context_filters(context_filters);
+        self.parsers.time = self.parsers.time.with_context_filters(context_filters);
+        self
+    }
+
+    /// Returns the parser with these settings.
+    pub fn build(self) -> EventParser {
+        EventParser {
+            parsers: self.parsers,
+            now: self.now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod parser_tests {
+    use super::EventParser;
+    use crate::Confidence;
+    use chrono::{Duration, FixedOffset, NaiveDate, Weekday};
+    use date_time_parser::{Direction, FixedClock, MeridiemPolicy, Rounding};
+    use icalendar::{Component, Event};
+
+    #[test]
+    fn builder_tests() {
+        let clock = FixedClock(
+            NaiveDate::from_ymd_opt(2021, 3, 3)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap(),
+        );
+        let value = |e: &Event, key: &str| e.properties()[key].value().to_owned();
+
+        let parser = EventParser::default();
+        let e = parser.parse_with_clock("Dinner at 7pm tomorrow", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210304T190000Z");
+        assert_eq!(value(&e, "DTEND"), "20210304T200000Z");
+        assert_eq!(
+            value(
+                &parser.parse_with_clock("Dentist June 5", &clock),
+                "DTSTART"
+            ),
+            "20210605"
+        );
+
+        let parser = EventParser::builder()
+            .default_duration(Duration::minutes(45))
+            .all_day(false)
+            .meridiem(MeridiemPolicy::Pm)
+            .locale("en_GB")
+            .build();
+        let e = parser.parse_with_clock("Dinner at 7 tomorrow", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210304T190000Z");
+        assert_eq!(value(&e, "DTEND"), "20210304T194500Z");
+        let e = parser.parse_with_clock("Dentist 5/6", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210605T090000Z");
+        assert_eq!(value(&e, "DTEND"), "20210605T094500Z");
+
+        let parser = EventParser::builder().week_start(Weekday::Sun).build();
+        let e = parser.parse_with_clock("Conference all week", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210228");
+
+        let parser = EventParser::builder()
+            .timezone(FixedOffset::west_opt(5 * 3600).unwrap())
+            .build();
+        let e = parser.parse_with_clock("Dinner at 7pm tomorrow", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210305T000000Z");
+
+        let parser = EventParser::builder().direction(Direction::Past).build();
+        let e = parser.parse_with_clock("Retro Monday at 3pm", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210301T150000Z");
+
+        let parser = EventParser::builder().rounding(Rounding::Nearest15).build();
+        let e = parser.parse_with_clock("Call mom in 2 hours", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210303T100000Z");
+
+        let parser = EventParser::builder()
+            .min_confidence(Confidence::Medium)
+            .build();
+        let e = parser.parse_with_clock("Meet in room 5", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210303");
+
+        let parser = EventParser::builder().context_filters(false).build();
+        let e = parser.parse_with_clock("$5 lunch", &clock);
+        assert_eq!(value(&e, "DTSTART"), "20210303T170000Z");
+    }
+
+    #[cfg(feature = "locale-es")]
+    #[test]
+    fn locale_builder_tests() {
+        let clock = FixedClock(
+            NaiveDate::from_ymd_opt(2021, 3, 3)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap(),
+        );
+        let parser = EventParser::builder().locale("es_MX").build();
+        let e = parser.parse_with_clock("Cena mañana a las 8 de la noche", &clock);
+        assert_eq!(e.properties()["DTSTART"].value(), "20210304T200000Z");
+    }
+}
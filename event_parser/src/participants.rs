@@ -0,0 +1,186 @@
+//! The people an event is with, named after "with", like "Alice" and "bob@example.com" of "Lunch with Alice and
+//! bob@example.com", or given by their email address anywhere in the text.
+
+use date_time_parser::patterns::{weekday_named, Pattern};
+use icalendar::Property;
+
+/// An email address, e.g. "bob@example.com".
+pub(crate) static EMAIL: Pattern = Pattern::new(r"\b[\w.+-]+@[\w-]+(\.[\w-]+)*\.[A-Za-z]{2,}\b");
+
+/// A list of people after "with", e.g. "with Alice, Bob Smith and carol@example.com", with the capture `list`. People
+/// are named with capitalized words or an email address, so "with friends" names no one.
+static WITH_LIST: Pattern = Pattern::new(
+    r"\b[Ww]ith\s(?P<list>(?:[\w.+-]+@[\w.-]+|[A-Z][\w'-]*(?:\s[A-Z][\w'-]*)?)(?:(?:,\s?|,?\s(?:and|&)\s)(?:[\w.+-]+@[\w.-]+|[A-Z][\w'-]*(?:\s[A-Z][\w'-]*)?))*)",
+);
+
+/// One person in a list after "with", e.g. "Bob Smith" or "carol@example.com".
+static LIST_ITEM: Pattern = Pattern::new(r"[\w.+-]+@[\w.-]+|[A-Z][\w'-]*(?:\s[A-Z][\w'-]*)?");
+
+/// Capitalized words that start a date or time rather than a name, like "Friday" of "with Alice Friday".
+static TEMPORAL_WORD: Pattern = Pattern::new(
+    r"^(?i:(jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\.?|today|tonight|tomorrow|next|this|last|noon|midnight|every)$",
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Someone an event is with.
+pub(crate) enum Participant {
+    /// A person named in words, e.g. "Alice"
+    Name(String),
+    /// A person given by their email address, e.g. "bob@example.com"
+    Email(String),
+}
+
+impl Participant {
+    /// Returns the `ATTENDEE` property for this person [(RFC 5545, Section 3.8.4.1)](https://tools.ietf.org/html/rfc5545#section-3.8.4.1).
+    /// A person without an address is given as "invalid:nomail", as calendar apps do.
+    pub(crate) fn attendee(&self) -> Property {
+        match self {
+            Participant::Name(name) => Property::new("ATTENDEE", "invalid:nomail")
+                .add_parameter("CN", name)
+                .done(),
+            Participant::Email(address) => {
+                Property::new("ATTENDEE", &format!("mailto:{}", address))
+            }
+        }
+    }
+}
+
+/// Returns the people in `text`, those listed after "with" first and then any other email addresses, each once.
+pub(crate) fn find_participants(text: &str) -> Vec<Participant> {
+    let mut found: Vec<Participant> = WITH_LIST
+        .captures_iter(text)
+        .flat_map(|caps| listed(caps.name("list").unwrap().as_str()))
+        .collect();
+    for address in EMAIL.find_iter(text) {
+        found.push(Participant::Email(address.as_str().to_owned()));
+    }
+    let mut seen = Vec::new();
+    found.retain(|p| {
+        let new = !seen.contains(p);
+        seen.push(p.clone());
+        new
+    });
+    found
+}
+
+/// Returns `text` with the people in it taken out, for the summary. Email addresses always are, and a list after
+/// "with" keeps the names in it if `keep_names`, e.g. "Lunch with Alice and bob@example.com" becomes "Lunch with Alice".
+pub(crate) fn without_participants(text: &str, keep_names: bool) -> String {
+    let text = WITH_LIST.replace_all(text, |caps: &regex::Captures| {
+        let list = caps.name("list").unwrap();
+        let with = &caps[0][..list.start() - caps.get(0).unwrap().start()];
+        let (people, used) = listed_with_len(list.as_str());
+        let names: Vec<&str> = people
+            .iter()
+            .filter_map(|p| match p {
+                Participant::Name(name) if keep_names => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        // words after the names, like "Friday" of "with Alice Friday", are left for the other patterns
+        let rest = &list.as_str()[used..];
+        match names.split_last() {
+            None => rest.to_owned(),
+            Some((last, [])) => format!("{}{}{}", with, last, rest),
+            Some((last, others)) => format!("{}{} and {}{}", with, others.join(", "), last, rest),
+        }
+    });
+    EMAIL.replace_all(&text, "").into_owned()
+}
+
+/// Returns the people in a list after "with", stopping at the first word that starts a date or time.
+fn listed(list: &str) -> Vec<Participant> {
+    listed_with_len(list).0
+}
+
+/// Returns the people in a list after "with" like [`listed`], and how many bytes of the list they take up.
+fn listed_with_len(list: &str) -> (Vec<Participant>, usize) {
+    let mut people = Vec::new();
+    let mut used = 0;
+    for item in LIST_ITEM.find_iter(list) {
+        if EMAIL.is_match(item.as_str()) {
+            people.push(Participant::Email(item.as_str().to_owned()));
+            used = item.end();
+            continue;
+        }
+        let words: Vec<&str> = item
+            .as_str()
+            .split(' ')
+            .take_while(|word| !TEMPORAL_WORD.is_match(word) && weekday_named(word).is_none())
+            .collect();
+        if words.is_empty() {
+            break;
+        }
+        let name = words.join(" ");
+        used = item.start() + name.len();
+        people.push(Participant::Name(name));
+        if used < item.end() {
+            break;
+        }
+    }
+    (people, used)
+}
+
+#[cfg(test)]
+mod participants_tests {
+    use super::{find_participants, without_participants, Participant};
+
+    #[test]
+    fn find_participants_tests() {
+        let name = |n: &str| Participant::Name(n.to_owned());
+        let email = |e: &str| Participant::Email(e.to_owned());
+        assert_eq!(
+            find_participants("Lunch with Alice and bob@example.com"),
+            vec![name("Alice"), email("bob@example.com")]
+        );
+        assert_eq!(
+            find_participants("Call with Alice, Bob Smith & Carol tomorrow"),
+            vec![name("Alice"), name("Bob Smith"), name("Carol")]
+        );
+        assert_eq!(
+            find_participants("Coffee with Sam Friday at 3pm"),
+            vec![name("Sam")]
+        );
+        assert_eq!(
+            find_participants("Send notes to dana@example.org"),
+            vec![email("dana@example.org")]
+        );
+        assert_eq!(find_participants("Dinner with friends"), vec![]);
+        assert_eq!(find_participants("Meeting with June 5"), vec![]);
+    }
+
+    #[test]
+    fn without_participants_tests() {
+        let text = "Lunch with Alice and bob@example.com tomorrow";
+        assert_eq!(
+            without_participants(text, true),
+            "Lunch with Alice tomorrow"
+        );
+        // the spaces left over are collapsed in the summary
+        assert_eq!(without_participants(text, false), "Lunch  tomorrow");
+        assert_eq!(
+            without_participants("Call with Alice, Bob Smith & Carol", true),
+            "Call with Alice, Bob Smith and Carol"
+        );
+        assert_eq!(
+            without_participants("Coffee with Sam Friday", false),
+            "Coffee  Friday"
+        );
+        assert_eq!(
+            without_participants("Dinner with friends", false),
+            "Dinner with friends"
+        );
+    }
+
+    #[test]
+    fn attendee_tests() {
+        let attendee = Participant::Email("bob@example.com".to_owned()).attendee();
+        assert_eq!(attendee.key(), "ATTENDEE");
+        assert_eq!(attendee.value(), "mailto:bob@example.com");
+        let attendee = Participant::Name("Alice".to_owned()).attendee();
+        assert_eq!(attendee.value(), "invalid:nomail");
+        let mut written = String::new();
+        attendee.fmt_write(&mut written).unwrap();
+        assert_eq!(written, "ATTENDEE;CN=Alice:invalid:nomail\r\n");
+    }
+}
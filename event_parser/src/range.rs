@@ -0,0 +1,105 @@
+//! Ranges of dates or times written with a connector, like "6/1-6/8", "7–9pm", "from 7 to 9", "between 2 and 4" or
+//! "seven till nine pm".
+
+use date_time_parser::patterns::Pattern;
+use std::ops::Range;
+
+/// A pair of dates or times joined by a dash or "to", e.g. "6/1-6/8", "9 to 5", "Mon–Fri" or "7 pm through 9 pm",
+/// with the captures `start` and `end`.
+static DASH_RANGE: Pattern = Pattern::new(
+    r"(?P<start>[/:\w]+(\s?(?i:[ap]\.?m\b\.?))?)(\s?(-|–|—|to|\bthrough\b|\bthru\b)\s?)(?P<end>[/:\w]+(\s?(?i:[ap]\.?m\b\.?))?)",
+);
+
+/// One side of a range that can only be a time: an hour, maybe with minutes, or an hour in words, with an optional
+/// meridiem, e.g. "7", "9:30pm", "nine pm" or "noon".
+macro_rules! time_side {
+    () => {
+        r"(\d{1,2}(:\d{2})?|noon|midnight|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)(\s?[ap]\.?m\b\.?|\b)"
+    };
+}
+
+/// A pair of times joined by "till" or "until", e.g. "7 till 9pm" or "seven until nine pm", with the captures `start`
+/// and `end`. Dates are left out, as "until" also ends a schedule, as in "every Monday until 6/30".
+pub(crate) static TILL_RANGE: Pattern = Pattern::new(concat!(
+    r"(?i)\b(?P<start>",
+    time_side!(),
+    r")\s('til|til|till|until)\s(?P<end>",
+    time_side!(),
+    ")"
+));
+
+/// A pair of times after "between", e.g. "between 2 and 4pm", with the captures `start` and `end`.
+pub(crate) static BETWEEN_RANGE: Pattern = Pattern::new(concat!(
+    r"(?i)\bbetween\s(?P<start>",
+    time_side!(),
+    r")\s(and|&)\s(?P<end>",
+    time_side!(),
+    ")"
+));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A range found by [`ranges`].
+pub(crate) struct RangeMatch<'t> {
+    /// The text before the connector, e.g. "7" of "7 till 9pm"
+    pub start: &'t str,
+    /// The text after the connector, e.g. "9pm" of "7 till 9pm"
+    pub end: &'t str,
+    /// Where the whole range is in the text, in bytes
+    pub span: Range<usize>,
+    /// Where the connector between the two ends is in the text, in bytes, e.g. " till " of "7 till 9pm"
+    pub connector: Range<usize>,
+    /// Whether the range can only be one of times, as with "till" and "between"
+    pub times_only: bool,
+}
+
+/// Returns every range in `text`, those joined by "between" or "till" first and then those joined by a dash or "to",
+/// each in the order they appear.
+pub(crate) fn ranges(text: &str) -> impl Iterator<Item = RangeMatch<'_>> {
+    let found = move |pattern: &'static Pattern, times_only: bool| {
+        pattern.captures_iter(text).map(move |caps| RangeMatch {
+            start: caps.name("start").unwrap().as_str(),
+            end: caps.name("end").unwrap().as_str(),
+            span: caps.get(0).unwrap().range(),
+            connector: caps.name("start").unwrap().end()..caps.name("end").unwrap().start(),
+            times_only,
+        })
+    };
+    found(&BETWEEN_RANGE, true)
+        .chain(found(&TILL_RANGE, true))
+        .chain(found(&DASH_RANGE, false))
+}
+
+#[cfg(test)]
+mod range_tests {
+    use super::ranges;
+
+    #[test]
+    fn ranges_tests() {
+        let sides = |text| {
+            ranges(text)
+                .next()
+                .map(|found| (found.start, found.end, found.times_only))
+        };
+        assert_eq!(sides("Party 7-9pm"), Some(("7", "9pm", false)));
+        assert_eq!(sides("Party from 7 to 9"), Some(("7", "9", false)));
+        assert_eq!(sides("Call 3–4pm"), Some(("3", "4pm", false)));
+        assert_eq!(sides("Call 3 — 4 pm"), Some(("3", "4 pm", false)));
+        assert_eq!(sides("Camp 6/1 through 6/8"), Some(("6/1", "6/8", false)));
+        assert_eq!(sides("Meeting between 2 and 4pm"), Some(("2", "4pm", true)));
+        assert_eq!(sides("Dinner 7 till 9pm"), Some(("7", "9pm", true)));
+        assert_eq!(
+            sides("from seven until nine pm"),
+            Some(("seven", "nine pm", true))
+        );
+        assert_eq!(sides("Class 10am until noon"), Some(("10am", "noon", true)));
+        assert_eq!(sides("Gym every day until June 30"), None);
+        assert_eq!(sides("Dinner until late"), None);
+        assert_eq!(sides("between you and me"), None);
+
+        // "to" inside a word is a range too, which the sides passed to the parsers rule out
+        let found: Vec<_> = ranges("Party tomorrow 10pm-2am")
+            .map(|found| found.start)
+            .collect();
+        assert_eq!(found, vec!["Party", "10pm"]);
+    }
+}
@@ -0,0 +1,383 @@
+//! Parse events from any reader and write them to any writer, one line at a time, so large files never have to be
+//! held in memory.
+
+use date_time_parser::{may_be_temporal, Clock, LocalClock};
+use icalendar::Event;
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::iter;
+use std::str;
+
+use crate::extract::{extract_at, Entity};
+#[cfg(feature = "config")]
+use crate::ParserConfig;
+use crate::{parse_at, write_pretty, HourFormat, ParseOutcome, Parsers};
+
+/// The most bytes of a line scanned at once. Longer lines are scanned in pieces split at whitespace.
+const CHUNK: usize = 64 * 1024;
+
+/// Parses each non-blank line read from `reader` into an event, lazily, as the returned iterator is advanced. Relative
+/// dates and times on each line are read around the local time when that line is parsed. An error reading a line is
+/// yielded in its place.
+///
+/// # Arguments
+///
+/// * `reader` - A buffered reader with one event per line, like a file or `stdin`.
+///
+/// # Example
+/// ```
+/// use event_parser::parse_events_from_reader;
+/// use icalendar::Component;
+///
+/// let input = "Lunch at noon\n\nDinner at 7pm\n".as_bytes();
+/// let summaries: Vec<_> = parse_events_from_reader(input)
+///     .map(|parsed| parsed.unwrap().event.properties()["SUMMARY"].value().to_owned())
+///     .collect();
+///
+/// assert_eq!(summaries, vec!["Lunch", "Dinner"]);
+/// ```
+pub fn parse_events_from_reader<'a, R: BufRead + 'a>(
+    reader: R,
+) -> impl Iterator<Item = io::Result<ParseOutcome>> + 'a {
+    parse_events_with_clock(reader, &LocalClock)
+}
+
+/// Parses each non-blank line read from `reader` into an event like
+/// [`parse_events_from_reader`](fn.parse_events_from_reader.html), with the settings in `config` and reading relative
+/// dates and times around `clock`. Enabled with the `config` feature.
+///
+/// # Arguments
+///
+/// * `reader` - A buffered reader with one event per line, like a file or `stdin`.
+/// * `config` - How to parse each line.
+/// * `clock` - Where the current date and time come from, e.g. an [`OffsetClock`](struct.OffsetClock.html) for
+///   another timezone.
+///
+/// # Example
+/// ```
+/// use event_parser::{parse_events_with_config, LocalClock, ParserConfig};
+/// use icalendar::Component;
+///
+/// let config = ParserConfig::from_toml("default_duration = 30").unwrap();
+/// let input = "Coffee at 9am\n".as_bytes();
+/// let event = parse_events_with_config(input, &config, &LocalClock).next().unwrap().unwrap().event;
+///
+/// assert_eq!(&event.properties()["DTEND"].value()[9..13], "0930");
+/// ```
+#[cfg(feature = "config")]
+pub fn parse_events_with_config<'a, R: BufRead + 'a>(
+    reader: R,
+    config: &ParserConfig,
+    clock: &'a dyn Clock,
+) -> impl Iterator<Item = io::Result<ParseOutcome>> + 'a {
+    parse_events_with(reader, clock, config.parsers())
+}
+
+/// Parses each non-blank line read from `reader` with respect to `clock`.
+fn parse_events_with_clock<'a, R: BufRead + 'a>(
+    reader: R,
+    clock: &'a dyn Clock,
+) -> impl Iterator<Item = io::Result<ParseOutcome>> + 'a {
+    parse_events_with(reader, clock, Parsers::default())
+}
+
+/// Parses each non-blank line read from `reader` with respect to `clock`, finding dates and times with `parsers`.
+fn parse_events_with<'a, R: BufRead + 'a>(
+    reader: R,
+    clock: &'a dyn Clock,
+    parsers: Parsers,
+) -> impl Iterator<Item = io::Result<ParseOutcome>> + 'a {
+    reader
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+        .map(move |line| line.map(|line| parse_at(&line, clock.now(), &parsers)))
+}
+
+/// Finds the entities in a document read from `reader`, like [`extract`](fn.extract.html) does for a string, yielding
+/// them as they are found. Only a line of the document is held in memory at a time, and lines with nothing that could
+/// be a date or time are skipped without being parsed, so large transcripts or mail archives can be scanned whole.
+/// Relative dates and times on each line are read around the local time when that line is scanned.
+///
+/// Each entity's span is where it is in the whole document, in bytes. Entities are not found across lines, or across
+/// the pieces a line longer than 64 KiB is split into. An error reading the document is yielded in place of the
+/// entities in the part that could not be read.
+///
+/// # Arguments
+///
+/// * `reader` - A buffered reader with the document, like a file or `stdin`.
+///
+/// # Example
+/// ```
+/// use event_parser::{scan_entities, EntityKind};
+/// use chrono::Duration;
+///
+/// let input = "Minutes of the planning meeting\nThe review runs 45 minutes\n";
+/// let entities: Vec<_> = scan_entities(input.as_bytes()).map(Result::unwrap).collect();
+///
+/// assert_eq!(entities.len(), 1);
+/// assert_eq!(entities[0].kind, EntityKind::Duration(Duration::minutes(45)));
+/// assert_eq!(&input[entities[0].span.clone()], "45 minutes");
+/// ```
+pub fn scan_entities<'a, R: BufRead + 'a>(
+    reader: R,
+) -> impl Iterator<Item = io::Result<Entity>> + 'a {
+    scan_entities_with_clock(reader, &LocalClock)
+}
+
+/// Finds the entities in a document read from `reader` with respect to `clock`.
+fn scan_entities_with_clock<'a, R: BufRead + 'a>(
+    mut reader: R,
+    clock: &'a dyn Clock,
+) -> impl Iterator<Item = io::Result<Entity>> + 'a {
+    let mut chunk = Vec::new();
+    let mut offset = 0;
+    let mut found = VecDeque::new();
+    iter::from_fn(move || loop {
+        if let Some(entity) = found.pop_front() {
+            return Some(Ok(entity));
+        }
+        if let Err(e) = read_chunk(&mut reader, &mut chunk) {
+            return Some(Err(e));
+        }
+        if chunk.is_empty() {
+            return None;
+        }
+
+        // a line cut short is split after its last whitespace, so no word is cut in two
+        let cut = if chunk.len() < CHUNK || chunk.ends_with(b"\n") {
+            chunk.len()
+        } else {
+            chunk
+                .iter()
+                .rposition(u8::is_ascii_whitespace)
+                .map_or(chunk.len(), |i| i + 1)
+        };
+        let len = match str::from_utf8(&chunk[..cut]) {
+            Ok(_) => cut,
+            // a character cut in two is left for the next piece
+            Err(e) if e.error_len().is_none() && e.valid_up_to() > 0 => e.valid_up_to(),
+            Err(_) => {
+                offset += chunk.len();
+                chunk.clear();
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                )));
+            }
+        };
+
+        let text = str::from_utf8(&chunk[..len]).unwrap();
+        if may_be_temporal(text) {
+            found.extend(
+                extract_at(text.trim_end(), clock.now())
+                    .into_iter()
+                    .map(|e| Entity {
+                        span: e.span.start + offset..e.span.end + offset,
+                        ..e
+                    }),
+            );
+        }
+        offset += len;
+        chunk.drain(..len);
+    })
+}
+
+/// Reads from `reader` onto the end of `chunk` up to the end of the line, or until it is [`CHUNK`] bytes long.
+fn read_chunk(reader: &mut impl BufRead, chunk: &mut Vec<u8>) -> io::Result<()> {
+    while chunk.len() < CHUNK && !chunk.ends_with(b"\n") {
+        let available = reader.fill_buf()?;
+        if available.is_empty() {
+            break;
+        }
+        let available = &available[..available.len().min(CHUNK - chunk.len())];
+        let take = available
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(available.len(), |i| i + 1);
+        chunk.extend_from_slice(&available[..take]);
+        reader.consume(take);
+    }
+    Ok(())
+}
+
+/// Writes each event to `writer` in the format [`pretty_print`](fn.pretty_print.html) uses, stopping at the first
+/// error writing.
+///
+/// # Arguments
+///
+/// * `writer` - Where to write the events, like a file or `stdout`.
+/// * `events` - The events to write, owned or borrowed.
+///
+/// # Example
+/// ```
+/// use event_parser::write_events;
+/// use icalendar::{Component, Event};
+///
+/// let mut out = Vec::new();
+/// write_events(&mut out, [Event::new().summary("Lunch").done()]).unwrap();
+///
+/// assert_eq!(String::from_utf8(out).unwrap(), "Event: \"Lunch\"\n");
+/// ```
+pub fn write_events<W, I>(writer: W, events: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<Event>,
+{
+    write_events_with_format(writer, events, HourFormat::default())
+}
+
+/// Writes each event to `writer` like [`write_events`](fn.write_events.html), with times written in `hours`.
+///
+/// # Arguments
+///
+/// * `writer` - Where to write the events, like a file or `stdout`.
+/// * `events` - The events to write, owned or borrowed.
+/// * `hours` - Whether times are written on a 12 or 24-hour clock.
+///
+/// # Example
+/// ```
+/// use event_parser::{to_event, write_events_with_format, HourFormat};
+///
+/// let mut out = Vec::new();
+/// write_events_with_format(&mut out, [to_event("Dinner at 7pm")], HourFormat::TwentyFourHour).unwrap();
+///
+/// assert!(String::from_utf8(out).unwrap().contains("19:00"));
+/// ```
+pub fn write_events_with_format<W, I>(mut writer: W, events: I, hours: HourFormat) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: Borrow<Event>,
+{
+    for event in events {
+        write_pretty(&mut writer, event.borrow(), hours)?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::{
+        parse_events_with_clock, scan_entities_with_clock, write_events, write_events_with_format,
+        CHUNK,
+    };
+    use crate::{EntityKind, FixedClock, HourFormat};
+    use chrono::{NaiveDate, NaiveTime};
+    use std::io::{self, BufReader, Read};
+
+    #[test]
+    fn read_write_tests() {
+        let clock = FixedClock(
+            NaiveDate::from_ymd_opt(2021, 3, 3)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap(),
+        );
+        let input = "Lunch at noon\n   \nDinner at Cafe Rio 7-9pm tomorrow\n".as_bytes();
+        let events: Vec<_> = parse_events_with_clock(input, &clock)
+            .map(|parsed| parsed.unwrap().event)
+            .collect();
+
+        let mut out = Vec::new();
+        write_events(&mut out, &events).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Event: \"Lunch\"\n\
+             12:00pm March 03 2021 - 01:00pm March 03 2021\n\
+             Event: \"Dinner\"\n\
+             Location: \"Cafe Rio\"\n\
+             07:00pm March 04 2021 - 09:00pm March 04 2021\n"
+        );
+    }
+
+    #[test]
+    fn hour_format_tests() {
+        let clock = FixedClock(
+            NaiveDate::from_ymd_opt(2021, 3, 3)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap(),
+        );
+        let input = "Dinner 7-9:30pm\n".as_bytes();
+        let events: Vec<_> = parse_events_with_clock(input, &clock)
+            .map(|parsed| parsed.unwrap().event)
+            .collect();
+
+        let mut out = Vec::new();
+        write_events_with_format(&mut out, &events, HourFormat::TwentyFourHour).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Event: \"Dinner\"\n\
+             19:00 March 03 2021 - 21:30 March 03 2021\n"
+        );
+    }
+
+    #[test]
+    fn read_error_tests() {
+        struct Broken;
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let reader = BufReader::new(Broken);
+        let clock = crate::LocalClock;
+        let mut parsed = parse_events_with_clock(reader, &clock);
+        assert!(parsed.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn scan_entities_tests() {
+        let clock = FixedClock(
+            NaiveDate::from_ymd_opt(2021, 3, 3)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap(),
+        );
+        let scan = |input: &str| -> Vec<(String, EntityKind)> {
+            scan_entities_with_clock(input.as_bytes(), &clock)
+                .map(|entity| {
+                    let entity = entity.unwrap();
+                    (input[entity.span].to_owned(), entity.kind)
+                })
+                .collect()
+        };
+        let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(
+            scan("Agenda\nSync moved to Friday\n\nLunch at noon"),
+            vec![
+                (
+                    "Friday".to_owned(),
+                    EntityKind::Date(NaiveDate::from_ymd_opt(2021, 3, 5).unwrap())
+                ),
+                ("noon".to_owned(), EntityKind::Time(hm(12, 0))),
+            ]
+        );
+
+        // a line too long to scan at once is split between words
+        let long = format!(
+            "{}dinner at 7pm and drinks at 9pm",
+            "blah ".repeat(CHUNK / 5 - 3)
+        );
+        assert_eq!(
+            scan(&long),
+            vec![
+                ("7pm".to_owned(), EntityKind::Time(hm(19, 0))),
+                ("9pm".to_owned(), EntityKind::Time(hm(21, 0))),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_error_tests() {
+        let clock = crate::LocalClock;
+        let input: &[u8] = b"caf\xe9 at 7pm\nlunch at noon\n";
+        let scanned: Vec<_> = scan_entities_with_clock(input, &clock).collect();
+        assert!(scanned[0].is_err());
+        assert_eq!(scanned.len(), 2);
+    }
+}
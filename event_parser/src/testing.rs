@@ -0,0 +1,192 @@
+//! Check that events survive being described in words and parsed back, so changes to the parser, or rules and locales
+//! built on it, can be tested against many events at once. Enabled with the `testing` feature.
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use icalendar::{Component, Event};
+use std::error::Error;
+use std::fmt;
+
+use crate::{describe_event, utc_date};
+
+const SUMMARIES: [&str; 10] = [
+    "Lunch",
+    "Dinner with Sam",
+    "Dentist",
+    "Yoga",
+    "Book club",
+    "Team retro",
+    "Piano lesson",
+    "Haircut",
+    "Standup",
+    "Coffee",
+];
+const LOCATIONS: [&str; 3] = ["Cafe Rio", "Union Station", "Blue Bottle"];
+
+/// The properties compared after a round trip.
+const COMPARED: [&str; 4] = ["SUMMARY", "LOCATION", "DTSTART", "DTEND"];
+
+/// An endless series of random events, the same for the same seed. Timed events start and end on the same day between
+/// 6am and midnight; all-day events last up to a week.
+///
+/// # Example
+/// ```
+/// use event_parser::testing::EventGenerator;
+///
+/// let events: Vec<_> = EventGenerator::new(7).take(10).collect();
+/// assert_eq!(events.len(), 10);
+/// ```
+pub struct EventGenerator {
+    state: u64,
+}
+
+impl EventGenerator {
+    /// Creates a generator of the events for `seed`.
+    pub fn new(seed: u64) -> EventGenerator {
+        EventGenerator { state: seed }
+    }
+
+    /// Returns a random number below `n` (SplitMix64).
+    fn below(&mut self, n: u64) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        (z ^ (z >> 31)) % n
+    }
+
+    fn event(&mut self) -> Event {
+        let mut e = Event::new();
+        e.summary(SUMMARIES[self.below(SUMMARIES.len() as u64) as usize]);
+        if self.below(3) == 0 {
+            e.location(LOCATIONS[self.below(LOCATIONS.len() as u64) as usize]);
+        }
+
+        let year = 2000 + self.below(41) as i32;
+        let month = 1 + self.below(12) as u32;
+        let day = 1 + self.below(28) as u32;
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        if self.below(4) == 0 {
+            e.start_date(utc_date(date));
+            e.end_date(utc_date(date + Duration::days(self.below(7) as i64)));
+        } else {
+            // quarter hours from 6am, ending by midnight
+            let start =
+                date.and_hms_opt(6, 0, 0).unwrap() + Duration::minutes(15 * self.below(64) as i64);
+            let latest = (date.and_hms_opt(23, 45, 0).unwrap() - start).num_minutes() / 15;
+            let end =
+                start + Duration::minutes(15 * (1 + self.below(latest.min(16) as u64) as i64));
+            e.starts(Utc.from_utc_datetime(&start));
+            e.ends(Utc.from_utc_datetime(&end));
+        }
+        e.done()
+    }
+}
+
+impl Iterator for EventGenerator {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        Some(self.event())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An event that came back different after being described and parsed again.
+pub struct RoundTripError {
+    /// The event's description that was parsed.
+    pub text: String,
+    /// The property that differs, like `DTSTART`.
+    pub property: &'static str,
+    /// The property's value in the event described.
+    pub expected: Option<String>,
+    /// The property's value in the event parsed back.
+    pub found: Option<String>,
+}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} was parsed with {} {:?}, not {:?}",
+            self.text, self.property, self.found, self.expected
+        )
+    }
+}
+
+impl Error for RoundTripError {}
+
+/// Describes `e` with [`describe_event`](../fn.describe_event.html), parses the description with `parse` and checks
+/// the summary, location, start and end came back the same.
+///
+/// # Example
+/// ```
+/// use event_parser::testing::{check_round_trip, EventGenerator};
+/// use event_parser::to_event;
+///
+/// for event in EventGenerator::new(1).take(20) {
+///     check_round_trip(&event, to_event).unwrap();
+/// }
+/// ```
+pub fn check_round_trip(e: &Event, parse: impl Fn(&str) -> Event) -> Result<(), RoundTripError> {
+    let text = describe_event(e);
+    let parsed = parse(&text);
+    let value = |e: &Event, key: &str| e.properties().get(key).map(|p| p.value().to_owned());
+    for property in COMPARED {
+        let (expected, found) = (value(e, property), value(&parsed, property));
+        if expected != found {
+            return Err(RoundTripError {
+                text,
+                property,
+                expected,
+                found,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks `cases` events from [`EventGenerator::new(seed)`](struct.EventGenerator.html) round trip through `parse`,
+/// like [`check_round_trip`](fn.check_round_trip.html) does for one.
+///
+/// # Panics
+///
+/// Panics with the first event that does not round trip.
+pub fn assert_round_trips(cases: usize, seed: u64, parse: impl Fn(&str) -> Event) {
+    for (i, event) in EventGenerator::new(seed).take(cases).enumerate() {
+        if let Err(e) = check_round_trip(&event, &parse) {
+            panic!("event {} of seed {}: {}", i, seed, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing_tests {
+    use super::{assert_round_trips, check_round_trip, EventGenerator};
+    use crate::{parse_at, Parsers};
+    use chrono::NaiveDate;
+    use icalendar::{Component, Event};
+
+    #[test]
+    fn round_trip_tests() {
+        let now = NaiveDate::from_ymd_opt(2021, 3, 3)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        assert_round_trips(100, 42, |text| {
+            parse_at(text, now, &Parsers::default()).event
+        });
+    }
+
+    #[test]
+    fn round_trip_error_tests() {
+        let event = EventGenerator::new(3).next().unwrap();
+        let error =
+            check_round_trip(&event, |_| Event::new().summary("Nothing").done()).unwrap_err();
+        assert_eq!(error.property, "SUMMARY");
+        assert_eq!(error.found.as_deref(), Some("Nothing"));
+        assert_eq!(
+            error.expected.as_deref(),
+            event.properties().get("SUMMARY").map(|p| p.value())
+        );
+    }
+}
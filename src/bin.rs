@@ -1,15 +1,182 @@
 //! # Event Parser
 //!
 //! A command line tool for parsing unstructered text into [iCalendar Events](../icalendar/struct.Event.html) using the [event_parser](../event_parser/index.html) library.
+//!
+//! Personal defaults are read from `~/.config/event_parser/config.toml` (or `$XDG_CONFIG_HOME/event_parser/config.toml`)
+//! if it exists. It takes the same settings as [`ParserConfig`](../event_parser/struct.ParserConfig.html), plus how
+//! output is shown:
+//!
+//! ```toml
+//! locale = "en_GB"      # read 5/6 as June 5
+//! timezone = "+01:00"   # read "today" and "in 2 hours" at this UTC offset instead of the local one
+//! time_format = "24h"   # show 19:00 rather than 07:00pm
+//! ```
+//!
+//! `event_parser_command_line_tool --ics <calendar.ics>` also appends each event parsed to the calendar, creating it if
+//! it does not exist, so the lines typed can be imported in one go.
+//!
+//! `event_parser_command_line_tool watch <directory or named pipe> <calendar.ics>` instead watches for new text, like
+//! files dropped into a directory by an email filter, and appends the events in it to the calendar.
+//!
+//! `event_parser_command_line_tool rpc` answers newline-delimited JSON-RPC requests on stdin, like
+//! `{"jsonrpc": "2.0", "id": 1, "method": "parse", "params": {"text": "Lunch at noon"}}`, with the event and the labeled
+//! pieces of the text, for editor plugins to run it in the background.
+//!
+//! With the `http` feature, `event_parser_command_line_tool serve --port 8080` answers `POST /parse` requests with a
+//! body like `{"text": "Lunch at noon"}` with the event as JSON.
 
-use event_parser;
-use std::io::BufRead;
+use chrono::FixedOffset;
+use event_parser::{Clock, HourFormat, LocalClock, OffsetClock, ParserConfig};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod json;
+mod rpc;
+#[cfg(feature = "http")]
+mod serve;
+mod watch;
+
+#[cfg(not(feature = "http"))]
+const USAGE: &str = "usage: event_parser_command_line_tool [--ics <calendar.ics>]
+       event_parser_command_line_tool watch <directory or named pipe> <calendar.ics>
+       event_parser_command_line_tool rpc";
+#[cfg(feature = "http")]
+const USAGE: &str = "usage: event_parser_command_line_tool [--ics <calendar.ics>]
+       event_parser_command_line_tool watch <directory or named pipe> <calendar.ics>
+       event_parser_command_line_tool rpc
+       event_parser_command_line_tool serve [--host <address>] [--port <port>]";
+
+/// Settings only the command line tool reads from the config file, alongside those of `ParserConfig`.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Display {
+    /// The UTC offset relative dates and times are read at, e.g. "+01:00". Defaults to the local timezone.
+    timezone: Option<String>,
+    time_format: TimeFormat,
+}
+
+#[derive(Deserialize, Default)]
+enum TimeFormat {
+    #[default]
+    #[serde(rename = "12h")]
+    TwelveHour,
+    #[serde(rename = "24h")]
+    TwentyFourHour,
+}
 
 fn main() {
-    println!("e.g. Lunch at 12pm");
-    let stdin = std::io::stdin();
-    for line in stdin.lock().lines() {
-        let event = event_parser::to_event(&line.unwrap());
-        event_parser::pretty_print(event);
+    let (config, display) = load_config().unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        process::exit(1);
+    });
+    let offset = display.timezone.map(|offset| {
+        offset.parse::<FixedOffset>().unwrap_or_else(|_| {
+            eprintln!("invalid timezone in config: {:?}, expected an offset like \"+01:00\"", offset);
+            process::exit(1);
+        })
+    });
+    let clock: Box<dyn Clock> = match offset {
+        Some(offset) => Box::new(OffsetClock(offset)),
+        None => Box::new(LocalClock),
+    };
+    let hours = match display.time_format {
+        TimeFormat::TwelveHour => HourFormat::TwelveHour,
+        TimeFormat::TwentyFourHour => HourFormat::TwentyFourHour,
+    };
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => {
+            println!("e.g. Lunch at 12pm");
+            let stdin = io::stdin();
+            let events = event_parser::parse_events_with_config(stdin.lock(), &config, clock.as_ref())
+                .map(|parsed| parsed.unwrap().event);
+            event_parser::write_events_with_format(io::stdout(), events, hours).unwrap();
+        }
+        ["--ics", target] => {
+            println!("e.g. Lunch at 12pm");
+            let stdin = io::stdin();
+            for parsed in event_parser::parse_events_with_config(stdin.lock(), &config, clock.as_ref()) {
+                let event = parsed.unwrap().event;
+                event_parser::write_events_with_format(io::stdout(), [&event], hours).unwrap();
+                if let Err(e) = watch::append_events(Path::new(target), vec![event]) {
+                    eprintln!("{}: {}", target, e);
+                    process::exit(1);
+                }
+            }
+        }
+        ["watch", source, target] => {
+            if let Err(e) = watch::run(Path::new(source), Path::new(target), &config, clock.as_ref()) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        ["rpc"] => {
+            let parser = json::Parser { config, clock, offset };
+            if let Err(e) = rpc::run(io::stdin().lock(), io::stdout().lock(), &parser) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        #[cfg(feature = "http")]
+        ["serve", ref options @ ..] => {
+            let (host, port) = serve_address(options).unwrap_or_else(|| {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            });
+            let parser = json::Parser { config, clock, offset };
+            if let Err(e) = serve::run(host, port, parser) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+/// Returns the host and port given by `serve`'s options, 127.0.0.1 and 8080 unless given.
+#[cfg(feature = "http")]
+fn serve_address<'a>(mut options: &[&'a str]) -> Option<(&'a str, u16)> {
+    let (mut host, mut port) = ("127.0.0.1", 8080);
+    while let [option, value, rest @ ..] = options {
+        match *option {
+            "--host" => host = value,
+            "--port" => port = value.parse().ok()?,
+            _ => return None,
+        }
+        options = rest;
     }
+    if options.is_empty() {
+        Some((host, port))
+    } else {
+        None
+    }
+}
+
+/// Reads the user's config file, or the defaults if there is none.
+fn load_config() -> Result<(ParserConfig, Display), String> {
+    let path = match config_path() {
+        Some(path) if path.exists() => path,
+        _ => return Ok((ParserConfig::default(), Display::default())),
+    };
+    let config = ParserConfig::load(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let display = toml::from_str(&text).map_err(|e| format!("{}: invalid TOML config: {}", path.display(), e))?;
+    Ok((config, display))
+}
+
+/// Returns where the user's config file would be.
+fn config_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("event_parser").join("config.toml"))
 }
@@ -0,0 +1,117 @@
+//! Parse text into the JSON form of an event that the tool's servers reply with.
+
+use chrono::{FixedOffset, Local, NaiveDateTime, TimeZone};
+use event_parser::{parse_events_with_config, Clock, ParseOutcome, ParsedEvent, ParserConfig};
+use icalendar::Component;
+use serde::Serialize;
+
+/// What a server parses with.
+pub struct Parser {
+    pub config: ParserConfig,
+    pub clock: Box<dyn Clock>,
+    /// The UTC offset times are given at, or `None` for the local timezone.
+    pub offset: Option<FixedOffset>,
+}
+
+/// A parsed event, with its start and end as RFC 3339 date-times, or dates for all-day events. Its summary, location,
+/// times and whether it is all day are those of the event's [`ParsedEvent`], which also has the `RRULE` and warnings.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct EventJson {
+    pub summary: Option<String>,
+    pub location: Option<String>,
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub all_day: bool,
+    pub rrule: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+impl EventJson {
+    /// Describes `parsed`, giving times at `offset`, or in the local timezone if there is none.
+    pub fn new(parsed: ParseOutcome, offset: Option<FixedOffset>) -> EventJson {
+        let rrule = parsed.event.properties().get("RRULE").map(|p| p.value().to_owned());
+        let warnings = parsed.warnings.iter().map(ToString::to_string).collect();
+        let event = match ParsedEvent::new(parsed.event) {
+            Some(event) => event,
+            None => return EventJson { rrule, warnings, ..EventJson::empty() },
+        };
+        let when = |time: NaiveDateTime| {
+            if event.is_all_day() {
+                return Some(time.date().to_string());
+            }
+            match offset {
+                Some(offset) => offset.from_local_datetime(&time).single().map(|d| d.to_rfc3339()),
+                None => Local.from_local_datetime(&time).earliest().map(|d| d.fixed_offset().to_rfc3339()),
+            }
+        };
+        EventJson {
+            summary: event.summary().map(str::to_owned),
+            location: event.location().map(str::to_owned),
+            start: when(event.start()),
+            end: when(event.end()),
+            all_day: event.is_all_day(),
+            rrule,
+            warnings,
+        }
+    }
+
+    /// An event with nothing parsed.
+    fn empty() -> EventJson {
+        EventJson {
+            summary: None,
+            location: None,
+            start: None,
+            end: None,
+            all_day: false,
+            rrule: None,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+/// Parses all of `text` into one event.
+pub fn parse_text(parser: &Parser, text: &str) -> EventJson {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let parsed = parse_events_with_config(line.as_bytes(), &parser.config, parser.clock.as_ref())
+        .next()
+        .and_then(Result::ok);
+    match parsed {
+        Some(parsed) => EventJson::new(parsed, parser.offset),
+        None => EventJson::empty(),
+    }
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::{parse_text, Parser};
+    use chrono::{FixedOffset, NaiveDate};
+    use event_parser::{FixedClock, ParserConfig};
+
+    #[test]
+    fn parse_text_tests() {
+        let parser = Parser {
+            config: ParserConfig::default(),
+            clock: Box::new(FixedClock(
+                NaiveDate::from_ymd_opt(2021, 3, 3)
+                    .unwrap()
+                    .and_hms_opt(8, 0, 0)
+                    .unwrap(),
+            )),
+            offset: Some(FixedOffset::east_opt(3600).unwrap()),
+        };
+
+        let parsed = parse_text(&parser, "Dinner at Cafe Rio\nat 7 tomorrow");
+        assert_eq!(parsed.summary.as_deref(), Some("Dinner"));
+        assert_eq!(parsed.location.as_deref(), Some("Cafe Rio"));
+        assert_eq!(parsed.start.as_deref(), Some("2021-03-04T19:00:00+01:00"));
+        assert_eq!(parsed.end.as_deref(), Some("2021-03-04T20:00:00+01:00"));
+        assert!(!parsed.all_day);
+        assert_eq!(parsed.warnings, vec!["no am or pm was given, so 7:00pm was guessed"]);
+
+        let parsed = parse_text(&parser, "Vacation 6/1-6/8");
+        assert_eq!(parsed.start.as_deref(), Some("2021-06-01"));
+        assert!(parsed.all_day);
+
+        assert_eq!(parse_text(&parser, "  ").summary, None);
+    }
+}
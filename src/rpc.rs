@@ -0,0 +1,201 @@
+//! `rpc`: a long-lived mode speaking newline-delimited JSON-RPC 2.0 over stdin and stdout, so editor plugins can keep
+//! one process running and ask it to parse text as the user types.
+//!
+//! Each line read is a request like
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "id": 1, "method": "parse", "params": {"text": "Lunch at noon"}}
+//! ```
+//!
+//! and each reply is one line with the parsed [`EventJson`](../json/struct.EventJson.html) and the labeled pieces of
+//! the text, with their byte offsets:
+//!
+//! ```json
+//! {"jsonrpc": "2.0", "id": 1, "result": {"event": {...}, "segments": [{"label": "summary", "text": "Lunch", "start": 0, "end": 5}, ...]}}
+//! ```
+
+use event_parser::{highlight_with, Label};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, Write};
+
+use crate::json::{parse_text, EventJson, Parser};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// A JSON-RPC request. One without an `id` is a notification and gets no reply.
+#[derive(Deserialize)]
+struct Request {
+    jsonrpc: String,
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// The params of a `parse` request.
+#[derive(Deserialize)]
+struct ParseParams {
+    text: String,
+}
+
+/// The result of a `parse` request.
+#[derive(Serialize)]
+struct ParseResult {
+    event: EventJson,
+    segments: Vec<SegmentJson>,
+}
+
+/// A piece of the parsed text, with where it is in bytes.
+#[derive(Serialize)]
+struct SegmentJson {
+    label: &'static str,
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+/// Answers the requests on each line of `input` on `output` until `input` ends.
+pub fn run(input: impl BufRead, mut output: impl Write, parser: &Parser) -> io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(reply) = handle(&line, parser) {
+            serde_json::to_writer(&mut output, &reply)?;
+            output.write_all(b"\n")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the reply to the request on `line`, or `None` if it is a notification.
+fn handle(line: &str, parser: &Parser) -> Option<Value> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return Some(error(Value::Null, PARSE_ERROR, &e.to_string())),
+    };
+    let request: Request = match serde_json::from_value(request.clone()) {
+        Ok(request) => request,
+        Err(e) => {
+            let id = request.get("id").cloned().unwrap_or(Value::Null);
+            return Some(error(id, INVALID_REQUEST, &e.to_string()));
+        }
+    };
+    let id = request.id.clone();
+    let reply = |id: Value| {
+        if request.jsonrpc != "2.0" {
+            return error(id, INVALID_REQUEST, "jsonrpc must be \"2.0\"");
+        }
+        match request.method.as_str() {
+            "parse" => match serde_json::from_value::<ParseParams>(request.params.clone()) {
+                Ok(params) => json!({ "jsonrpc": "2.0", "id": id, "result": parse(parser, &params.text) }),
+                Err(e) => error(id, INVALID_PARAMS, &e.to_string()),
+            },
+            method => error(id, METHOD_NOT_FOUND, &format!("unknown method {:?}", method)),
+        }
+    };
+    id.map(reply)
+}
+
+/// Parses `text` into its event and segments, both read with the settings and clock of `parser`.
+fn parse(parser: &Parser, text: &str) -> ParseResult {
+    let segments = highlight_with(text, parser.clock.now(), &parser.config)
+        .into_iter()
+        .map(|segment| SegmentJson {
+            label: match segment.label {
+                Label::Plain => "plain",
+                Label::Date => "date",
+                Label::Time => "time",
+                Label::Location => "location",
+                Label::Summary => "summary",
+            },
+            text: segment.text.to_owned(),
+            start: segment.span.start,
+            end: segment.span.end,
+        })
+        .collect();
+    ParseResult { event: parse_text(parser, text), segments }
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[cfg(test)]
+mod rpc_tests {
+    use super::run;
+    use crate::json::Parser;
+    use chrono::{FixedOffset, NaiveDate};
+    use event_parser::{FixedClock, ParserConfig};
+    use serde_json::Value;
+
+    #[test]
+    fn run_tests() {
+        let parser = Parser {
+            config: ParserConfig::default(),
+            clock: Box::new(FixedClock(NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap())),
+            offset: Some(FixedOffset::east_opt(0).unwrap()),
+        };
+        let input = r#"{"jsonrpc": "2.0", "id": 1, "method": "parse", "params": {"text": "Lunch at noon"}}
+
+{"jsonrpc": "2.0", "method": "parse", "params": {"text": "Lunch at noon"}}
+{"jsonrpc": "2.0", "id": "a", "method": "format"}
+{"jsonrpc": "2.0", "id": 2, "method": "parse", "params": {}}
+{"jsonrpc": "2.0", "id": 3}
+not json
+"#;
+        let mut output = Vec::new();
+        run(input.as_bytes(), &mut output, &parser).unwrap();
+        let replies: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(replies.len(), 5);
+
+        let result = &replies[0]["result"];
+        assert_eq!(replies[0]["id"], 1);
+        assert_eq!(result["event"]["summary"], "Lunch");
+        assert_eq!(result["event"]["start"], "2021-03-03T12:00:00+00:00");
+        assert_eq!(result["segments"][0]["label"], "summary");
+        assert_eq!(result["segments"][0]["text"], "Lunch");
+        assert_eq!(result["segments"][0]["start"], 0);
+        assert_eq!(result["segments"][0]["end"], 5);
+        let last = result["segments"].as_array().unwrap().last().unwrap().clone();
+        assert_eq!(last["label"], "time");
+        assert_eq!(last["end"], 13);
+
+        assert_eq!(replies[1]["id"], "a");
+        assert_eq!(replies[1]["error"]["code"], -32601);
+        assert_eq!(replies[2]["error"]["code"], -32602);
+        assert_eq!(replies[3]["id"], 3);
+        assert_eq!(replies[3]["error"]["code"], -32600);
+        assert_eq!(replies[4]["id"], Value::Null);
+        assert_eq!(replies[4]["error"]["code"], -32700);
+    }
+
+    #[test]
+    fn segments_follow_config_tests() {
+        let parser = Parser {
+            config: ParserConfig::from_toml("[rules]\nlocation = false").unwrap(),
+            clock: Box::new(FixedClock(NaiveDate::from_ymd_opt(2021, 3, 3).unwrap().and_hms_opt(8, 0, 0).unwrap())),
+            offset: Some(FixedOffset::east_opt(0).unwrap()),
+        };
+        let input = r#"{"jsonrpc": "2.0", "id": 1, "method": "parse", "params": {"text": "Lunch at Cafe Rio at noon"}}"#;
+        let mut output = Vec::new();
+        run(input.as_bytes(), &mut output, &parser).unwrap();
+        let reply: Value = serde_json::from_slice(&output).unwrap();
+
+        let result = &reply["result"];
+        assert_eq!(result["event"]["location"], Value::Null);
+        let segments = result["segments"].as_array().unwrap();
+        assert!(segments.iter().all(|segment| segment["label"] != "location"));
+        assert!(segments.iter().any(|segment| segment["label"] == "summary" && segment["text"] == "Cafe Rio"));
+    }
+}
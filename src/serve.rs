@@ -0,0 +1,34 @@
+//! `serve`: an HTTP server that parses text posted to `/parse` into a JSON event, so services in other languages can
+//! use the parser. Enabled with the `http` feature.
+
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::Deserialize;
+use std::io;
+use std::sync::Arc;
+
+use crate::json::{parse_text, EventJson, Parser};
+
+/// The body of a request to `/parse`.
+#[derive(Deserialize)]
+struct ParseRequest {
+    text: String,
+}
+
+/// Serves `POST /parse` at `host:port` until an error occurs. The request body is `{"text": "Lunch at noon"}` and the
+/// reply an [`EventJson`](../json/struct.EventJson.html).
+pub fn run(host: &str, port: u16, parser: Parser) -> io::Result<()> {
+    let app = Router::new()
+        .route("/parse", post(parse))
+        .with_state(Arc::new(parser));
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let listener = tokio::net::TcpListener::bind((host, port)).await?;
+        eprintln!("listening on http://{}", listener.local_addr()?);
+        axum::serve(listener, app).await
+    })
+}
+
+async fn parse(State(parser): State<Arc<Parser>>, Json(request): Json<ParseRequest>) -> Json<EventJson> {
+    Json(parse_text(&parser, &request.text))
+}
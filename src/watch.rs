@@ -0,0 +1,226 @@
+//! Watch a directory or named pipe for new text, parse it into events and append them to an .ics file.
+
+use event_parser::{parse_events_with_config, Clock, ParserConfig};
+use icalendar::{Calendar, Event};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// How often a watched directory is checked for new files.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watches `source` until an error occurs, appending the events on each line of its new text to the calendar at
+/// `target`. A directory is checked for new files, each read once it has stopped growing; files already in it are
+/// left alone, as are files that cannot be read, which are reported. A named pipe is read each time a writer opens and
+/// closes it. Only errors reading `source` itself or writing `target` stop the watch.
+pub fn run(source: &Path, target: &Path, config: &ParserConfig, clock: &dyn Clock) -> io::Result<()> {
+    if source.is_dir() {
+        let mut seen = files_in(source)?.into_iter().collect();
+        let mut growing = HashMap::new();
+        loop {
+            for file in poll(source, &mut seen, &mut growing)? {
+                match File::open(&file) {
+                    Ok(opened) => append_from(BufReader::new(opened), &file, target, config, clock)?,
+                    Err(e) => eprintln!("skipped {}: {}", file.display(), e),
+                }
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    } else if is_pipe(source) {
+        loop {
+            // opening blocks until something writes to the pipe
+            let reader = BufReader::new(File::open(source)?);
+            append_from(reader, source, target, config, clock)?;
+        }
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a directory or named pipe", source.display()),
+        ))
+    }
+}
+
+/// Returns the files in `dir` that are new since the last poll and have kept the same size since then. A file that
+/// has gone by the time its size is read is left out.
+fn poll(dir: &Path, seen: &mut HashSet<PathBuf>, growing: &mut HashMap<PathBuf, u64>) -> io::Result<Vec<PathBuf>> {
+    let mut ready = Vec::new();
+    for file in files_in(dir)? {
+        if seen.contains(&file) {
+            continue;
+        }
+        let len = match fs::metadata(&file) {
+            Ok(metadata) => metadata.len(),
+            Err(_) => {
+                growing.remove(&file);
+                continue;
+            }
+        };
+        if growing.insert(file.clone(), len) == Some(len) {
+            growing.remove(&file);
+            seen.insert(file.clone());
+            ready.push(file);
+        }
+    }
+    Ok(ready)
+}
+
+/// Returns the visible files in `dir`, in name order.
+fn files_in(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if entry.file_type().is_ok_and(|kind| kind.is_file()) && !hidden {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Parses each line read from `reader` into an event and appends them to the calendar at `target`. Lines that are not
+/// UTF-8 are reported and skipped, and the events read before an error reading `source` are still added; only an
+/// error writing `target` is returned.
+fn append_from(
+    reader: impl io::BufRead,
+    source: &Path,
+    target: &Path,
+    config: &ParserConfig,
+    clock: &dyn Clock,
+) -> io::Result<()> {
+    let mut events = Vec::new();
+    for parsed in parse_events_with_config(reader, config, clock) {
+        match parsed {
+            Ok(parsed) => events.push(parsed.event),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => eprintln!("skipped a line of {}: {}", source.display(), e),
+            Err(e) => {
+                eprintln!("stopped reading {}: {}", source.display(), e);
+                break;
+            }
+        }
+    }
+    let count = events.len();
+    if count > 0 {
+        append_events(target, events)?;
+    }
+    eprintln!("added {} event(s) from {}", count, source.display());
+    Ok(())
+}
+
+/// Adds `events` to the end of the calendar at `target`, creating it if it does not exist or is empty. A `target` that
+/// is not a calendar is left as it is, and an `InvalidData` error returned.
+pub fn append_events(target: &Path, events: Vec<Event>) -> io::Result<()> {
+    let new = events.into_iter().collect::<Calendar>().to_string();
+    let existing = match fs::read_to_string(target) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let merged = match (existing.rfind("END:VCALENDAR"), new.find("BEGIN:VEVENT"), new.rfind("END:VCALENDAR")) {
+        (Some(end), Some(first), Some(new_end)) => {
+            format!("{}{}{}", &existing[..end], &new[first..new_end], &existing[end..])
+        }
+        _ if existing.trim().is_empty() => new,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a calendar", target.display()),
+            ))
+        }
+    };
+    fs::write(target, merged)
+}
+
+#[cfg(unix)]
+fn is_pipe(path: &Path) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    fs::metadata(path).is_ok_and(|m| m.file_type().is_fifo())
+}
+
+#[cfg(not(unix))]
+fn is_pipe(_: &Path) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use super::{append_events, append_from, poll};
+    use event_parser::{FixedClock, ParserConfig};
+    use icalendar::{Component, Event};
+    use std::collections::{HashMap, HashSet};
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn append_events_tests() {
+        let dir = temp_dir("append");
+        let target = dir.join("events.ics");
+
+        append_events(&target, vec![Event::new().summary("Lunch").done()]).unwrap();
+        append_events(
+            &target,
+            vec![Event::new().summary("Dinner").done(), Event::new().summary("Drinks").done()],
+        )
+        .unwrap();
+
+        let ics = fs::read_to_string(&target).unwrap();
+        assert_eq!(ics.matches("BEGIN:VCALENDAR").count(), 1);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+        assert!(ics.find("SUMMARY:Lunch") < ics.find("SUMMARY:Drinks"));
+        assert!(ics.trim_end().ends_with("END:VCALENDAR"));
+
+        // a file that is not a calendar is not written over
+        let notes = dir.join("notes.ics");
+        fs::write(&notes, "my notes\n").unwrap();
+        let e = append_events(&notes, vec![Event::new().summary("Lunch").done()]).unwrap_err();
+        assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(fs::read_to_string(&notes).unwrap(), "my notes\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn append_from_tests() {
+        let dir = temp_dir("append_from");
+        let target = dir.join("events.ics");
+        let clock = FixedClock(
+            chrono::NaiveDate::from_ymd_opt(2021, 3, 3)
+                .unwrap()
+                .and_hms_opt(8, 0, 0)
+                .unwrap(),
+        );
+
+        // a line that is not UTF-8 is skipped, and the rest of the text still read
+        let text: &[u8] = b"Lunch at noon\n\xff\xfe at 3pm\nDinner at 7pm\n";
+        append_from(text, &dir.join("a.txt"), &target, &ParserConfig::default(), &clock).unwrap();
+        let ics = fs::read_to_string(&target).unwrap();
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("SUMMARY:Dinner"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn poll_tests() {
+        let dir = temp_dir("poll");
+        let mut seen = HashSet::new();
+        let mut growing = HashMap::new();
+        fs::write(dir.join("a.txt"), "Lunch at noon\n").unwrap();
+        fs::write(dir.join(".hidden"), "Lunch at noon\n").unwrap();
+
+        // a new file is only read once it has kept its size for a poll
+        assert!(poll(&dir, &mut seen, &mut growing).unwrap().is_empty());
+        fs::write(dir.join("a.txt"), "Lunch at noon\nDinner at 7pm\n").unwrap();
+        assert!(poll(&dir, &mut seen, &mut growing).unwrap().is_empty());
+        assert_eq!(poll(&dir, &mut seen, &mut growing).unwrap(), vec![dir.join("a.txt")]);
+        assert!(poll(&dir, &mut seen, &mut growing).unwrap().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("event_parser_watch_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+}